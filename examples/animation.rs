@@ -0,0 +1,112 @@
+use bevy::prelude::*;
+use woodpecker_ui::prelude::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(WoodpeckerUIPlugin::default())
+        .add_systems(Startup, startup)
+        .run();
+}
+
+fn startup(mut commands: Commands, mut ui_context: ResMut<WoodpeckerContext>) {
+    commands.spawn((Camera2d, WoodpeckerView));
+
+    let quad_styles = WoodpeckerStyle {
+        position: WidgetPosition::Absolute,
+        background_color: Srgba::new(1.0, 0.0, 0.0, 1.0).into(),
+        left: 50.0.into(),
+        top: 50.0.into(),
+        width: 100.0.into(),
+        height: 100.0.into(),
+        ..Default::default()
+    };
+
+    let root = commands.spawn_empty().id();
+    commands.entity(root).insert((
+        WoodpeckerApp,
+        WidgetChildren::default()
+            // A bouncing quad with three keyframes.
+            .with_child::<Element>((
+                Element,
+                WidgetRender::Quad,
+                quad_styles,
+                Animation::new(1500.0)
+                    .with_eased_keyframe(0.0, quad_styles, TransitionEasing::CubicOut)
+                    .with_eased_keyframe(
+                        0.5,
+                        WoodpeckerStyle {
+                            top: 250.0.into(),
+                            background_color: Srgba::new(0.0, 0.0, 1.0, 1.0).into(),
+                            ..quad_styles
+                        },
+                        TransitionEasing::CubicIn,
+                    )
+                    .with_keyframe(
+                        1.0,
+                        WoodpeckerStyle {
+                            left: 500.0.into(),
+                            ..quad_styles
+                        },
+                    )
+                    .with_iterations(AnimationIterations::Infinite)
+                    .with_direction(AnimationDirection::Alternate),
+            ))
+            // Plays three times then keeps the last keyframe, chaining
+            // another animation when it completes.
+            .with_child::<Element>((
+                Element,
+                WidgetRender::Quad,
+                WoodpeckerStyle {
+                    top: 400.0.into(),
+                    ..quad_styles
+                },
+                Animation::new(500.0)
+                    .with_keyframe(
+                        0.0,
+                        WoodpeckerStyle {
+                            top: 400.0.into(),
+                            ..quad_styles
+                        },
+                    )
+                    .with_keyframe(
+                        1.0,
+                        WoodpeckerStyle {
+                            top: 400.0.into(),
+                            width: 300.0.into(),
+                            ..quad_styles
+                        },
+                    )
+                    .with_delay(500.0)
+                    .with_iterations(AnimationIterations::Count(3))
+                    .with_fill_mode(AnimationFillMode::Forwards),
+            ))
+            .with_observe(
+                CurrentWidget(root),
+                |trigger: On<AnimationCompleted>, mut commands: Commands| {
+                    let style = WoodpeckerStyle {
+                        position: WidgetPosition::Absolute,
+                        background_color: Srgba::new(0.0, 1.0, 0.0, 1.0).into(),
+                        left: 50.0.into(),
+                        top: 400.0.into(),
+                        width: 300.0.into(),
+                        height: 100.0.into(),
+                        ..Default::default()
+                    };
+                    commands.entity(trigger.target).insert(
+                        Animation::new(1000.0)
+                            .with_keyframe(0.0, style)
+                            .with_keyframe(
+                                1.0,
+                                WoodpeckerStyle {
+                                    opacity: 0.0,
+                                    ..style
+                                },
+                            )
+                            .with_fill_mode(AnimationFillMode::Forwards),
+                    );
+                },
+            ),
+    ));
+    ui_context.set_root_widget(root);
+}
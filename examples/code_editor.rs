@@ -20,6 +20,14 @@ fn startup(
     let font = asset_server.load("Outfit/static/Outfit-Regular.ttf");
     font_manager.add(&font);
 
+    let highlighter = SyntaxHighlighter::new("rust", "dracula");
+    let startup_start = CODE_BLOCK.find("startup").unwrap();
+    let diagnostics = vec![Diagnostic::new(
+        startup_start..startup_start + "startup".len(),
+        DiagnosticSeverity::Warning,
+        "function `startup` is defined below",
+    )];
+
     let root = commands
         .spawn((
             WoodpeckerApp,
@@ -45,9 +53,11 @@ fn startup(
                                 TextBox {
                                     initial_value: CODE_BLOCK.to_string(),
                                     multi_line: true,
-                                    text_highlighting: ApplyHighlighting::new(|txt| {
-                                        Some(highlight("rust", txt, "dracula"))
+                                    text_highlighting: ApplyHighlighting::background(move |txt| {
+                                        Some(highlighter.highlight(txt))
                                     }),
+                                    code_editor: Some(CodeEditorSettings::default()),
+                                    diagnostics,
                                     ..default()
                                 },
                             )),
@@ -0,0 +1,46 @@
+use bevy::prelude::*;
+use woodpecker_ui::prelude::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(WoodpeckerUIPlugin::default())
+        .add_systems(Startup, startup)
+        .run();
+}
+
+fn startup(mut commands: Commands, mut ui_context: ResMut<WoodpeckerContext>) {
+    commands.spawn((Camera2d, WoodpeckerView));
+
+    let materials = ["Wood", "Stone", "Iron", "Gold", "Diamond", "Obsidian"];
+    let tools = ["Sword", "Pickaxe", "Axe", "Shovel", "Hoe", "Short Bow"];
+    let items = materials
+        .iter()
+        .flat_map(|material| tools.iter().map(move |tool| format!("{material} {tool}")))
+        .map(DropdownItem::from)
+        .collect::<Vec<_>>();
+
+    let root = commands.spawn_empty().id();
+    commands.entity(root).insert((
+        WoodpeckerApp,
+        WoodpeckerStyle {
+            padding: Edge::all(10.0),
+            width: 300.0.into(),
+            ..default()
+        },
+        WidgetChildren::default()
+            .with_child::<ComboBox>(ComboBox {
+                items,
+                allow_custom_value: true,
+                ..Default::default()
+            })
+            .with_observe(
+                CurrentWidget(root),
+                |trigger: On<Change<ComboBoxChanged>>| match trigger.data.index {
+                    Some(index) => info!("Picked item {index}: {}", trigger.data.value),
+                    None => info!("Custom value: {}", trigger.data.value),
+                },
+            ),
+    ));
+    ui_context.set_root_widget(root);
+}
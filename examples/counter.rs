@@ -64,6 +64,12 @@ fn render(
             ))
             .with_child::<WButton>((
                 WButton,
+                // Fade the hover color in and out.
+                StyleTransitions::new([(
+                    TransitionProperty::BackgroundColor,
+                    150.0,
+                    TransitionEasing::QuadraticOut,
+                )]),
                 WidgetChildren::default().with_child::<Element>((
                     Element,
                     WoodpeckerStyle {
@@ -49,8 +49,14 @@ fn startup(
         },
         WidgetChildren::default()
             .with_child::<Dropdown>(Dropdown {
-                list: vec!["Red".into(), "Green".into(), "Blue".into()],
-                current_value: "Red".into(),
+                items: vec![
+                    DropdownItem::new("Red", "red"),
+                    DropdownItem::new("Green", "green"),
+                    DropdownItem::new("Blue", "blue"),
+                    DropdownItem::new("Purple", "purple").disabled(),
+                ],
+                selected: Some(0),
+                placeholder: "Pick a color".into(),
                 ..Default::default()
             })
             .with_observe(
@@ -60,9 +66,9 @@ fn startup(
                  mut query: Query<&mut MeshMaterial2d<ColorMaterial>>| {
                     for mut material in query.iter_mut() {
                         match trigger.data.value.as_str() {
-                            "Red" => *material = MeshMaterial2d(material_list.red.clone()),
-                            "Green" => *material = MeshMaterial2d(material_list.green.clone()),
-                            "Blue" => *material = MeshMaterial2d(material_list.blue.clone()),
+                            "red" => *material = MeshMaterial2d(material_list.red.clone()),
+                            "green" => *material = MeshMaterial2d(material_list.green.clone()),
+                            "blue" => *material = MeshMaterial2d(material_list.blue.clone()),
                             _ => {}
                         }
                     }
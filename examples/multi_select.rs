@@ -0,0 +1,49 @@
+use bevy::prelude::*;
+use woodpecker_ui::prelude::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(WoodpeckerUIPlugin::default())
+        .add_systems(Startup, startup)
+        .run();
+}
+
+fn startup(mut commands: Commands, mut ui_context: ResMut<WoodpeckerContext>) {
+    commands.spawn((Camera2d, WoodpeckerView));
+
+    let root = commands.spawn_empty().id();
+    commands.entity(root).insert((
+        WoodpeckerApp,
+        WoodpeckerStyle {
+            padding: Edge::all(10.0),
+            width: 300.0.into(),
+            flex_direction: WidgetFlexDirection::Column,
+            gap: (10.0.into(), 10.0.into()),
+            ..default()
+        },
+        WidgetChildren::default()
+            .with_child::<MultiSelect>(MultiSelect {
+                items: vec![
+                    DropdownItem::from("Weapons"),
+                    DropdownItem::from("Armor"),
+                    DropdownItem::from("Potions"),
+                    DropdownItem::from("Quest Items").disabled(),
+                ],
+                initial_selection: vec![0],
+            })
+            .with_observe(
+                CurrentWidget(root),
+                |trigger: On<Change<MultiSelectChanged>>| {
+                    info!("Filters: {:?}", trigger.data.values);
+                },
+            )
+            .with_child::<TagInput>(TagInput {
+                initial_tags: vec!["rare".into()],
+            })
+            .with_observe(CurrentWidget(root), |trigger: On<Change<TagsChanged>>| {
+                info!("Tags: {:?}", trigger.data.tags);
+            }),
+    ));
+    ui_context.set_root_widget(root);
+}
@@ -0,0 +1,44 @@
+use bevy::prelude::*;
+use woodpecker_ui::prelude::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(WoodpeckerUIPlugin::default())
+        .add_systems(Startup, startup)
+        .run();
+}
+
+fn startup(mut commands: Commands, mut ui_context: ResMut<WoodpeckerContext>) {
+    commands.spawn((Camera2d, WoodpeckerView));
+
+    let root = commands.spawn_empty().id();
+    commands.entity(root).insert((
+        WoodpeckerApp,
+        WoodpeckerStyle {
+            padding: Edge::all(10.0),
+            width: 300.0.into(),
+            flex_direction: WidgetFlexDirection::Column,
+            gap: (10.0.into(), 10.0.into()),
+            ..default()
+        },
+        WidgetChildren::default()
+            .with_child::<NumberInput>(NumberInput {
+                label: "X".into(),
+                step: 0.1,
+                ..default()
+            })
+            .with_observe(CurrentWidget(root), |trigger: On<Change<NumberChanged>>| {
+                info!("Value: {}", trigger.data.value);
+            })
+            .with_child::<NumberInput>(NumberInput {
+                label: "Health".into(),
+                initial_value: 100.0,
+                min: Some(0.0),
+                max: Some(200.0),
+                precision: 0,
+                ..default()
+            }),
+    ));
+    ui_context.set_root_widget(root);
+}
@@ -0,0 +1,49 @@
+use bevy::prelude::*;
+use woodpecker_ui::prelude::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(WoodpeckerUIPlugin::default())
+        .add_systems(Startup, startup)
+        .run();
+}
+
+fn startup(mut commands: Commands, mut ui_context: ResMut<WoodpeckerContext>) {
+    commands.spawn((Camera2d, WoodpeckerView));
+
+    let sizes = ["Small", "Medium", "Large", "Huge"];
+    let mut radio_buttons = WidgetChildren::default();
+    for (index, size) in sizes.iter().enumerate() {
+        radio_buttons.add::<RadioButton>(RadioButton {
+            index,
+            label: size.to_string(),
+            value: size.to_lowercase(),
+            disabled: index == 3,
+        });
+    }
+
+    let root = commands.spawn_empty().id();
+    commands.entity(root).insert((
+        WoodpeckerApp,
+        WoodpeckerStyle {
+            padding: Edge::all(10.0),
+            ..default()
+        },
+        WidgetChildren::default()
+            .with_child::<RadioGroup>((
+                RadioGroup {
+                    initial_index: Some(1),
+                    ..default()
+                },
+                PassedChildren(radio_buttons),
+            ))
+            .with_observe(
+                CurrentWidget(root),
+                |trigger: On<Change<RadioGroupChanged>>| {
+                    info!("Size: {}", trigger.data.value);
+                },
+            ),
+    ));
+    ui_context.set_root_widget(root);
+}
@@ -35,7 +35,10 @@ Lorem ipsum dolor sit amet, consectetur adipiscing elit. Cras sed tellus neque.
                 },
                 PassedChildren(
                     WidgetChildren::default().with_child::<ScrollContextProvider>((
-                        ScrollContextProvider::default(),
+                        ScrollContextProvider {
+                            // Touch drags can pull the content past the edges.
+                            initial_value: ScrollContext::default().with_mode(ScrollMode::Bounce),
+                        },
                         WoodpeckerStyle {
                             margin: Edge::all(0.0).left(10.0).right(10.0).bottom(10.0),
                             width: Units::Percentage(100.0),
@@ -43,7 +46,13 @@ Lorem ipsum dolor sit amet, consectetur adipiscing elit. Cras sed tellus neque.
                             ..Default::default()
                         },
                         WidgetChildren::default().with_child::<ScrollBox>((
-                            ScrollBox::default(),
+                            ScrollBox {
+                                // Ease wheel scrolling instead of jumping.
+                                spring: Some(Spring::DEFAULT),
+                                // Flung content keeps moving.
+                                friction: Some(4.0),
+                                ..Default::default()
+                            },
                             PassedChildren(WidgetChildren::default().with_child::<Element>((
                                 Element,
                                 WoodpeckerStyle {
@@ -30,6 +30,8 @@ fn startup(
         WoodpeckerApp,
         WoodpeckerStyle {
             padding: Edge::all(10.0),
+            flex_direction: WidgetFlexDirection::Column,
+            gap: (30.0.into(), 30.0.into()),
             ..default()
         },
         WidgetChildren::default()
@@ -37,6 +39,11 @@ fn startup(
                 start: 0.0,
                 end: 1.0,
                 value: 0.5,
+                step: Some(0.05),
+                ticks: [0.0, 0.25, 0.5, 0.75, 1.0]
+                    .map(|value| SliderTick::new(value).with_label(format!("{value}")))
+                    .to_vec(),
+                ..default()
             })
             .with_observe(
                 CurrentWidget(root),
@@ -52,6 +59,20 @@ fn startup(
                             .set_alpha(trigger.data.value)
                     }
                 },
+            )
+            .with_child::<Slider>(Slider {
+                start: 0.0,
+                end: 100.0,
+                value: 20.0,
+                upper_value: Some(80.0),
+                step: Some(1.0),
+                ..default()
+            })
+            .with_observe(
+                CurrentWidget(root),
+                |trigger: On<Change<SliderRangeChanged>>| {
+                    println!("Range: {} - {}", trigger.data.low, trigger.data.high);
+                },
             ),
     ));
     ui_context.set_root_widget(root);
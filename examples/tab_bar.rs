@@ -0,0 +1,105 @@
+use bevy::prelude::*;
+use woodpecker_ui::prelude::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(WoodpeckerUIPlugin::default())
+        .add_systems(Startup, startup)
+        .run();
+}
+
+fn startup(mut commands: Commands, mut ui_context: ResMut<WoodpeckerContext>) {
+    commands.spawn((Camera2d, WoodpeckerView));
+
+    let text = WoodpeckerStyle {
+        font_size: 18.0,
+        color: Color::WHITE,
+        margin: Edge::all(10.0),
+        ..default()
+    };
+
+    let mut tabs = vec![];
+    let mut tab_content = WidgetChildren::default();
+    for i in 0..12 {
+        tabs.push(TabButton {
+            index: i,
+            title: format!("File {}.rs", i + 1),
+            // The first tab can't be closed.
+            closable: i > 0,
+            ..default()
+        });
+        tab_content.add::<TabContent>(TabContentBundle {
+            tab_content: TabContent { index: i },
+            children: PassedChildren(WidgetChildren::default().with_child::<Element>((
+                Element,
+                text,
+                WidgetRender::Text {
+                    content: format!("The contents of file {}.rs", i + 1),
+                },
+            ))),
+            ..default()
+        });
+    }
+
+    let root = commands.spawn_empty().id();
+    commands.entity(root).insert((
+        WoodpeckerApp,
+        WoodpeckerStyle {
+            padding: Edge::all(10.0),
+            flex_direction: WidgetFlexDirection::Column,
+            gap: (10.0.into(), 10.0.into()),
+            ..default()
+        },
+        WidgetChildren::default()
+            .with_child::<WButton>((
+                WButton,
+                WidgetChildren::default().with_child::<Element>((
+                    Element,
+                    text,
+                    WidgetRender::Text {
+                        content: "Show the last file".into(),
+                    },
+                )),
+            ))
+            .with_observe(
+                CurrentWidget(root),
+                |_trigger: On<Pointer<Click>>,
+                 mut commands: Commands,
+                 provider_query: Query<Entity, With<TabContextProvider>>| {
+                    for provider in provider_query.iter() {
+                        commands.trigger(SelectTab {
+                            target: provider,
+                            index: 11,
+                        });
+                    }
+                },
+            )
+            .with_child::<TabContextProvider>(TabContextProviderBundle {
+                styles: WoodpeckerStyle {
+                    width: 600.0.into(),
+                    height: 300.0.into(),
+                    ..default()
+                },
+                children: PassedChildren(
+                    WidgetChildren::default()
+                        .with_child::<TabBar>(TabBar { tabs })
+                        .with_child::<Element>((Element, tab_content)),
+                ),
+                ..default()
+            })
+            .with_observe(CurrentWidget(root), |trigger: On<Change<TabChanged>>| {
+                info!(
+                    "Switched from tab {} to {}",
+                    trigger.data.previous, trigger.data.index
+                );
+            })
+            .with_observe(CurrentWidget(root), |trigger: On<Change<TabClosed>>| {
+                info!("Closed tab {}", trigger.data.index);
+            })
+            .with_observe(CurrentWidget(root), |trigger: On<Change<TabsReordered>>| {
+                info!("Tab order: {:?}", trigger.data.tabs);
+            }),
+    ));
+    ui_context.set_root_widget(root);
+}
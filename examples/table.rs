@@ -0,0 +1,69 @@
+use bevy::prelude::*;
+use woodpecker_ui::prelude::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(WoodpeckerUIPlugin::default())
+        .add_systems(Startup, startup)
+        .run();
+}
+
+fn startup(mut commands: Commands, mut ui_context: ResMut<WoodpeckerContext>) {
+    commands.spawn((Camera2d, WoodpeckerView));
+
+    let names = [
+        "Ash", "Birch", "Cedar", "Elm", "Fir", "Oak", "Pine", "Willow",
+    ];
+    let rows = (0..2_000)
+        .map(|i| {
+            vec![
+                (i + 1).to_string(),
+                format!("{} {}", names[i % names.len()], i / names.len()),
+                ((i * 7919) % 100_000).to_string(),
+                format!("{:.1}", (i * 31 % 1000) as f32 / 10.0),
+            ]
+        })
+        .collect::<Vec<_>>();
+
+    let root = commands.spawn_empty().id();
+    commands.entity(root).insert((
+        WoodpeckerApp,
+        WoodpeckerStyle {
+            padding: Edge::all(10.0),
+            width: Units::Percentage(100.0),
+            height: Units::Percentage(100.0),
+            ..default()
+        },
+        WidgetChildren::default()
+            .with_child::<Table>(Table {
+                columns: vec![
+                    TableColumn::new("#", 60.0).with_align(TextAlign::Right),
+                    TableColumn::new("Player", 200.0).with_limits(100.0, 400.0),
+                    TableColumn::new("Score", 120.0).with_align(TextAlign::Right),
+                    TableColumn::new("Play time (h)", 140.0)
+                        .with_align(TextAlign::Right)
+                        .unsortable(),
+                ],
+                rows,
+                selection: TableSelection::Multiple,
+                ..default()
+            })
+            .with_observe(
+                CurrentWidget(root),
+                |trigger: On<Change<TableSortChanged>>| {
+                    info!(
+                        "Sorted by column {} {:?}",
+                        trigger.data.column, trigger.data.direction
+                    );
+                },
+            )
+            .with_observe(
+                CurrentWidget(root),
+                |trigger: On<Change<TableSelectionChanged>>| {
+                    info!("Selected rows: {:?}", trigger.data.selected);
+                },
+            ),
+    ));
+    ui_context.set_root_widget(root);
+}
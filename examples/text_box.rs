@@ -15,7 +15,18 @@ fn startup(mut commands: Commands, mut ui_context: ResMut<WoodpeckerContext>) {
     let root = commands
         .spawn((
             WoodpeckerApp,
-            WidgetChildren::default().with_child::<TextBox>(TextBox::default()),
+            WidgetChildren::default().with_child::<TextBox>(TextBox {
+                suggestions: Some(SuggestionProvider::from_words([
+                    "apple",
+                    "apricot",
+                    "avocado",
+                    "banana",
+                    "blackberry",
+                    "blueberry",
+                    "cherry",
+                ])),
+                ..default()
+            }),
         ))
         .id();
     ui_context.set_root_widget(root);
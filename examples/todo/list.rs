@@ -105,7 +105,28 @@ fn render(
                     },
                 ),
             WidgetRender::Quad,
+            // Fade todos in when they are added and out when they are done.
+            EnterTransition::new(
+                [TransitionProperty::Opacity],
+                WoodpeckerStyle {
+                    opacity: 0.0,
+                    ..Default::default()
+                },
+                250.0,
+                TransitionEasing::QuadraticOut,
+            ),
+            ExitTransition::new(
+                [TransitionProperty::Opacity],
+                WoodpeckerStyle {
+                    opacity: 0.0,
+                    ..Default::default()
+                },
+                250.0,
+                TransitionEasing::QuadraticIn,
+            ),
         ));
+        // Keyed so the remaining todos aren't remounted when one is removed.
+        todo_children.add_key(todo.clone());
     }
 
     children.add::<Element>((
@@ -38,7 +38,7 @@ fn startup(mut commands: Commands, mut ui_context: ResMut<WoodpeckerContext>) {
                     Transition {
                         easing: TransitionEasing::QuadraticInOut,
                         timeout: 500.0,
-                        looping: true,
+                        looping: TransitionLooping::PingPong,
                         style_a: WoodpeckerStyle {
                             ..quad_styles.clone()
                         },
@@ -55,7 +55,7 @@ fn startup(mut commands: Commands, mut ui_context: ResMut<WoodpeckerContext>) {
                     Transition {
                         easing: TransitionEasing::CubicInOut,
                         timeout: 500.0,
-                        looping: true,
+                        looping: TransitionLooping::PingPong,
                         style_a: WoodpeckerStyle {
                             top: 175.0.into(),
                             ..quad_styles.clone()
@@ -74,7 +74,7 @@ fn startup(mut commands: Commands, mut ui_context: ResMut<WoodpeckerContext>) {
                     Transition {
                         easing: TransitionEasing::CircularInOut,
                         timeout: 500.0,
-                        looping: true,
+                        looping: TransitionLooping::PingPong,
                         style_a: WoodpeckerStyle {
                             top: 300.0.into(),
                             ..quad_styles.clone()
@@ -100,7 +100,7 @@ fn startup(mut commands: Commands, mut ui_context: ResMut<WoodpeckerContext>) {
                     Transition {
                         easing: TransitionEasing::CubicInOut,
                         timeout: 500.0,
-                        looping: true,
+                        looping: TransitionLooping::PingPong,
                         style_a: WoodpeckerStyle {
                             width: 0.0.into(),
                             top: 425.0.into(),
@@ -130,7 +130,7 @@ fn startup(mut commands: Commands, mut ui_context: ResMut<WoodpeckerContext>) {
                     Transition {
                         easing: TransitionEasing::CubicInOut,
                         timeout: 2500.0,
-                        looping: true,
+                        looping: TransitionLooping::PingPong,
                         style_a: WoodpeckerStyle {
                             width: 0.0.into(),
                             top: 450.0.into(),
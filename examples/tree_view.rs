@@ -0,0 +1,71 @@
+use bevy::prelude::*;
+use woodpecker_ui::prelude::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(WoodpeckerUIPlugin::default())
+        .add_systems(Startup, startup)
+        .run();
+}
+
+fn startup(mut commands: Commands, mut ui_context: ResMut<WoodpeckerContext>) {
+    commands.spawn((Camera2d, WoodpeckerView));
+
+    let player = commands.spawn(Name::new("Player")).id();
+    let sword = commands.spawn(Name::new("Sword")).id();
+    let camera = commands.spawn(Name::new("Camera")).id();
+
+    let root = commands.spawn_empty().id();
+    commands.entity(root).insert((
+        WoodpeckerApp,
+        WoodpeckerStyle {
+            padding: Edge::all(10.0),
+            width: 300.0.into(),
+            ..default()
+        },
+        WidgetChildren::default()
+            .with_child::<TreeView>(TreeView {
+                nodes: vec![
+                    TreeNode::new("scene", "Scene")
+                        .with_child(
+                            TreeNode::new("player", "Player")
+                                .with_entity(player)
+                                .with_child(TreeNode::new("sword", "Sword").with_entity(sword)),
+                        )
+                        .with_child(TreeNode::new("camera", "Camera").with_entity(camera)),
+                    // The children of this node are added when it's expanded.
+                    TreeNode::new("assets", "Assets").lazy(),
+                ],
+                initial_expanded: vec!["scene".into()],
+                multi_select: true,
+                ..default()
+            })
+            .with_observe(
+                CurrentWidget(root),
+                |trigger: On<Change<TreeViewChanged>>| {
+                    info!(
+                        "Selected {:?}, entities {:?}",
+                        trigger.data.selected, trigger.data.entities
+                    );
+                },
+            )
+            .with_observe(
+                CurrentWidget(root),
+                |trigger: On<Change<TreeNodeToggled>>, mut tree_query: Query<&mut TreeView>| {
+                    if !trigger.data.load_children {
+                        return;
+                    }
+                    let Ok(mut tree_view) = tree_query.get_mut(trigger.target) else {
+                        return;
+                    };
+                    if let Some(node) = TreeNode::find_mut(&mut tree_view.nodes, &trigger.data.id) {
+                        for texture in ["grass.png", "stone.png", "water.png"] {
+                            node.children.push(TreeNode::new(texture, texture));
+                        }
+                    }
+                },
+            ),
+    ));
+    ui_context.set_root_widget(root);
+}
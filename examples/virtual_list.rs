@@ -0,0 +1,141 @@
+use bevy::prelude::*;
+use woodpecker_ui::prelude::*;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(WoodpeckerUIPlugin::default())
+        .add_systems(Startup, startup)
+        .run();
+}
+
+fn startup(mut commands: Commands, mut ui_context: ResMut<WoodpeckerContext>) {
+    commands.spawn((Camera2d, WoodpeckerView));
+
+    let text = WoodpeckerStyle {
+        color: Color::WHITE,
+        font_size: 16.0,
+        ..default()
+    };
+
+    let root = commands.spawn_empty().id();
+    commands.entity(root).insert((
+        WoodpeckerApp,
+        WoodpeckerStyle {
+            padding: Edge::all(10.0),
+            width: Units::Percentage(100.0),
+            height: Units::Percentage(100.0),
+            gap: (10.0.into(), 10.0.into()),
+            ..default()
+        },
+        WidgetChildren::default()
+            .with_child::<Element>((
+                Element,
+                WoodpeckerStyle {
+                    width: 300.0.into(),
+                    height: Units::Percentage(100.0),
+                    flex_direction: WidgetFlexDirection::Column,
+                    gap: (10.0.into(), 10.0.into()),
+                    ..default()
+                },
+                WidgetChildren::default()
+                    .with_child::<WButton>((
+                        WButton,
+                        WidgetChildren::default().with_child::<Element>((
+                            Element,
+                            text,
+                            WidgetRender::Text {
+                                content: "Jump to row 5000".into(),
+                            },
+                        )),
+                    ))
+                    .with_observe(
+                        CurrentWidget(root),
+                        |_trigger: On<Pointer<Click>>,
+                         mut commands: Commands,
+                         list_query: Query<Entity, With<VirtualList>>| {
+                            for list in list_query.iter() {
+                                commands.trigger(ScrollToIndex {
+                                    target: list,
+                                    index: 5000,
+                                    align: ScrollAlign::Center,
+                                    animated: true,
+                                });
+                            }
+                        },
+                    )
+                    .with_child::<VirtualList>(VirtualList {
+                        item_count: 10_000,
+                        // Every tenth row has a second line of text.
+                        row_height: RowHeight::Measured(32.0),
+                        items: VirtualItems::new(move |index, children| {
+                            let content = if index % 10 == 0 {
+                                format!("Row {index}\nA taller row")
+                            } else {
+                                format!("Row {index}")
+                            };
+                            children.add::<Element>((
+                                Element,
+                                WoodpeckerStyle {
+                                    padding: Edge::all(6.0),
+                                    background_color: if index % 2 == 0 {
+                                        colors::BACKGROUND
+                                    } else {
+                                        colors::BACKGROUND_LIGHT
+                                    },
+                                    ..default()
+                                },
+                                WidgetRender::Quad,
+                                WidgetChildren::default().with_child::<Element>((
+                                    Element,
+                                    text,
+                                    WidgetRender::Text { content },
+                                )),
+                            ));
+                        }),
+                        styles: WoodpeckerStyle {
+                            width: Units::Percentage(100.0),
+                            flex_grow: 1.0,
+                            ..default()
+                        },
+                        ..default()
+                    }),
+            ))
+            .with_child::<VirtualGrid>(VirtualGrid {
+                item_count: 5_000,
+                cell_size: Vec2::splat(48.0),
+                items: VirtualItems::new(move |index, children| {
+                    children.add::<Element>((
+                        Element,
+                        WoodpeckerStyle {
+                            width: Units::Percentage(100.0),
+                            height: Units::Percentage(100.0),
+                            justify_content: Some(WidgetJustifyContent::Center),
+                            align_items: Some(WidgetAlignItems::Center),
+                            border_radius: Corner::all(4.0),
+                            background_color: colors::PRIMARY,
+                            ..default()
+                        },
+                        WidgetRender::Quad,
+                        WidgetChildren::default().with_child::<Element>((
+                            Element,
+                            WoodpeckerStyle {
+                                font_size: 12.0,
+                                ..text
+                            },
+                            WidgetRender::Text {
+                                content: index.to_string(),
+                            },
+                        )),
+                    ));
+                }),
+                styles: WoodpeckerStyle {
+                    flex_grow: 1.0,
+                    height: Units::Percentage(100.0),
+                    ..default()
+                },
+                ..default()
+            }),
+    ));
+    ui_context.set_root_widget(root);
+}
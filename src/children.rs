@@ -1,6 +1,6 @@
 use std::sync::{Arc, RwLock};
 
-use bevy::{ecs::system::IntoObserverSystem, prelude::*};
+use bevy::{ecs::system::IntoObserverSystem, platform::collections::HashSet, prelude::*};
 
 use crate::{context::Widget, prelude::WidgetMapper, CurrentWidget, ObserverCache, ParentWidget};
 
@@ -35,6 +35,7 @@ pub struct WidgetChildren {
                     &mut World,
                     &mut WidgetMapper,
                     &mut ObserverCache,
+                    &mut HashSet<Entity>,
                     ParentWidget,
                     usize,
                     String,
@@ -54,6 +55,7 @@ pub struct WidgetChildren {
                     &mut World,
                     &mut WidgetMapper,
                     &mut ObserverCache,
+                    &mut HashSet<Entity>,
                     ParentWidget,
                     usize,
                     String,
@@ -162,6 +164,7 @@ impl WidgetChildren {
                 move |world: &mut World,
                       widget_mapper: &mut WidgetMapper,
                       observer_cache: &mut ObserverCache,
+                      removed_list: &mut HashSet<Entity>,
                       parent: ParentWidget,
                       index: usize,
                       widget_type: String,
@@ -172,6 +175,7 @@ impl WidgetChildren {
                     let child_widget = widget_mapper.get_or_insert_entity_world(
                         world,
                         observer_cache,
+                        removed_list,
                         widget_type,
                         parent,
                         child_key,
@@ -278,7 +282,7 @@ impl WidgetChildren {
         self.parent_widget = Some(parent_widget);
     }
 
-    pub(crate) fn process_world(&mut self, world: &mut World) {
+    pub(crate) fn process_world(&mut self, world: &mut World, removed_list: &mut HashSet<Entity>) {
         let Some(parent_widget) = self.parent_widget else {
             return;
         };
@@ -318,6 +322,7 @@ impl WidgetChildren {
                             world,
                             &mut widget_mapper,
                             &mut observer_cache,
+                            removed_list,
                             parent_widget,
                             i,
                             widget_type.clone(),
@@ -3,7 +3,7 @@ use bevy::{
     prelude::*,
 };
 
-use crate::{context::Widget, runner::get_all_children, ObserverCache, ParentWidget};
+use crate::{context::Widget, runner::unmount_widget, ObserverCache, ParentWidget};
 
 /// Maps parent widgets to child widgets.
 /// Will overwrite old widgets with new entities if the type and or key has changed.
@@ -84,6 +84,7 @@ impl WidgetMapper {
         &mut self,
         world: &mut World,
         observer_cache: &mut ObserverCache,
+        removed_list: &mut HashSet<Entity>,
         widget_name: String,
         parent: ParentWidget,
         child_key: Option<String>,
@@ -94,31 +95,37 @@ impl WidgetMapper {
         } else {
             widget_name
         };
-        if let Some(child_vec) = self.parent_entity_to_child.get(&parent) {
-            if let Some(mapping) = child_vec.get(child_position_index) {
-                if key == mapping.key {
-                    self.new_this_tick.insert(mapping.entity);
-                    return mapping.entity;
-                } else {
+        if let Some(child_vec) = self.parent_entity_to_child.get_mut(&parent) {
+            // Keyed children are matched wherever they were so reordering doesn't remount them.
+            let position = if child_key.is_some() {
+                child_vec
+                    .iter()
+                    .skip(child_position_index)
+                    .position(|mapping| mapping.key == key)
+                    .map(|position| position + child_position_index)
+            } else {
+                child_vec
+                    .get(child_position_index)
+                    .filter(|mapping| mapping.key == key)
+                    .map(|_| child_position_index)
+            };
+            if let Some(position) = position {
+                let mapping = child_vec.remove(position);
+                let entity = mapping.entity;
+                child_vec.insert(child_position_index.min(child_vec.len()), mapping);
+                self.new_this_tick.insert(entity);
+                return entity;
+            } else if child_key.is_none() {
+                if let Some(mapping) = child_vec.get(child_position_index) {
                     let entity_to_remove = mapping.entity;
-                    // Remove observers
-                    observer_cache.despawn_for_target(world, entity_to_remove);
-
-                    // Remove from the mapper.
-                    self.remove_by_entity_id(parent.entity(), entity_to_remove);
-
-                    // Remove children of this child
-                    for child in get_all_children(world, entity_to_remove) {
-                        if world.get_entity(child).is_err() {
-                            continue;
-                        }
-                        let parent = world.entity(child).get::<ChildOf>().expect("Unknown dangling child! This is an error with woodpecker UI source please file a bug report.").parent();
-                        self.remove_by_entity_id(parent, child);
-                        observer_cache.despawn_for_target(world, child);
-                    }
-
-                    // Remove
-                    world.entity_mut(entity_to_remove).despawn();
+                    unmount_widget(
+                        world,
+                        self,
+                        observer_cache,
+                        parent.entity(),
+                        entity_to_remove,
+                        removed_list,
+                    );
                 }
             }
         }
@@ -139,3 +146,54 @@ impl WidgetMapper {
         }
     }
 }
+
+#[test]
+fn test_keyed_children() {
+    let mut world = World::new();
+    let mut observer_cache = ObserverCache::default();
+    let mut removed_list = HashSet::default();
+    let mut mapper = WidgetMapper::new();
+    let parent = ParentWidget(world.spawn_empty().id());
+    let mut child = |world: &mut World, mapper: &mut WidgetMapper, key: &str, index: usize| {
+        mapper.get_or_insert_entity_world(
+            world,
+            &mut observer_cache,
+            &mut removed_list,
+            "Element".into(),
+            parent,
+            Some(key.into()),
+            index,
+        )
+    };
+
+    let a = child(&mut world, &mut mapper, "a", 0);
+    let b = child(&mut world, &mut mapper, "b", 1);
+    let c = child(&mut world, &mut mapper, "c", 2);
+    mapper.clear_added_this_frame();
+
+    // Removing "b" keeps "c" mounted.
+    assert_eq!(child(&mut world, &mut mapper, "a", 0), a);
+    assert_eq!(child(&mut world, &mut mapper, "c", 1), c);
+    assert!(!mapper.added_this_frame(b));
+    assert_eq!(mapper.get_all_children(parent.entity()), vec![a, c, b]);
+    mapper.remove_by_entity_id(parent.entity(), b);
+    mapper.clear_added_this_frame();
+
+    // Reordering reuses the same entities.
+    assert_eq!(child(&mut world, &mut mapper, "c", 0), c);
+    assert_eq!(child(&mut world, &mut mapper, "a", 1), a);
+    assert_eq!(mapper.get_all_children(parent.entity()), vec![c, a]);
+    mapper.clear_added_this_frame();
+
+    // An unkeyed child in the place of another widget unmounts it.
+    mapper.get_or_insert_entity_world(
+        &mut world,
+        &mut observer_cache,
+        &mut removed_list,
+        "Button".into(),
+        parent,
+        None,
+        0,
+    );
+    assert!(removed_list.contains(&c));
+}
@@ -27,6 +27,16 @@ impl CurrentFocus {
         self.0 = entity;
     }
 
+    /// Focuses an entity and triggers [`WidgetFocus`] unless it already has focus,
+    /// ex: giving a widget focus back after clicking one of its children blurred it.
+    pub fn refocus(&mut self, commands: &mut Commands, entity: Entity) {
+        if self.0 == entity {
+            return;
+        }
+        self.0 = entity;
+        commands.trigger(WidgetFocus { target: entity });
+    }
+
     #[allow(dead_code)]
     pub(crate) fn find_next_focus() {
         // TODO: Write system to find next focused object
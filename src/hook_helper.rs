@@ -66,19 +66,54 @@ impl HookHelper {
         {
             context_entity
         } else {
-            let context_entity = commands
-                .spawn((StateMarker, initial_context))
-                .insert(ChildOf(*current_widget))
-                .id();
-
-            let context_types = self.internal_context.entry(*current_widget).or_default();
-
-            context_types.insert(type_name, context_entity);
+            self.spawn_context(commands, current_widget, type_name, initial_context)
+        }
+    }
 
+    /// Like use_context but only looks at the current widget, so the widget provides
+    /// its own context even when an ancestor provides one of the same type.
+    pub fn provide_context<T: Component>(
+        &mut self,
+        commands: &mut Commands,
+        current_widget: CurrentWidget,
+        initial_context: T,
+    ) -> Entity {
+        let type_name: String = std::any::type_name::<T>().into();
+        if let Some(context_entity) = self.get_own_context::<T>(current_widget) {
             context_entity
+        } else {
+            self.spawn_context(commands, current_widget, type_name, initial_context)
         }
     }
 
+    /// Looks for a T context provided by the widget itself, ignoring its ancestors.
+    pub fn get_own_context<T: Component>(&self, current_widget: CurrentWidget) -> Option<Entity> {
+        let type_name: String = std::any::type_name::<T>().into();
+        self.internal_context
+            .get(&*current_widget)
+            .and_then(|context_types| context_types.get(&type_name))
+            .copied()
+    }
+
+    fn spawn_context<T: Component>(
+        &mut self,
+        commands: &mut Commands,
+        current_widget: CurrentWidget,
+        type_name: String,
+        initial_context: T,
+    ) -> Entity {
+        let context_entity = commands
+            .spawn((StateMarker, initial_context))
+            .insert(ChildOf(*current_widget))
+            .id();
+
+        let context_types = self.internal_context.entry(*current_widget).or_default();
+
+        context_types.insert(type_name, context_entity);
+
+        context_entity
+    }
+
     /// Like use_context but does not spawn a new context entity it only
     /// looks for an existing one.
     pub fn get_context<T: Component>(&self, current_widget: CurrentWidget) -> Option<Entity> {
@@ -222,6 +222,8 @@ impl Plugin for WoodpeckerUIPlugin {
     fn build(&self, app: &mut App) {
         embedded_asset!(app, "embedded_assets/Poppins-Regular.ttf");
         embedded_asset!(app, "embedded_assets/icons/arrow-down.svg");
+        embedded_asset!(app, "embedded_assets/icons/arrow-left.svg");
+        embedded_asset!(app, "embedded_assets/icons/arrow-right.svg");
         embedded_asset!(app, "embedded_assets/icons/arrow-up.svg");
         embedded_asset!(app, "embedded_assets/icons/checkmark.svg");
         embedded_asset!(app, "embedded_assets/icons/copy-outline.svg");
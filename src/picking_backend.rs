@@ -1,5 +1,5 @@
 use bevy::{
-    input::mouse::MouseWheel,
+    input::mouse::{MouseScrollUnit, MouseWheel},
     picking::{
         backend::{HitData, PointerHits},
         hover::HoverMap,
@@ -12,6 +12,7 @@ use bevy::{
 use crate::{
     context::WoodpeckerContext,
     layout::system::WidgetLayout,
+    prelude::Exiting,
     styles::{WidgetVisibility, WoodpeckerStyle},
     WoodpeckerView,
 };
@@ -24,6 +25,7 @@ pub(crate) fn system(
     layout_query: Query<(&WidgetLayout, &WoodpeckerStyle)>,
     child_query: Query<&Children>,
     pickable_query: Query<&Pickable>,
+    exiting_query: Query<(), With<Exiting>>,
     mut output: EventWriter<PointerHits>,
     #[cfg(feature = "debug-render")] mut gizmos: Gizmos,
 ) {
@@ -62,6 +64,7 @@ pub(crate) fn system(
             &layout_query,
             &child_query,
             &pickable_query,
+            &exiting_query,
             &mut picks,
             total,
         );
@@ -84,9 +87,15 @@ fn process_entity(
     layout_query: &Query<(&WidgetLayout, &WoodpeckerStyle)>,
     child_query: &Query<&Children>,
     pickable_query: &Query<&Pickable>,
+    exiting_query: &Query<(), With<Exiting>>,
     pick_list: &mut Vec<(Entity, HitData)>,
     total: usize,
 ) {
+    // Widgets animating out can't be interacted with.
+    if exiting_query.contains(entity) {
+        return;
+    }
+
     if let Ok((layout, style)) = layout_query.get(entity) {
         // Don't even process children if a parent is hidden.
         if matches!(style.visibility, WidgetVisibility::Hidden) || style.opacity < 0.001 {
@@ -143,6 +152,7 @@ fn process_entity(
             layout_query,
             child_query,
             pickable_query,
+            exiting_query,
             pick_list,
             total,
         );
@@ -150,9 +160,42 @@ fn process_entity(
 }
 
 /// When a user scrolls the mouse wheel over an entity.
-#[derive(Debug, Default, Reflect, Clone, Copy)]
+///
+/// Triggered on the topmost hovered entity and bubbles up to its parents. Scroll boxes stop
+/// the propagation once they used all of the scroll, what's left at their edges goes on to the
+/// parents as pixels. Holding Shift turns vertical scrolling into horizontal scrolling.
+#[derive(Debug, Reflect, Clone, Copy)]
 pub struct MouseWheelScroll {
     pub scroll: Vec2,
+    /// Whether `scroll` is in lines or pixels
+    pub unit: MouseScrollUnit,
+}
+
+impl Default for MouseWheelScroll {
+    fn default() -> Self {
+        Self {
+            scroll: Vec2::ZERO,
+            unit: MouseScrollUnit::Line,
+        }
+    }
+}
+
+impl MouseWheelScroll {
+    /// The scroll amount in pixels, `line` is the size of a line in pixels.
+    pub fn pixels(&self, line: f32) -> Vec2 {
+        match self.unit {
+            MouseScrollUnit::Line => self.scroll * line,
+            MouseScrollUnit::Pixel => self.scroll,
+        }
+    }
+
+    /// The scroll amount in lines, `line` is the size of a line in pixels.
+    pub fn lines(&self, line: f32) -> Vec2 {
+        match self.unit {
+            MouseScrollUnit::Line => self.scroll,
+            MouseScrollUnit::Pixel => self.scroll / line.max(1.0),
+        }
+    }
 }
 
 pub fn mouse_wheel_system(
@@ -163,6 +206,7 @@ pub fn mouse_wheel_system(
     pointers: Query<&PointerLocation>,
     // Bevy Input
     mut evr_scroll: EventReader<MouseWheel>,
+    keys: Res<ButtonInput<KeyCode>>,
 ) {
     let pointer_location = |pointer_id: PointerId| {
         pointer_map
@@ -171,10 +215,38 @@ pub fn mouse_wheel_system(
             .and_then(|pointer| pointer.location.clone())
     };
 
-    for (pointer_id, hovered_entity, _hit) in hover_map
-        .iter()
-        .flat_map(|(id, hashmap)| hashmap.iter().map(|data| (*id, *data.0, data.1.clone())))
-    {
+    // Read the events once, every pointer gets all of them.
+    let shift = keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+    let wheel_events = evr_scroll
+        .read()
+        .map(|mwe| {
+            let scroll = if shift && mwe.x == 0.0 {
+                // Scrolling down shows more of the content on the right.
+                Vec2::new(-mwe.y, 0.0)
+            } else {
+                Vec2::new(mwe.x, mwe.y)
+            };
+            MouseWheelScroll {
+                scroll,
+                unit: mwe.unit,
+            }
+        })
+        .collect::<Vec<_>>();
+    if wheel_events.is_empty() {
+        return;
+    }
+
+    for (pointer_id, hits) in hover_map.iter() {
+        // The event bubbles, so only the topmost entity gets it. Otherwise the parents of
+        // hovered entities that don't block the ones below would get it twice.
+        let Some(hovered_entity) = hits
+            .iter()
+            .min_by(|(_, a), (_, b)| a.depth.total_cmp(&b.depth))
+            .map(|(entity, _)| *entity)
+        else {
+            continue;
+        };
+        let pointer_id = *pointer_id;
         let Some(location) = pointer_location(pointer_id) else {
             debug!(
                 "Unable to get location for pointer {:?} during pointer over",
@@ -183,15 +255,9 @@ pub fn mouse_wheel_system(
             continue;
         };
 
-        for mwe in evr_scroll.read() {
-            let scroll = Vec2::new(mwe.x, mwe.y);
+        for wheel_event in wheel_events.iter() {
             commands.trigger(
-                Pointer::new(
-                    pointer_id,
-                    location.clone(),
-                    MouseWheelScroll { scroll },
-                    hovered_entity,
-                ),
+                Pointer::new(pointer_id, location.clone(), *wheel_event, hovered_entity),
                 //hovered_entity,
             );
         }
@@ -18,7 +18,7 @@ use crate::{
     context::Widget,
     hook_helper::StateMarker,
     metrics::WidgetMetrics,
-    prelude::{PreviousWidget, WidgetMapper},
+    prelude::{ExitTransition, Exiting, PreviousWidget, WidgetMapper, WoodpeckerStyle},
     CurrentWidget, ObserverCache, WoodpeckerContext,
 };
 
@@ -31,6 +31,9 @@ pub(crate) fn system(world: &mut World) {
     let mut widget_query_state =
         QueryState::<One<&dyn Widget>, Without<PreviousWidget>>::new(world);
 
+    let mut removed_list = HashSet::default();
+    despawn_exited(world, &mut removed_list);
+
     // STEP 1: Run update systems and mark widgets as needing to be re-rendered
     // Note: re-rendering means to re-build the sub-tree at X point in the tree.
     world.resource_scope(|_world: &mut World, mut widget_mapper: Mut<WidgetMapper>| {
@@ -55,7 +58,6 @@ pub(crate) fn system(world: &mut World) {
             .collect::<Vec<_>>()
     };
 
-    let mut removed_list = HashSet::default();
     let mut metrics = world.remove_resource::<WidgetMetrics>().unwrap();
     metrics.clear_last_frame();
 
@@ -126,6 +128,116 @@ fn update_widgets(
     }
 }
 
+/// Removes a widget that its parent no longer renders.
+///
+/// Widgets with an [`ExitTransition`] are kept alive until it has played.
+pub(crate) fn unmount_widget(
+    world: &mut World,
+    widget_mapper: &mut WidgetMapper,
+    observer_cache: &mut ObserverCache,
+    parent: Entity,
+    widget: Entity,
+    removed_list: &mut HashSet<Entity>,
+) {
+    // Remove observers
+    observer_cache.despawn_for_target(world, widget);
+
+    // Remove from the mapper.
+    widget_mapper.remove_by_entity_id(parent, widget);
+
+    let entity = world.entity(widget);
+    if entity.contains::<ExitTransition>() && !entity.contains::<Exiting>() {
+        let style = entity.get::<WoodpeckerStyle>().copied().unwrap_or_default();
+        world.entity_mut(widget).insert(Exiting::new(style));
+        return;
+    }
+
+    despawn_widget(world, widget_mapper, observer_cache, widget, removed_list);
+}
+
+/// Despawns a widget and removes its descendants from the mapper and observer cache.
+fn despawn_widget(
+    world: &mut World,
+    widget_mapper: &mut WidgetMapper,
+    observer_cache: &mut ObserverCache,
+    widget: Entity,
+    removed_list: &mut HashSet<Entity>,
+) {
+    // Despawn and despawn recursive.
+    removed_list.insert(widget);
+    // Entity and its children were despawned lets make sure all of the descendants are removed from the mapper!
+    for child in get_all_children(world, widget) {
+        observer_cache.despawn_for_target(world, child);
+        let parent = world.entity(child).get::<ChildOf>().expect("Unknown dangling child! This is an error with woodpecker UI source please file a bug report.").parent();
+        widget_mapper.remove_by_entity_id(parent, child);
+        removed_list.insert(child);
+    }
+    // Do this last so the parent query still works.
+    if world.get_entity(widget).is_ok() {
+        world.entity_mut(widget).despawn();
+    }
+}
+
+/// Sorts the bevy children of a widget to match the order it rendered them in.
+///
+/// Entities that aren't mapped, like state or exiting widgets, keep their place.
+fn order_children(world: &mut World, widget_mapper: &mut WidgetMapper, parent: Entity) {
+    let mapped = widget_mapper.get_all_children(parent);
+    let Some(mut children) = world.get_mut::<Children>(parent) else {
+        return;
+    };
+    let mut next = mapped.iter();
+    let order = children
+        .iter()
+        .map(|child| {
+            if mapped.contains(&child) {
+                next.next().copied().unwrap_or(child)
+            } else {
+                child
+            }
+        })
+        .collect::<Vec<_>>();
+    if children.iter().eq(order.iter().copied()) {
+        return;
+    }
+    let index = order
+        .iter()
+        .enumerate()
+        .map(|(i, entity)| (*entity, i))
+        .collect::<HashMap<_, _>>();
+    children.sort_by_key(|child| index.get(child).copied().unwrap_or(usize::MAX));
+}
+
+/// Despawns widgets that finished their [`ExitTransition`].
+fn despawn_exited(world: &mut World, removed_list: &mut HashSet<Entity>) {
+    let mut query = world.query::<(Entity, &Exiting)>();
+    let exited = query
+        .iter(world)
+        .filter(|(_, exiting)| exiting.is_finished())
+        .map(|(entity, _)| entity)
+        .collect::<Vec<_>>();
+    if exited.is_empty() {
+        return;
+    }
+    world.resource_scope(|world: &mut World, mut widget_mapper: Mut<WidgetMapper>| {
+        world.resource_scope(
+            |world: &mut World, mut observer_cache: Mut<ObserverCache>| {
+                for entity in exited {
+                    if world.get_entity(entity).is_ok() {
+                        despawn_widget(
+                            world,
+                            &mut widget_mapper,
+                            &mut observer_cache,
+                            entity,
+                            removed_list,
+                        );
+                    }
+                }
+            },
+        );
+    });
+}
+
 // Recursively gets all widget children down the tree for a given entity.
 pub fn get_all_children(world: &mut World, parent_entity: Entity) -> Vec<Entity> {
     let mut children = vec![];
@@ -255,45 +367,38 @@ fn run_render_system(
         .get::<WidgetChildren>()
         .cloned()
     {
-        children.process_world(world);
+        children.process_world(world, removed_list);
         world.entity_mut(widget_entity).insert(children);
     }
 
     // STEP 5: Despawn unmounted widgets.
     world.resource_scope(|world: &mut World, mut widget_mapper: Mut<WidgetMapper>| {
-        // Note: Children here are only the immediate children attached to the parent(widget_entity).
-        let children = widget_mapper.get_all_children(widget_entity);
-        for child in children.iter() {
-            // Only remove if the child was not added this frame.
-            if !widget_mapper.added_this_frame(*child) {
-                trace!("Removing: {child}");
-
-                if world.get_entity(*child).is_err() {
-                    panic!("Error: Attempted to despawn an entity already despawned. :( Widget entities should never manually be removed. This might be a bug with the widget runner backend, please file a ticket!");
-                }
+        world.resource_scope(|world: &mut World, mut observer_cache: Mut<ObserverCache>| {
+            // Note: Children here are only the immediate children attached to the parent(widget_entity).
+            let children = widget_mapper.get_all_children(widget_entity);
+            for child in children.iter() {
+                // Only remove if the child was not added this frame.
+                if !widget_mapper.added_this_frame(*child) {
+                    trace!("Removing: {child}");
 
-                // Remove observers
-                world.resource_scope(
-                    |world: &mut World, mut observer_cache: Mut<ObserverCache>| {
-                    observer_cache.despawn_for_target(world, *child);
-                });
-
-                // Remove from the mapper.
-                widget_mapper.remove_by_entity_id(widget_entity, *child);
-                // Despawn and despawn recursive.
-                removed_list.insert(*child);
-                // Entity and its children were despawned lets make sure all of the descendants are removed from the mapper!
-                for child in get_all_children(world, *child) {
-                    let parent = world.entity(child).get::<ChildOf>().expect("Unknown dangling child! This is an error with woodpecker UI source please file a bug report.").parent();
-                    widget_mapper.remove_by_entity_id(parent, child);
-                    removed_list.insert(child);
-                }
-                // Do this last so the parent query still works.
-                if world.get_entity(*child).is_ok() {
-                    world.entity_mut(*child).despawn();
+                    if world.get_entity(*child).is_err() {
+                        panic!("Error: Attempted to despawn an entity already despawned. :( Widget entities should never manually be removed. This might be a bug with the widget runner backend, please file a ticket!");
+                    }
+
+                    unmount_widget(
+                        world,
+                        &mut widget_mapper,
+                        &mut observer_cache,
+                        widget_entity,
+                        *child,
+                        removed_list,
+                    );
                 }
             }
-        }
+        });
+
+        // Keyed children may have moved, keep the layout order in sync.
+        order_children(world, &mut widget_mapper, widget_entity);
     });
 
     // A this point we should have initialized both the update and render systems.
@@ -345,61 +345,114 @@ impl WoodpeckerStyle {
 
     /// Lerps between two styles.
     ///
-    /// Note: Only lerps: border_color, color, font_size, height, max_height, width,
-    /// max_width, min_width, min_height, left, bottom, right, top, and opacity currrently.
+    /// Colors, numbers and units of the same kind are interpolated. Units of different
+    /// kinds, ex: pixels and a percentage, can't be resolved without a layout so they
+    /// switch halfway like any other discrete field, see [`WoodpeckerStyle::lerp_resolved`].
+    ///
+    /// `display` and `visibility` stay visible for the whole animation when one of the
+    /// styles is hidden, so elements fade in and out instead of popping.
     pub fn lerp(&self, b: &WoodpeckerStyle, x: f32) -> WoodpeckerStyle {
-        let mut new_styles = *self; // Default to A styles.
-
-        new_styles.background_color = hsv_lerp(&self.background_color, &b.background_color, x);
-
-        // new_styles.border = Edge::new(
-        //     lerp_units(self.border.top, b.top, x),
-        //     lerp_units(self.border.right, b.right, x),
-        //     lerp_units(self.border.bottom, b.bottom, x),
-        //     lerp_units(self.border.left, b.left, x),
-        // );
-
-        new_styles.border_color = hsv_lerp(&self.border_color, &b.border_color, x);
-
-        // new_styles.border_radius = Corner::new(
-        //     lerp_units(self.border_radius.top_left, b.border_radius.top_left, x),
-        //     lerp_units(self.border_radius.top_right, b.border_radius.top_right, x),
-        //     lerp_units(
-        //         self.border_radius.bottom_left,
-        //         b.border_radius.bottom_left,
-        //         x,
-        //     ),
-        //     lerp_units(
-        //         self.border_radius.bottom_right,
-        //         b.border_radius.bottom_right,
-        //         x,
-        //     ),
-        // );
-
-        new_styles.color = hsv_lerp(&self.color, &b.color, x);
-
-        new_styles.font_size = lerp(self.font_size, b.font_size, x);
-        new_styles.height = lerp_units(self.height, b.height, x);
-        new_styles.max_height = lerp_units(self.max_height, b.max_height, x);
-        new_styles.max_width = lerp_units(self.max_width, b.max_width, x);
-        new_styles.min_height = lerp_units(self.min_height, b.min_height, x);
-        new_styles.min_width = lerp_units(self.min_width, b.min_width, x);
-
-        // new_styles.padding = Edge::new(
-        //     lerp_units(self.padding.top, b.padding.top, x),
-        //     lerp_units(self.padding.right, b.padding.right, x),
-        //     lerp_units(self.padding.bottom, b.padding.bottom, x),
-        //     lerp_units(self.padding.left, b.padding.left, x),
-        // );
-
-        new_styles.left = lerp_units(self.left, b.left, x);
-        new_styles.right = lerp_units(self.right, b.right, x);
-        new_styles.top = lerp_units(self.top, b.top, x);
-        new_styles.bottom = lerp_units(self.bottom, b.bottom, x);
-        new_styles.width = lerp_units(self.width, b.width, x);
-        new_styles.opacity = lerp(self.opacity, b.opacity, x);
-
-        new_styles
+        self.lerp_with(b, x, None)
+    }
+
+    /// Lerps between two styles resolving pixel to percentage transitions against
+    /// the size of the parent widget.
+    ///
+    /// Horizontal properties, padding and margin resolve against the parent width and
+    /// vertical properties against the parent height, like CSS.
+    pub fn lerp_resolved(&self, b: &WoodpeckerStyle, x: f32, parent_size: Vec2) -> WoodpeckerStyle {
+        self.lerp_with(b, x, Some(parent_size))
+    }
+
+    fn lerp_with(&self, b: &WoodpeckerStyle, x: f32, parent_size: Option<Vec2>) -> WoodpeckerStyle {
+        let horizontal = parent_size.map(|size| size.x);
+        let vertical = parent_size.map(|size| size.y);
+
+        WoodpeckerStyle {
+            // Layout
+            height: lerp_units(self.height, b.height, x, vertical),
+            max_height: lerp_units(self.max_height, b.max_height, x, vertical),
+            max_width: lerp_units(self.max_width, b.max_width, x, horizontal),
+            min_height: lerp_units(self.min_height, b.min_height, x, vertical),
+            min_width: lerp_units(self.min_width, b.min_width, x, horizontal),
+            padding: lerp_edge(self.padding, b.padding, x, horizontal),
+            width: lerp_units(self.width, b.width, x, horizontal),
+            display: match (self.display, b.display) {
+                (display, WidgetDisplay::None) if x < 1.0 => display,
+                (WidgetDisplay::None, display) if x > 0.0 => display,
+                (a, b) => discrete(a, b, x),
+            },
+            visibility: match (self.visibility, b.visibility) {
+                (WidgetVisibility::Visible, _) if x < 1.0 => WidgetVisibility::Visible,
+                (_, WidgetVisibility::Visible) if x > 0.0 => WidgetVisibility::Visible,
+                (a, b) => discrete(a, b, x),
+            },
+            overflow: discrete(self.overflow, b.overflow, x),
+            position: discrete(self.position, b.position, x),
+            left: lerp_units(self.left, b.left, x, horizontal),
+            right: lerp_units(self.right, b.right, x, horizontal),
+            top: lerp_units(self.top, b.top, x, vertical),
+            bottom: lerp_units(self.bottom, b.bottom, x, vertical),
+            margin: lerp_edge(self.margin, b.margin, x, horizontal),
+            align_items: discrete(self.align_items, b.align_items, x),
+            align_self: discrete(self.align_self, b.align_self, x),
+            justify_items: discrete(self.justify_items, b.justify_items, x),
+            justify_self: discrete(self.justify_self, b.justify_self, x),
+            align_content: discrete(self.align_content, b.align_content, x),
+            justify_content: discrete(self.justify_content, b.justify_content, x),
+            gap: (
+                lerp_units(self.gap.0, b.gap.0, x, horizontal),
+                lerp_units(self.gap.1, b.gap.1, x, vertical),
+            ),
+            flex_direction: discrete(self.flex_direction, b.flex_direction, x),
+            flex_wrap: discrete(self.flex_wrap, b.flex_wrap, x),
+            flex_basis: lerp_units(self.flex_basis, b.flex_basis, x, horizontal),
+            flex_grow: lerp(self.flex_grow, b.flex_grow, x).max(0.0),
+            flex_shrink: lerp(self.flex_shrink, b.flex_shrink, x).max(0.0),
+            // Rendering
+            background_color: hsv_lerp(&self.background_color, &b.background_color, x),
+            border_color: hsv_lerp(&self.border_color, &b.border_color, x),
+            // A radius percentage is relative to the widget itself, not its parent.
+            border_radius: Corner::new(
+                lerp_units(
+                    self.border_radius.top_left,
+                    b.border_radius.top_left,
+                    x,
+                    None,
+                ),
+                lerp_units(
+                    self.border_radius.top_right,
+                    b.border_radius.top_right,
+                    x,
+                    None,
+                ),
+                lerp_units(
+                    self.border_radius.bottom_left,
+                    b.border_radius.bottom_left,
+                    x,
+                    None,
+                ),
+                lerp_units(
+                    self.border_radius.bottom_right,
+                    b.border_radius.bottom_right,
+                    x,
+                    None,
+                ),
+            ),
+            border: lerp_edge(self.border, b.border, x, horizontal),
+            color: hsv_lerp(&self.color, &b.color, x),
+            font: discrete(self.font, b.font, x),
+            font_size: lerp(self.font_size, b.font_size, x),
+            text_wrap: discrete(self.text_wrap, b.text_wrap, x),
+            line_height: match (self.line_height, b.line_height) {
+                (Some(a), Some(b)) => Some(lerp(a, b, x)),
+                (a, b) => discrete(a, b, x),
+            },
+            opacity: lerp(self.opacity, b.opacity, x),
+            text_alignment: discrete(self.text_alignment, b.text_alignment, x),
+            image_quality: discrete(self.image_quality, b.image_quality, x),
+            z_index: discrete(self.z_index, b.z_index, x),
+        }
     }
 }
 
@@ -454,9 +507,9 @@ impl From<WoodpeckerStyle> for taffy::Style {
     }
 }
 
-fn lerp_units(prop_a: Units, prop_b: Units, x: f32) -> Units {
-    match (prop_a, prop_b) {
-        (Units::Pixels(a), Units::Pixels(b)) => {
+fn lerp_units(prop_a: Units, prop_b: Units, x: f32, basis: Option<f32>) -> Units {
+    match (prop_a, prop_b, basis) {
+        (Units::Pixels(a), Units::Pixels(b), _) => {
             let mut value = lerp(a, b, x);
             if a > b {
                 value = value.clamp(b, a);
@@ -465,19 +518,47 @@ fn lerp_units(prop_a: Units, prop_b: Units, x: f32) -> Units {
             }
             Units::Pixels(value)
         }
-        (Units::Percentage(a), Units::Percentage(b)) => Units::Percentage(if a > b {
+        (Units::Percentage(a), Units::Percentage(b), _) => Units::Percentage(if a > b {
             lerp(a, b, x).clamp(b, a)
         } else {
             lerp(a, b, x).clamp(a, b)
         }),
-        _ => {
-            bevy::prelude::trace!(
-                "Cannot lerp between non-matching units! Unit_A: {:?}, Unit_B: {:?}",
-                prop_a,
-                prop_b
-            );
-            prop_b
+        // Mixed units animate in pixels and land on the exact target.
+        (
+            Units::Pixels(_) | Units::Percentage(_),
+            Units::Pixels(_) | Units::Percentage(_),
+            Some(basis),
+        ) if x > 0.0 && x < 1.0 => {
+            let resolve = |units: Units| match units {
+                Units::Percentage(percentage) => percentage / 100.0 * basis,
+                units => units.value_or(0.0),
+            };
+            lerp_units(
+                Units::Pixels(resolve(prop_a)),
+                Units::Pixels(resolve(prop_b)),
+                x,
+                None,
+            )
         }
+        _ => discrete(prop_a, prop_b, x),
+    }
+}
+
+fn lerp_edge(a: Edge, b: Edge, x: f32, basis: Option<f32>) -> Edge {
+    Edge::new(
+        lerp_units(a.top, b.top, x, basis),
+        lerp_units(a.right, b.right, x, basis),
+        lerp_units(a.bottom, b.bottom, x, basis),
+        lerp_units(a.left, b.left, x, basis),
+    )
+}
+
+/// Values that can't be interpolated switch halfway through.
+fn discrete<T>(a: T, b: T, x: f32) -> T {
+    if x < 0.5 {
+        a
+    } else {
+        b
     }
 }
 
@@ -594,3 +675,122 @@ fn hsv_lerp(from: &Color, to: &Color, amount: f32) -> Color {
 pub(crate) fn lerp(a: f32, b: f32, x: f32) -> f32 {
     a * (1.0 - x) + b * x
 }
+
+#[test]
+fn test_lerp_numeric_fields() {
+    let a = WoodpeckerStyle {
+        padding: Edge::all(0.0),
+        margin: Edge::all(10.0),
+        border: Edge::all(0.0),
+        border_radius: Corner::all(0.0),
+        gap: (Units::Pixels(0.0), Units::Pixels(0.0)),
+        flex_basis: Units::Percentage(0.0),
+        flex_grow: 0.0,
+        flex_shrink: 1.0,
+        line_height: Some(10.0),
+        ..Default::default()
+    };
+    let b = WoodpeckerStyle {
+        padding: Edge::all(10.0),
+        margin: Edge::all(0.0),
+        border: Edge::new(2.0, 4.0, 6.0, 8.0),
+        border_radius: Corner::all(8.0),
+        gap: (Units::Pixels(10.0), Units::Pixels(20.0)),
+        flex_basis: Units::Percentage(100.0),
+        flex_grow: 2.0,
+        flex_shrink: 0.0,
+        line_height: Some(20.0),
+        ..Default::default()
+    };
+    let half = a.lerp(&b, 0.5);
+    assert_eq!(half.padding, Edge::all(5.0));
+    assert_eq!(half.margin, Edge::all(5.0));
+    assert_eq!(half.border, Edge::new(1.0, 2.0, 3.0, 4.0));
+    assert_eq!(half.border_radius, Corner::all(4.0));
+    assert_eq!(half.gap, (Units::Pixels(5.0), Units::Pixels(10.0)));
+    assert_eq!(half.flex_basis, Units::Percentage(50.0));
+    assert_eq!(half.flex_grow, 1.0);
+    assert_eq!(half.flex_shrink, 0.5);
+    assert_eq!(half.line_height, Some(15.0));
+
+    assert_eq!(a.lerp(&b, 0.0).padding, a.padding);
+    assert_eq!(a.lerp(&b, 1.0).line_height, b.line_height);
+}
+
+#[test]
+fn test_lerp_mixed_units() {
+    let a = WoodpeckerStyle {
+        width: Units::Pixels(0.0),
+        height: Units::Pixels(0.0),
+        left: Units::Auto,
+        ..Default::default()
+    };
+    let b = WoodpeckerStyle {
+        width: Units::Percentage(50.0),
+        height: Units::Percentage(50.0),
+        left: Units::Pixels(10.0),
+        ..Default::default()
+    };
+
+    // Without a layout mixed units switch halfway.
+    assert_eq!(a.lerp(&b, 0.25).width, Units::Pixels(0.0));
+    assert_eq!(a.lerp(&b, 0.5).width, Units::Percentage(50.0));
+
+    // Resolved against the parent, width uses the parent width and height the parent height.
+    let parent_size = Vec2::new(200.0, 100.0);
+    let half = a.lerp_resolved(&b, 0.5, parent_size);
+    assert_eq!(half.width, Units::Pixels(50.0));
+    assert_eq!(half.height, Units::Pixels(25.0));
+    // Auto can't be resolved.
+    assert_eq!(half.left, Units::Pixels(10.0));
+    assert_eq!(a.lerp_resolved(&b, 0.25, parent_size).left, Units::Auto);
+    // Lands exactly on the target units.
+    let end = a.lerp_resolved(&b, 1.0, parent_size);
+    assert_eq!(end.width, Units::Percentage(50.0));
+}
+
+#[test]
+fn test_lerp_discrete_fields() {
+    let a = WoodpeckerStyle {
+        position: WidgetPosition::Relative,
+        line_height: None,
+        z_index: None,
+        ..Default::default()
+    };
+    let b = WoodpeckerStyle {
+        position: WidgetPosition::Absolute,
+        line_height: Some(20.0),
+        z_index: Some(WidgetZ::Global(10)),
+        ..Default::default()
+    };
+    assert_eq!(a.lerp(&b, 0.49).position, WidgetPosition::Relative);
+    assert_eq!(a.lerp(&b, 0.5).position, WidgetPosition::Absolute);
+    assert_eq!(a.lerp(&b, 0.25).line_height, None);
+    assert_eq!(a.lerp(&b, 0.75).line_height, Some(20.0));
+    assert_eq!(a.lerp(&b, 0.75).z_index, Some(WidgetZ::Global(10)));
+
+    // Hidden elements stay visible until the end of the animation.
+    let shown = WoodpeckerStyle::default();
+    let hidden = WoodpeckerStyle {
+        display: WidgetDisplay::None,
+        visibility: WidgetVisibility::Hidden,
+        ..Default::default()
+    };
+    assert_eq!(shown.lerp(&hidden, 0.99).display, WidgetDisplay::Flex);
+    assert_eq!(shown.lerp(&hidden, 1.0).display, WidgetDisplay::None);
+    assert_eq!(
+        shown.lerp(&hidden, 0.99).visibility,
+        WidgetVisibility::Visible
+    );
+    assert_eq!(
+        shown.lerp(&hidden, 1.0).visibility,
+        WidgetVisibility::Hidden
+    );
+    // And become visible as soon as it starts.
+    assert_eq!(hidden.lerp(&shown, 0.0).display, WidgetDisplay::None);
+    assert_eq!(hidden.lerp(&shown, 0.01).display, WidgetDisplay::Flex);
+    assert_eq!(
+        hidden.lerp(&shown, 0.01).visibility,
+        WidgetVisibility::Visible
+    );
+}
@@ -0,0 +1,618 @@
+use bevy::prelude::*;
+
+use crate::prelude::*;
+
+/// A keyframe in an [`Animation`].
+#[derive(Debug, Reflect, Clone, Copy, PartialEq)]
+pub struct Keyframe {
+    /// Where the keyframe sits in the animation, from 0.0 to 1.0.
+    pub offset: f32,
+    /// The styles at this keyframe.
+    pub style: WoodpeckerStyle,
+    /// The easing used from this keyframe to the next one.
+    pub easing: TransitionEasing,
+}
+
+/// How many times an animation plays.
+#[derive(Debug, Reflect, Clone, Copy, PartialEq)]
+pub enum AnimationIterations {
+    /// Plays a fixed number of times.
+    Count(u32),
+    /// Plays forever.
+    Infinite,
+}
+
+impl Default for AnimationIterations {
+    fn default() -> Self {
+        Self::Count(1)
+    }
+}
+
+/// The direction each iteration of an animation plays in.
+#[derive(Default, Debug, Reflect, Clone, Copy, PartialEq)]
+pub enum AnimationDirection {
+    /// Every iteration plays forwards.
+    #[default]
+    Normal,
+    /// Every iteration plays backwards.
+    Reverse,
+    /// Iterations alternate, starting forwards.
+    Alternate,
+    /// Iterations alternate, starting backwards.
+    AlternateReverse,
+}
+
+/// Which styles are applied outside of the time an animation is playing.
+#[derive(Default, Debug, Reflect, Clone, Copy, PartialEq)]
+pub enum AnimationFillMode {
+    /// The widget keeps its own styles before and after the animation.
+    #[default]
+    None,
+    /// The styles of the last keyframe played are kept after the animation.
+    Forwards,
+    /// The styles of the first keyframe are applied during the delay.
+    Backwards,
+    /// Both forwards and backwards.
+    Both,
+}
+
+/// Triggered on the animated entity when an [`Animation`] starts a new iteration.
+#[derive(Clone, PartialEq, Debug, Reflect, EntityEvent)]
+pub struct AnimationIteration {
+    /// The target of this event
+    #[event_target]
+    pub target: Entity,
+    /// The index of the iteration that started.
+    pub iteration: u32,
+}
+
+/// Triggered on the animated entity when an [`Animation`] finishes.
+#[derive(Clone, PartialEq, Debug, Reflect, EntityEvent)]
+pub struct AnimationCompleted {
+    /// The target of this event
+    #[event_target]
+    pub target: Entity,
+}
+
+/// What happened while advancing an animation.
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub(crate) struct AnimationStep {
+    pub(crate) iteration: Option<u32>,
+    pub(crate) completed: bool,
+}
+
+/// A keyframe animation bevy component that animates the styles of Woodpecker UI widgets.
+///
+/// Unlike [`Transition`] an animation can have any number of keyframes.
+/// All times are in milliseconds. The animation is played by an [`AnimationPlayback`]
+/// added next to it, query that to pause, seek or restart it.
+///
+/// ```ignore
+/// Animation::new(1000.0)
+///     .with_keyframe(0.0, style_a)
+///     .with_eased_keyframe(0.5, style_b, TransitionEasing::CubicOut)
+///     .with_keyframe(1.0, style_c)
+///     .with_iterations(AnimationIterations::Infinite)
+///     .with_direction(AnimationDirection::Alternate);
+/// ```
+#[derive(Component, Debug, Reflect, Clone, PartialEq)]
+pub struct Animation {
+    /// The keyframes, sorted by offset.
+    pub keyframes: Vec<Keyframe>,
+    /// The duration of a single iteration.
+    pub duration: f32,
+    /// The time before the first iteration starts.
+    pub delay: f32,
+    /// How many times the animation plays.
+    pub iterations: AnimationIterations,
+    /// The direction of each iteration.
+    pub direction: AnimationDirection,
+    /// Which styles are applied before and after the animation.
+    pub fill_mode: AnimationFillMode,
+    /// How fast the animation plays, negative values play it backwards.
+    pub playback_rate: f32,
+    /// Does the animation start paused?
+    pub paused: bool,
+}
+
+impl Default for Animation {
+    fn default() -> Self {
+        Self {
+            keyframes: vec![],
+            duration: 0.0,
+            delay: 0.0,
+            iterations: Default::default(),
+            direction: Default::default(),
+            fill_mode: Default::default(),
+            playback_rate: 1.0,
+            paused: false,
+        }
+    }
+}
+
+impl Animation {
+    /// Creates a new animation with a duration for a single iteration.
+    pub fn new(duration: f32) -> Self {
+        Self {
+            duration,
+            ..Default::default()
+        }
+    }
+
+    /// Adds a linear keyframe.
+    pub fn with_keyframe(self, offset: f32, style: WoodpeckerStyle) -> Self {
+        self.with_eased_keyframe(offset, style, TransitionEasing::Linear)
+    }
+
+    /// Adds a keyframe with the easing used up until the next keyframe.
+    pub fn with_eased_keyframe(
+        mut self,
+        offset: f32,
+        style: WoodpeckerStyle,
+        easing: TransitionEasing,
+    ) -> Self {
+        let offset = offset.clamp(0.0, 1.0);
+        let index = self
+            .keyframes
+            .partition_point(|keyframe| keyframe.offset <= offset);
+        self.keyframes.insert(
+            index,
+            Keyframe {
+                offset,
+                style,
+                easing,
+            },
+        );
+        self
+    }
+
+    /// Sets the delay before the animation starts.
+    pub fn with_delay(mut self, delay: f32) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Sets how many times the animation plays.
+    pub fn with_iterations(mut self, iterations: AnimationIterations) -> Self {
+        self.iterations = iterations;
+        self
+    }
+
+    /// Sets the direction of each iteration.
+    pub fn with_direction(mut self, direction: AnimationDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Sets which styles are applied before and after the animation.
+    pub fn with_fill_mode(mut self, fill_mode: AnimationFillMode) -> Self {
+        self.fill_mode = fill_mode;
+        self
+    }
+
+    /// Sets the playback rate.
+    pub fn with_playback_rate(mut self, playback_rate: f32) -> Self {
+        self.playback_rate = playback_rate;
+        self
+    }
+
+    /// Creates the animation paused.
+    pub fn paused(mut self) -> Self {
+        self.paused = true;
+        self
+    }
+
+    /// The time all of the iterations take.
+    pub fn active_duration(&self) -> f32 {
+        match self.iterations {
+            AnimationIterations::Count(count) => self.duration.max(0.0) * count as f32,
+            AnimationIterations::Infinite => f32::INFINITY,
+        }
+    }
+
+    fn end_time(&self) -> f32 {
+        self.delay.max(0.0) + self.active_duration()
+    }
+
+    fn last_iteration(&self) -> u32 {
+        match self.iterations {
+            AnimationIterations::Count(count) => count.saturating_sub(1),
+            AnimationIterations::Infinite => u32::MAX,
+        }
+    }
+
+    fn iteration_at(&self, time: f32) -> Option<u32> {
+        let local = time - self.delay.max(0.0);
+        if local < 0.0 {
+            return None;
+        }
+        if self.duration <= 0.0 {
+            return Some(self.last_iteration());
+        }
+        Some(((local / self.duration) as u32).min(self.last_iteration()))
+    }
+
+    fn directed(&self, iteration: u32, progress: f32) -> f32 {
+        let reverse = match self.direction {
+            AnimationDirection::Normal => false,
+            AnimationDirection::Reverse => true,
+            AnimationDirection::Alternate => iteration % 2 == 1,
+            AnimationDirection::AlternateReverse => iteration % 2 == 0,
+        };
+        if reverse {
+            1.0 - progress
+        } else {
+            progress
+        }
+    }
+
+    /// The keyframe progress at a time, `None` if the animation doesn't apply.
+    fn progress_at(&self, time: f32) -> Option<f32> {
+        let local = time - self.delay.max(0.0);
+        if local < 0.0 {
+            return matches!(
+                self.fill_mode,
+                AnimationFillMode::Backwards | AnimationFillMode::Both
+            )
+            .then(|| self.directed(0, 0.0));
+        }
+        if local >= self.active_duration() {
+            if !matches!(
+                self.fill_mode,
+                AnimationFillMode::Forwards | AnimationFillMode::Both
+            ) {
+                return None;
+            }
+            // Zero iterations never leave the start.
+            if self.iterations == AnimationIterations::Count(0) {
+                return Some(self.directed(0, 0.0));
+            }
+            return Some(self.directed(self.last_iteration(), 1.0));
+        }
+        if self.duration <= 0.0 {
+            return Some(self.directed(self.last_iteration(), 1.0));
+        }
+        let iteration = (local / self.duration) as u32;
+        Some(self.directed(iteration, (local % self.duration) / self.duration))
+    }
+
+    /// Interpolates the keyframes at a progress from 0.0 to 1.0.
+    pub fn sample(&self, progress: f32) -> Option<WoodpeckerStyle> {
+        let first = self.keyframes.first()?;
+        let last = self.keyframes.last()?;
+        if progress <= first.offset {
+            return Some(first.style);
+        }
+        if progress >= last.offset {
+            return Some(last.style);
+        }
+        let index = self
+            .keyframes
+            .windows(2)
+            .position(|pair| progress <= pair[1].offset)?;
+        let (a, b) = (&self.keyframes[index], &self.keyframes[index + 1]);
+        let span = b.offset - a.offset;
+        let x = if span <= 0.0 {
+            1.0
+        } else {
+            (progress - a.offset) / span
+        };
+        Some(a.style.lerp(&b.style, a.easing.ease(x)))
+    }
+
+    /// The animated styles at a time including the delay, `None` if the animation doesn't apply.
+    pub fn style_at(&self, time: f32) -> Option<WoodpeckerStyle> {
+        self.progress_at(time)
+            .and_then(|progress| self.sample(progress))
+    }
+}
+
+/// Plays an [`Animation`], added to the animated entity when the animation starts.
+///
+/// This lives outside of [`Animation`] because parents re-insert their children's components
+/// every time they render. Inserting a different animation starts it from the beginning.
+#[derive(Component, Debug, Reflect, Clone, PartialEq)]
+pub struct AnimationPlayback {
+    /// The animation being played.
+    animation: Animation,
+    playing: bool,
+    finished: bool,
+    elapsed: f32,
+    /// Iterations of an infinite animation dropped from `elapsed` to keep it small.
+    wrapped_iterations: u32,
+    /// The styles of the entity without the animation.
+    base_style: WoodpeckerStyle,
+}
+
+impl AnimationPlayback {
+    fn new(animation: &Animation, base_style: WoodpeckerStyle) -> Self {
+        let mut playback = Self {
+            animation: animation.clone(),
+            playing: false,
+            finished: false,
+            elapsed: 0.0,
+            wrapped_iterations: 0,
+            base_style,
+        };
+        playback.restart();
+        playback.playing = !animation.paused;
+        playback
+    }
+
+    /// Is the animation currently playing?
+    pub fn is_playing(&self) -> bool {
+        self.playing
+    }
+
+    /// Has the animation played to the end?
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// Plays or resumes the animation, restarting it if it already finished.
+    pub fn play(&mut self) {
+        if self.finished {
+            self.restart();
+        }
+        self.playing = true;
+    }
+
+    /// Pauses the animation.
+    pub fn pause(&mut self) {
+        self.playing = false;
+    }
+
+    /// Restarts the animation from the beginning, or from the end when the
+    /// playback rate is negative.
+    ///
+    /// Infinite animations have no end, restarting one with a negative playback rate does nothing.
+    pub fn restart(&mut self) {
+        let animation = &self.animation;
+        if animation.playback_rate < 0.0 && animation.iterations == AnimationIterations::Infinite {
+            return;
+        }
+        self.elapsed = if animation.playback_rate < 0.0 {
+            animation.end_time()
+        } else {
+            0.0
+        };
+        self.wrapped_iterations = 0;
+        self.finished = false;
+        self.playing = true;
+    }
+
+    /// Jumps to a time, including the delay.
+    pub fn seek(&mut self, time: f32) {
+        self.elapsed = time.clamp(0.0, self.animation.end_time());
+        self.wrapped_iterations = 0;
+        self.wrap();
+        self.finished = false;
+    }
+
+    /// The current time, including the delay.
+    pub fn elapsed(&self) -> f32 {
+        self.elapsed
+    }
+
+    /// The current iteration, `None` during the delay.
+    pub fn current_iteration(&self) -> Option<u32> {
+        self.animation
+            .iteration_at(self.elapsed)
+            .map(|iteration| iteration.wrapping_add(self.wrapped_iterations))
+    }
+
+    /// The animated styles at the current time, `None` if the animation doesn't apply.
+    pub fn current_style(&self) -> Option<WoodpeckerStyle> {
+        self.animation.style_at(self.elapsed)
+    }
+
+    /// Drops whole pairs of iterations from the time of an infinite animation so it
+    /// doesn't lose precision, pairs keep the direction of alternating iterations.
+    fn wrap(&mut self) {
+        let animation = &self.animation;
+        if animation.iterations != AnimationIterations::Infinite || animation.duration <= 0.0 {
+            return;
+        }
+        let delay = animation.delay.max(0.0);
+        let cycle = animation.duration * 2.0;
+        let local = self.elapsed - delay;
+        if local >= cycle {
+            let cycles = (local / cycle) as u32;
+            self.elapsed = delay + local % cycle;
+            self.wrapped_iterations = self.wrapped_iterations.wrapping_add(cycles.wrapping_mul(2));
+        }
+    }
+
+    /// Moves the animation forward by `delta` milliseconds, scaled by the playback rate.
+    pub(crate) fn advance(&mut self, delta: f32) -> AnimationStep {
+        let mut step = AnimationStep::default();
+        if !self.playing || self.finished {
+            return step;
+        }
+
+        let before = self.current_iteration();
+        let end = self.animation.end_time();
+        let playback_rate = self.animation.playback_rate;
+        self.elapsed = (self.elapsed + delta * playback_rate).clamp(0.0, end);
+        self.wrap();
+        let after = self.current_iteration();
+        if let (Some(before), Some(after)) = (before, after) {
+            if before != after {
+                step.iteration = Some(after);
+            }
+        }
+
+        let done = if playback_rate < 0.0 {
+            self.elapsed <= 0.0
+        } else {
+            self.elapsed >= end
+        };
+        if done {
+            self.finished = true;
+            self.playing = false;
+            step.completed = true;
+        }
+        step
+    }
+}
+
+// IMPORTANT: When modifying widget entities we need to verify we aren't modifying previous widget values.
+pub(crate) fn update_animations(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<
+        (
+            Entity,
+            Ref<Animation>,
+            Option<&mut AnimationPlayback>,
+            &mut WoodpeckerStyle,
+        ),
+        Without<PreviousWidget>,
+    >,
+) {
+    for (entity, animation, mut playback, mut style) in query.iter_mut() {
+        let mut started = None;
+        let playback = match playback.as_mut() {
+            Some(playback) => {
+                // Paused animations only need to update when they are changed, ex: seeking.
+                if !playback.playing && !playback.is_changed() && !animation.is_changed() {
+                    continue;
+                }
+                playback.bypass_change_detection()
+            }
+            None => started.insert(AnimationPlayback::new(&animation, *style)),
+        };
+        // Parents re-insert the same animation when they render, only a different one restarts.
+        if animation.is_changed() && playback.animation != *animation {
+            *playback = AnimationPlayback::new(&animation, *style);
+        }
+
+        let step = playback.advance(time.delta_secs() * 1000.0);
+        style.set_if_neq(playback.current_style().unwrap_or(playback.base_style));
+
+        if let Some(iteration) = step.iteration {
+            commands.trigger(AnimationIteration {
+                target: entity,
+                iteration,
+            });
+        }
+        if step.completed {
+            commands.trigger(AnimationCompleted { target: entity });
+        }
+        if let Some(started) = started {
+            commands.entity(entity).insert(started);
+        }
+    }
+}
+
+#[test]
+fn test_animation_timeline() {
+    let style = |left: f32| WoodpeckerStyle {
+        left: left.into(),
+        ..Default::default()
+    };
+    let left = |style: Option<WoodpeckerStyle>| style.map(|style| style.left.value_or(0.0));
+
+    let animation = Animation::new(100.0)
+        .with_keyframe(1.0, style(100.0))
+        .with_keyframe(0.0, style(0.0))
+        .with_keyframe(0.5, style(80.0))
+        .with_delay(50.0)
+        .with_iterations(AnimationIterations::Count(2))
+        .with_direction(AnimationDirection::Alternate);
+    assert_eq!(animation.keyframes[1].offset, 0.5);
+    assert_eq!(left(animation.sample(0.25)), Some(40.0));
+    assert_eq!(left(animation.sample(0.75)), Some(90.0));
+
+    // The delay applies the base styles without a backwards fill.
+    let mut playback = AnimationPlayback::new(&animation, WoodpeckerStyle::default());
+    assert_eq!(playback.advance(25.0), AnimationStep::default());
+    assert_eq!(left(playback.current_style()), None);
+    playback.animation.fill_mode = AnimationFillMode::Both;
+    assert_eq!(left(playback.current_style()), Some(0.0));
+
+    playback.advance(50.0);
+    assert_eq!(playback.current_iteration(), Some(0));
+    assert_eq!(left(playback.current_style()), Some(40.0));
+
+    // The second iteration plays backwards.
+    let step = playback.advance(100.0);
+    assert_eq!(step.iteration, Some(1));
+    assert_eq!(left(playback.current_style()), Some(90.0));
+
+    playback.pause();
+    assert_eq!(playback.advance(100.0), AnimationStep::default());
+    playback.play();
+    playback.animation.playback_rate = 2.0;
+    let step = playback.advance(100.0);
+    assert!(step.completed);
+    assert!(playback.is_finished());
+    assert_eq!(left(playback.current_style()), Some(0.0));
+
+    playback.seek(100.0);
+    assert_eq!(left(playback.current_style()), Some(80.0));
+
+    // Infinite animations keep their time small and alternate across the dropped iterations.
+    let animation = Animation::new(100.0)
+        .with_keyframe(0.0, style(0.0))
+        .with_keyframe(1.0, style(100.0))
+        .with_iterations(AnimationIterations::Infinite)
+        .with_direction(AnimationDirection::Alternate);
+    let mut playback = AnimationPlayback::new(&animation, WoodpeckerStyle::default());
+    for _ in 0..1_000 {
+        playback.advance(1_025.0);
+    }
+    assert!(playback.elapsed() < 200.0);
+    assert_eq!(playback.current_iteration(), Some(10_250));
+    assert_eq!(left(playback.current_style()), Some(0.0));
+    playback.advance(25.0);
+    assert_eq!(left(playback.current_style()), Some(25.0));
+
+    playback.animation.playback_rate = -1.0;
+    let elapsed = playback.elapsed();
+    playback.restart();
+    assert_eq!(playback.elapsed(), elapsed);
+}
+
+#[test]
+fn test_animation_reinserted() {
+    use bevy::ecs::system::RunSystemOnce;
+    use std::time::Duration;
+
+    let style = |left: f32| WoodpeckerStyle {
+        left: left.into(),
+        ..Default::default()
+    };
+    let animation = Animation::new(100.0)
+        .with_keyframe(0.0, style(0.0))
+        .with_keyframe(1.0, style(100.0));
+
+    let mut world = World::new();
+    world.insert_resource(Time::<()>::default());
+    let entity = world.spawn((animation.clone(), style(0.0))).id();
+    let frame = |world: &mut World| {
+        world
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_millis(25));
+        world.run_system_once(update_animations).unwrap();
+    };
+    frame(&mut world);
+    frame(&mut world);
+    let elapsed = |world: &World| world.get::<AnimationPlayback>(entity).unwrap().elapsed();
+    let close = |a: f32, b: f32| (a - b).abs() < 0.01;
+    assert!(close(elapsed(&world), 50.0));
+
+    // A parent rendering again re-inserts the same animation and its base styles.
+    world
+        .entity_mut(entity)
+        .insert((animation.clone(), style(0.0)));
+    frame(&mut world);
+    assert!(close(elapsed(&world), 75.0));
+    let left = world.get::<WoodpeckerStyle>(entity).unwrap().left;
+    assert!(close(left.value_or(0.0), 75.0));
+
+    // A different animation starts over.
+    world.entity_mut(entity).insert(animation.with_delay(10.0));
+    frame(&mut world);
+    assert!(close(elapsed(&world), 25.0));
+}
@@ -0,0 +1,163 @@
+use std::{ops::Range, sync::Arc};
+
+/// The maximum number of suggestions shown in the popup at once.
+pub(crate) const MAX_VISIBLE_SUGGESTIONS: usize = 8;
+
+/// Provides autocomplete suggestions for the token under the cursor.
+///
+/// The provider receives the part of the token before the cursor and returns
+/// the candidates to display, in order.
+#[derive(Clone)]
+pub struct SuggestionProvider {
+    inner: Arc<dyn Fn(&str) -> Vec<String> + Send + Sync + 'static>,
+}
+
+impl SuggestionProvider {
+    /// Creates a new suggestion provider.
+    pub fn new(f: impl Fn(&str) -> Vec<String> + Send + Sync + 'static) -> Self {
+        Self { inner: Arc::new(f) }
+    }
+
+    /// Creates a provider that suggests every word starting with the token, ignoring case.
+    pub fn from_words(words: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        let words = words.into_iter().map(Into::into).collect::<Vec<String>>();
+        Self::new(move |token| {
+            let token = token.to_lowercase();
+            words
+                .iter()
+                .filter(|word| word.to_lowercase().starts_with(&token))
+                .cloned()
+                .collect()
+        })
+    }
+
+    /// Returns the suggestions for a token.
+    pub fn suggest(&self, token: &str) -> Vec<String> {
+        (self.inner)(token)
+    }
+}
+
+impl PartialEq for SuggestionProvider {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+/// The autocomplete popup state of a textbox.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct AutocompleteState {
+    /// Is the popup open?
+    pub open: bool,
+    /// The byte range of the token that will be replaced.
+    pub token: Range<usize>,
+    /// The current suggestions.
+    pub suggestions: Vec<String>,
+    /// The index of the highlighted suggestion.
+    pub selected: usize,
+    /// Is the pointer over the popup?
+    pub(crate) hovering: bool,
+}
+
+impl AutocompleteState {
+    /// Asks the provider for suggestions for the token ending at the cursor.
+    pub(crate) fn update(&mut self, text: &str, cursor: usize, provider: &SuggestionProvider) {
+        let token = token_at(text, cursor);
+        if token.start == cursor {
+            self.close();
+            return;
+        }
+        let typed = &text[token.start..cursor];
+        let suggestions = provider.suggest(typed);
+        // Nothing left to complete.
+        if suggestions.is_empty()
+            || (suggestions.len() == 1 && suggestions[0] == text[token.clone()])
+        {
+            self.close();
+            return;
+        }
+        if suggestions != self.suggestions {
+            self.selected = 0;
+        }
+        self.open = true;
+        self.token = token;
+        self.suggestions = suggestions;
+    }
+
+    /// Closes the popup.
+    pub fn close(&mut self) {
+        self.open = false;
+        self.suggestions.clear();
+        self.selected = 0;
+    }
+
+    /// Highlights the next suggestion, wrapping around.
+    pub fn select_next(&mut self) {
+        if !self.suggestions.is_empty() {
+            self.selected = (self.selected + 1) % self.suggestions.len();
+        }
+    }
+
+    /// Highlights the previous suggestion, wrapping around.
+    pub fn select_previous(&mut self) {
+        if !self.suggestions.is_empty() {
+            self.selected = self
+                .selected
+                .checked_sub(1)
+                .unwrap_or(self.suggestions.len() - 1);
+        }
+    }
+
+    /// The range of suggestions visible in the popup, keeping the selected one in view.
+    pub(crate) fn visible_range(&self) -> Range<usize> {
+        let start = (self.selected + 1).saturating_sub(MAX_VISIBLE_SUGGESTIONS);
+        start..self.suggestions.len().min(start + MAX_VISIBLE_SUGGESTIONS)
+    }
+}
+
+fn is_token_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// The byte range of the word around the cursor.
+pub(crate) fn token_at(text: &str, cursor: usize) -> Range<usize> {
+    let start = text[..cursor]
+        .char_indices()
+        .rev()
+        .take_while(|(_, c)| is_token_char(*c))
+        .last()
+        .map(|(i, _)| i)
+        .unwrap_or(cursor);
+    let end = text[cursor..]
+        .char_indices()
+        .find(|(_, c)| !is_token_char(*c))
+        .map(|(i, _)| cursor + i)
+        .unwrap_or(text.len());
+    start..end
+}
+
+#[test]
+fn test_autocomplete() {
+    let text = "/give sw_ord 1";
+    assert_eq!(token_at(text, 3), 1..5);
+    assert_eq!(token_at(text, 9), 6..12);
+    assert_eq!(token_at(text, 0), 0..0);
+    assert_eq!(token_at("é", 2), 0..2);
+
+    let provider = SuggestionProvider::from_words(["give", "gift", "kill"]);
+    let mut state = AutocompleteState::default();
+    state.update(text, 3, &provider);
+    assert!(state.open);
+    assert_eq!(state.token, 1..5);
+    assert_eq!(state.suggestions, vec!["give", "gift"]);
+
+    state.select_previous();
+    assert_eq!(state.selected, 1);
+    state.select_next();
+    assert_eq!(state.selected, 0);
+
+    // The token is already complete.
+    state.update(text, 5, &provider);
+    assert!(!state.open);
+    state.update(text, 0, &provider);
+    assert!(!state.open);
+}
@@ -0,0 +1,399 @@
+use std::ops::Range;
+
+use bevy::prelude::*;
+use bevy_vello::vello::peniko::Brush;
+
+use super::{colors, text_box::TabMode};
+
+/// A pair of brackets used for bracket matching and auto-indent.
+#[derive(Debug, Reflect, Clone, Copy, PartialEq)]
+pub struct BracketPair {
+    /// The opening bracket
+    pub open: char,
+    /// The closing bracket
+    pub close: char,
+}
+
+impl BracketPair {
+    /// Creates a new bracket pair.
+    pub const fn new(open: char, close: char) -> Self {
+        Self { open, close }
+    }
+}
+
+/// Settings that turn a multi-line [`crate::prelude::TextBox`] into a code editor.
+///
+/// Enables a line-number gutter, current-line highlight, matching bracket
+/// highlight, auto-indent on enter, block indent/outdent with tab/shift+tab
+/// and toggle-comment with ctrl+/.
+#[derive(Debug, Reflect, Clone, PartialEq)]
+pub struct CodeEditorSettings {
+    /// Show line numbers in the gutter.
+    pub line_numbers: bool,
+    /// The width of the gutter in pixels.
+    pub gutter_width: f32,
+    /// The gutter background color.
+    pub gutter_background: Color,
+    /// The color of the line numbers.
+    pub gutter_color: Color,
+    /// The color used to highlight the line the cursor is on.
+    /// Set to transparent to disable.
+    pub current_line_color: Color,
+    /// The color used to highlight the bracket pair around the cursor.
+    /// Set to transparent to disable.
+    pub bracket_match_color: Color,
+    /// Copy the indentation of the current line when pressing enter.
+    pub auto_indent: bool,
+    /// The token used when toggling line comments. Ex: `//`
+    pub line_comment: String,
+    /// Bracket pairs used for matching and auto-indent.
+    pub brackets: Vec<BracketPair>,
+}
+
+impl Default for CodeEditorSettings {
+    fn default() -> Self {
+        Self {
+            line_numbers: true,
+            gutter_width: 48.0,
+            gutter_background: colors::BACKGROUND,
+            gutter_color: Srgba::new(0.592, 0.627, 0.749, 1.0).into(),
+            current_line_color: Srgba::new(1.0, 1.0, 1.0, 0.05).into(),
+            bracket_match_color: Srgba::new(0.592, 0.627, 0.749, 0.35).into(),
+            auto_indent: true,
+            line_comment: "//".into(),
+            brackets: vec![
+                BracketPair::new('(', ')'),
+                BracketPair::new('[', ']'),
+                BracketPair::new('{', '}'),
+            ],
+        }
+    }
+}
+
+/// A single edit applied at the start of a line.
+struct LineEdit {
+    at: usize,
+    remove: usize,
+    insert: String,
+}
+
+/// The string inserted for one level of indentation.
+pub(crate) fn indent_unit(tab_mode: TabMode) -> String {
+    match tab_mode {
+        TabMode::Tab => "\t".into(),
+        TabMode::Space(spaces) => " ".repeat(spaces as usize),
+    }
+}
+
+/// Byte index of the start of the line containing `index`.
+pub(crate) fn line_start(text: &str, index: usize) -> usize {
+    text[..index].rfind('\n').map(|i| i + 1).unwrap_or(0)
+}
+
+/// Byte index of the end of the line containing `index`(excluding the new line).
+pub(crate) fn line_end(text: &str, index: usize) -> usize {
+    text[index..]
+        .find('\n')
+        .map(|i| index + i)
+        .unwrap_or(text.len())
+}
+
+/// Returns the byte ranges of each line touched by the selection.
+///
+/// A selection ending at the very start of a line does not include that line.
+fn selected_lines(text: &str, selection: &Range<usize>) -> Vec<Range<usize>> {
+    let mut end = selection.end;
+    if selection.end > selection.start && line_start(text, end) == end {
+        end -= 1;
+    }
+    let mut lines = vec![];
+    let mut start = line_start(text, selection.start);
+    loop {
+        let line_end = line_end(text, start);
+        lines.push(start..line_end);
+        if line_end >= end || line_end >= text.len() {
+            break;
+        }
+        start = line_end + 1;
+    }
+    lines
+}
+
+fn leading_whitespace(line: &str) -> usize {
+    line.len() - line.trim_start_matches([' ', '\t']).len()
+}
+
+fn apply_line_edits(
+    text: &str,
+    edits: &[LineEdit],
+    selection: Range<usize>,
+) -> (String, Range<usize>) {
+    let mut new_text = String::with_capacity(text.len());
+    let mut last = 0;
+    for edit in edits {
+        new_text.push_str(&text[last..edit.at]);
+        new_text.push_str(&edit.insert);
+        last = edit.at + edit.remove;
+    }
+    new_text.push_str(&text[last..]);
+
+    let map = |position: usize| {
+        let mut delta = 0isize;
+        for edit in edits {
+            if position <= edit.at {
+                break;
+            }
+            let removed = edit.remove.min(position - edit.at);
+            delta += edit.insert.len() as isize - removed as isize;
+        }
+        (position as isize + delta) as usize
+    };
+
+    (new_text, map(selection.start)..map(selection.end))
+}
+
+/// Returns the text to insert when enter is pressed at `cursor`.
+///
+/// The indentation of the current line is kept and an extra level is added
+/// after an opening bracket.
+pub(crate) fn auto_indent(
+    text: &str,
+    cursor: usize,
+    tab_mode: TabMode,
+    settings: &CodeEditorSettings,
+) -> String {
+    let start = line_start(text, cursor);
+    let line = &text[start..cursor];
+    let mut insert = format!("\n{}", &line[..leading_whitespace(line)]);
+    if line
+        .trim_end()
+        .chars()
+        .last()
+        .is_some_and(|c| settings.brackets.iter().any(|pair| pair.open == c))
+    {
+        insert.push_str(&indent_unit(tab_mode));
+    }
+    insert
+}
+
+/// Indents every line touched by the selection by one level.
+pub(crate) fn indent_lines(
+    text: &str,
+    selection: Range<usize>,
+    tab_mode: TabMode,
+) -> (String, Range<usize>) {
+    let lines = selected_lines(text, &selection);
+    let single_line = lines.len() == 1;
+    let edits = lines
+        .into_iter()
+        .filter(|line| single_line || !text[line.clone()].trim().is_empty())
+        .map(|line| LineEdit {
+            at: line.start,
+            remove: 0,
+            insert: indent_unit(tab_mode),
+        })
+        .collect::<Vec<_>>();
+    apply_line_edits(text, &edits, selection)
+}
+
+/// Removes one level of indentation from every line touched by the selection.
+pub(crate) fn outdent_lines(
+    text: &str,
+    selection: Range<usize>,
+    tab_mode: TabMode,
+) -> (String, Range<usize>) {
+    let width = match tab_mode {
+        TabMode::Tab => 4,
+        TabMode::Space(spaces) => spaces as usize,
+    };
+    let edits = selected_lines(text, &selection)
+        .into_iter()
+        .filter_map(|line| {
+            let line_text = &text[line.clone()];
+            let remove = if line_text.starts_with('\t') {
+                1
+            } else {
+                line_text.len() - line_text.trim_start_matches(' ').len()
+            }
+            .min(width);
+            (remove > 0).then(|| LineEdit {
+                at: line.start,
+                remove,
+                insert: String::new(),
+            })
+        })
+        .collect::<Vec<_>>();
+    apply_line_edits(text, &edits, selection)
+}
+
+/// Comments or uncomments every line touched by the selection.
+///
+/// If every non-empty line is already commented the comment token is removed,
+/// otherwise it is inserted at the smallest indentation of the lines.
+pub(crate) fn toggle_line_comment(
+    text: &str,
+    selection: Range<usize>,
+    token: &str,
+) -> (String, Range<usize>) {
+    if token.is_empty() {
+        return (text.to_string(), selection);
+    }
+    let lines = selected_lines(text, &selection)
+        .into_iter()
+        .filter(|line| !text[line.clone()].trim().is_empty())
+        .collect::<Vec<_>>();
+    if lines.is_empty() {
+        return (text.to_string(), selection);
+    }
+
+    let all_commented = lines
+        .iter()
+        .all(|line| text[line.clone()].trim_start().starts_with(token));
+
+    let edits = if all_commented {
+        lines
+            .iter()
+            .map(|line| {
+                let line_text = &text[line.clone()];
+                let indent = leading_whitespace(line_text);
+                let mut remove = token.len();
+                if line_text[indent + remove..].starts_with(' ') {
+                    remove += 1;
+                }
+                LineEdit {
+                    at: line.start + indent,
+                    remove,
+                    insert: String::new(),
+                }
+            })
+            .collect::<Vec<_>>()
+    } else {
+        let indent = lines
+            .iter()
+            .map(|line| leading_whitespace(&text[line.clone()]))
+            .min()
+            .unwrap_or(0);
+        lines
+            .iter()
+            .map(|line| LineEdit {
+                at: line.start + indent,
+                remove: 0,
+                insert: format!("{token} "),
+            })
+            .collect::<Vec<_>>()
+    };
+    apply_line_edits(text, &edits, selection)
+}
+
+/// Finds the bracket next to the cursor and its matching pair.
+///
+/// Returns the byte index of both brackets.
+pub(crate) fn find_matching_bracket(
+    text: &str,
+    cursor: usize,
+    brackets: &[BracketPair],
+) -> Option<(usize, usize)> {
+    let after = text[cursor..].chars().next().map(|c| (cursor, c));
+    let before = text[..cursor].char_indices().next_back();
+
+    [after, before]
+        .into_iter()
+        .flatten()
+        .find_map(|(index, c)| {
+            let pair = brackets.iter().find(|p| p.open == c || p.close == c)?;
+            let mut depth = 0usize;
+            if c == pair.open {
+                for (i, c) in text[index..].char_indices() {
+                    if c == pair.open {
+                        depth += 1;
+                    } else if c == pair.close {
+                        depth -= 1;
+                        if depth == 0 {
+                            return Some((index, index + i));
+                        }
+                    }
+                }
+            } else {
+                for (i, c) in text[..index + c.len_utf8()].char_indices().rev() {
+                    if c == pair.close {
+                        depth += 1;
+                    } else if c == pair.open {
+                        depth -= 1;
+                        if depth == 0 {
+                            return Some((index, i));
+                        }
+                    }
+                }
+            }
+            None
+        })
+}
+
+/// Builds the gutter text, one entry per visual line of the layout.
+///
+/// Wrapped lines get an empty entry so the numbers line up with the text.
+pub(crate) fn gutter_text(layout: &parley::Layout<Brush>, text: &str) -> String {
+    let mut gutter = String::new();
+    let mut line_number = 0;
+    for (i, line) in layout.lines().enumerate() {
+        if i > 0 {
+            gutter.push('\n');
+        }
+        let start = line.text_range().start;
+        if start == 0 || text.as_bytes().get(start - 1) == Some(&b'\n') {
+            line_number += 1;
+            gutter.push_str(&line_number.to_string());
+        }
+    }
+    if gutter.is_empty() {
+        gutter.push('1');
+    }
+    gutter
+}
+
+/// The bounding box of the character at the given byte index.
+pub(crate) fn char_geometry(
+    layout: &parley::Layout<Brush>,
+    text: &str,
+    index: usize,
+) -> Option<parley::BoundingBox> {
+    let len = text[index..].chars().next()?.len_utf8();
+    let selection = parley::Selection::new(
+        parley::Cursor::from_byte_index(layout, index, parley::Affinity::Downstream),
+        parley::Cursor::from_byte_index(layout, index + len, parley::Affinity::Upstream),
+    );
+    selection
+        .geometry(layout)
+        .first()
+        .map(|(bounds, _)| *bounds)
+}
+
+#[test]
+fn test_code_editor_line_edits() {
+    let settings = CodeEditorSettings::default();
+    let tab_mode = TabMode::Space(4);
+
+    assert_eq!(
+        auto_indent("    foo {", 9, tab_mode, &settings),
+        "\n        "
+    );
+    assert_eq!(auto_indent("    foo", 7, tab_mode, &settings), "\n    ");
+
+    let text = "a\n\nb";
+    let (indented, selection) = indent_lines(text, 0..text.len(), tab_mode);
+    assert_eq!(indented, "    a\n\n    b");
+    assert_eq!(selection, 0..indented.len());
+    let (outdented, _) = outdent_lines(&indented, selection, tab_mode);
+    assert_eq!(outdented, text);
+
+    let text = "  a\n    b";
+    let (commented, selection) = toggle_line_comment(text, 0..text.len(), "//");
+    assert_eq!(commented, "  // a\n  //   b");
+    let (uncommented, _) = toggle_line_comment(&commented, selection, "//");
+    assert_eq!(uncommented, text);
+
+    let brackets = CodeEditorSettings::default().brackets;
+    assert_eq!(find_matching_bracket("f(a[0])", 1, &brackets), Some((1, 6)));
+    assert_eq!(find_matching_bracket("f(a[0])", 7, &brackets), Some((6, 1)));
+    assert_eq!(find_matching_bracket("foo", 1, &brackets), None);
+}
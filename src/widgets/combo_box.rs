@@ -0,0 +1,917 @@
+use std::ops::Range;
+
+use super::text_box::sync_text_box;
+use crate::{picking_backend::MouseWheelScroll, prelude::*};
+use bevy::prelude::*;
+
+/// A combo box change event.
+#[derive(Debug, Clone, Reflect)]
+pub struct ComboBoxChanged {
+    /// The index of the selected item, `None` for a custom value.
+    pub index: Option<usize>,
+    /// The value of the selected item or the custom text.
+    pub value: String,
+}
+
+/// How the typed text filters the options of a [`ComboBox`].
+#[derive(Debug, Default, Reflect, Clone, Copy, PartialEq, Eq)]
+pub enum ComboBoxFilter {
+    /// Options starting with the text, ignoring case.
+    Prefix,
+    /// Options containing the characters of the text in order, ignoring case.
+    /// Prefix and substring matches are listed first.
+    #[default]
+    Fuzzy,
+}
+
+/// Combo box styles
+#[derive(Clone, PartialEq, Reflect)]
+pub struct ComboBoxStyles {
+    /// The styles of the row holding the textbox and the arrow
+    pub background: WoodpeckerStyle,
+    /// Arrow icon styles
+    pub icon: WoodpeckerStyle,
+    /// Option list styles
+    pub list: WoodpeckerStyle,
+    /// Option styles, the height is used for every option
+    pub option: WoodpeckerStyle,
+    /// Highlighted option styles
+    pub option_highlighted: WoodpeckerStyle,
+    /// Disabled option styles
+    pub option_disabled: WoodpeckerStyle,
+    /// The color of the parts of an option matching the text
+    pub match_color: Color,
+    /// Option icon styles
+    pub option_icon: WoodpeckerStyle,
+    /// Scrollbar thumb styles, shown when there are more options than fit
+    pub scroll_thumb: WoodpeckerStyle,
+}
+
+impl Default for ComboBoxStyles {
+    fn default() -> Self {
+        let option = WoodpeckerStyle {
+            width: Units::Percentage(100.0),
+            height: 26.0.into(),
+            padding: Edge::all(0.0).left(6.0).right(6.0),
+            align_items: Some(WidgetAlignItems::Center),
+            background_color: colors::BACKGROUND_MID,
+            color: Color::WHITE,
+            font_size: 14.0,
+            text_wrap: TextWrap::None,
+            ..Default::default()
+        };
+        Self {
+            background: WoodpeckerStyle {
+                width: Units::Percentage(100.0),
+                align_items: Some(WidgetAlignItems::Center),
+                ..Default::default()
+            },
+            icon: WoodpeckerStyle {
+                color: Color::WHITE,
+                width: 20.0.into(),
+                height: 20.0.into(),
+                margin: Edge::all(0.0).left(4.0),
+                ..Default::default()
+            },
+            list: WoodpeckerStyle {
+                background_color: colors::BACKGROUND_MID,
+                border_radius: Corner::all(4.0),
+                padding: Edge::all(2.0),
+                flex_direction: WidgetFlexDirection::Column,
+                ..Default::default()
+            },
+            option,
+            option_highlighted: WoodpeckerStyle {
+                background_color: colors::PRIMARY,
+                ..option
+            },
+            option_disabled: WoodpeckerStyle {
+                color: colors::BACKGROUND_LIGHT,
+                ..option
+            },
+            match_color: colors::PRIMARY_LIGHT,
+            option_icon: WoodpeckerStyle {
+                color: Color::WHITE,
+                width: 16.0.into(),
+                height: 16.0.into(),
+                margin: Edge::all(0.0).right(6.0),
+                ..Default::default()
+            },
+            scroll_thumb: WoodpeckerStyle {
+                position: WidgetPosition::Absolute,
+                right: 2.0.into(),
+                width: 4.0.into(),
+                border_radius: Corner::all(2.0),
+                background_color: colors::BACKGROUND_LIGHT,
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Combo box state
+#[derive(Default, Debug, Component, Clone, PartialEq, Reflect)]
+pub struct ComboBoxState {
+    /// Is the option list open?
+    pub is_open: bool,
+    /// The text in the textbox.
+    pub text: String,
+    /// The selected item.
+    pub selected: Option<usize>,
+    /// The committed value, the selected item's value or a custom value.
+    pub value: String,
+    /// The option highlighted by the keyboard or pointer.
+    pub highlighted: Option<usize>,
+    /// The index of the first visible option in the filtered list.
+    pub scroll: usize,
+    /// Is the text being used to filter the options?
+    filtering: bool,
+    hovering_list: bool,
+    text_box: Option<Entity>,
+    /// The text last pushed into the textbox, typing doesn't change it so the textbox isn't reset.
+    synced_text: String,
+}
+
+impl ComboBoxState {
+    fn close(&mut self) {
+        self.is_open = false;
+        self.hovering_list = false;
+    }
+
+    /// Shows the text in the textbox after selecting or committing.
+    fn sync_text(&mut self, commands: &mut Commands) {
+        sync_text_box(commands, &mut self.synced_text, &self.text, self.text_box);
+    }
+}
+
+/// An option that passed the filter.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct OptionMatch {
+    /// The index of the item.
+    pub(crate) index: usize,
+    score: i32,
+    /// The byte ranges of the label that matched.
+    pub(crate) ranges: Vec<Range<usize>>,
+}
+
+/// A textbox with a filterable list of options.
+///
+/// Typing filters the options, the matching parts of each option are highlighted.
+/// Up/Down/PageUp/PageDown move through the options, Enter selects the highlighted option
+/// and Escape closes the list. Only the visible options are spawned so the list can
+/// hold thousands of items.
+///
+/// When `allow_custom_value` is set, pressing Enter or leaving the combo box with text
+/// that isn't an option commits the text as the value. Otherwise the text is reset
+/// to the selected option.
+#[derive(Widget, Component, Clone, PartialEq, Reflect)]
+#[auto_update(render)]
+#[props(ComboBox)]
+#[state(ComboBoxState)]
+#[require(WoodpeckerStyle, WidgetChildren)]
+pub struct ComboBox {
+    /// The options, uses the same items as a [`Dropdown`]
+    pub items: Vec<DropdownItem>,
+    /// The initially selected item
+    pub selected: Option<usize>,
+    /// The initial text when no item is selected
+    pub initial_value: String,
+    /// Accept text that doesn't match an option as the value
+    pub allow_custom_value: bool,
+    /// How the text filters the options
+    pub filter: ComboBoxFilter,
+    /// The number of options visible at once
+    pub max_visible_options: usize,
+    /// Styles
+    pub styles: ComboBoxStyles,
+}
+
+impl Default for ComboBox {
+    fn default() -> Self {
+        Self {
+            items: vec![],
+            selected: None,
+            initial_value: String::new(),
+            allow_custom_value: false,
+            filter: ComboBoxFilter::Fuzzy,
+            max_visible_options: 8,
+            styles: ComboBoxStyles::default(),
+        }
+    }
+}
+
+impl ComboBox {
+    /// The options matching the text, best matches first.
+    pub(crate) fn matches(&self, state: &ComboBoxState) -> Vec<OptionMatch> {
+        let query = if state.filtering {
+            state.text.as_str()
+        } else {
+            ""
+        };
+        filter_options(&self.items, query, self.filter)
+    }
+
+    fn select(
+        &self,
+        commands: &mut Commands,
+        combo_box_entity: Entity,
+        state: &mut ComboBoxState,
+        index: usize,
+    ) {
+        let Some(item) = self.items.get(index).filter(|item| !item.disabled) else {
+            return;
+        };
+        state.selected = Some(index);
+        state.highlighted = Some(index);
+        state.text = item.label.clone();
+        state.value = item.value.clone();
+        state.filtering = false;
+        state.close();
+        state.sync_text(commands);
+        commands.trigger(Change {
+            target: combo_box_entity,
+            data: ComboBoxChanged {
+                index: Some(index),
+                value: item.value.clone(),
+            },
+        });
+    }
+
+    /// Commits the text as a custom value, or resets the text to the selection.
+    fn commit_text(
+        &self,
+        commands: &mut Commands,
+        combo_box_entity: Entity,
+        state: &mut ComboBoxState,
+    ) {
+        if let Some(index) = self
+            .items
+            .iter()
+            .position(|item| !item.disabled && item.label == state.text)
+        {
+            if state.selected != Some(index) {
+                self.select(commands, combo_box_entity, state, index);
+            }
+        } else if self.allow_custom_value {
+            if state.selected.is_some() || state.value != state.text {
+                state.selected = None;
+                state.value = state.text.clone();
+                commands.trigger(Change {
+                    target: combo_box_entity,
+                    data: ComboBoxChanged {
+                        index: None,
+                        value: state.value.clone(),
+                    },
+                });
+            }
+        } else {
+            state.text = state
+                .selected
+                .and_then(|index| self.items.get(index))
+                .map(|item| item.label.clone())
+                .unwrap_or_default();
+        }
+        state.filtering = false;
+        state.close();
+        state.sync_text(commands);
+    }
+
+    fn open(&self, state: &mut ComboBoxState) {
+        state.is_open = true;
+        let matches = self.matches(state);
+        // Typed custom text is committed with Enter unless the user picks an option.
+        let auto_highlight = !(self.allow_custom_value && state.filtering);
+        if auto_highlight
+            && !matches
+                .iter()
+                .any(|option| Some(option.index) == state.highlighted)
+        {
+            state.highlighted = move_highlight(&self.items, &matches, None, 1);
+        }
+        state.scroll = scroll_into_view(
+            0,
+            highlighted_position(&matches, state.highlighted),
+            self.max_visible_options,
+        );
+    }
+
+    /// Moves the highlight by `delta` options and scrolls it into view.
+    fn move_highlight(&self, state: &mut ComboBoxState, delta: isize) {
+        let matches = self.matches(state);
+        state.highlighted = move_highlight(&self.items, &matches, state.highlighted, delta);
+        state.scroll = scroll_into_view(
+            state.scroll,
+            highlighted_position(&matches, state.highlighted),
+            self.max_visible_options,
+        );
+    }
+}
+
+fn eq_ignore_case(a: char, b: char) -> bool {
+    a == b || a.to_lowercase().eq(b.to_lowercase())
+}
+
+fn is_word_start(label: &[(usize, char)], i: usize) -> bool {
+    i == 0 || matches!(label[i - 1].1, ' ' | '_' | '-' | '/' | '.')
+}
+
+/// Matches a query against a label, returning a score and the matching byte ranges of the label.
+pub(crate) fn match_label(
+    query: &str,
+    label: &str,
+    filter: ComboBoxFilter,
+) -> Option<(i32, Vec<Range<usize>>)> {
+    let query = query.chars().collect::<Vec<_>>();
+    let chars = label.char_indices().collect::<Vec<_>>();
+    let byte_at = |i: usize| chars.get(i).map(|(byte, _)| *byte).unwrap_or(label.len());
+    if query.is_empty() {
+        return Some((0, vec![]));
+    }
+    if query.len() > chars.len() {
+        return None;
+    }
+
+    // Substring matches, prefixes first then matches at the start of a word.
+    let starts = match filter {
+        ComboBoxFilter::Prefix => 0..1,
+        ComboBoxFilter::Fuzzy => 0..chars.len() - query.len() + 1,
+    };
+    let mut substring: Option<(i32, usize)> = None;
+    for start in starts {
+        if !query
+            .iter()
+            .enumerate()
+            .all(|(i, q)| eq_ignore_case(chars[start + i].1, *q))
+        {
+            continue;
+        }
+        let score = if start == 0 {
+            3000
+        } else if is_word_start(&chars, start) {
+            2000 - start as i32
+        } else {
+            1000 - start as i32
+        };
+        if substring.is_none_or(|(best, _)| score > best) {
+            substring = Some((score, start));
+        }
+    }
+    if let Some((score, start)) = substring {
+        return Some((score, vec![byte_at(start)..byte_at(start + query.len())]));
+    }
+    if filter == ComboBoxFilter::Prefix {
+        return None;
+    }
+
+    // Characters in order, rewarding runs and word starts.
+    let mut score = 0;
+    let mut ranges: Vec<Range<usize>> = vec![];
+    let mut last: Option<usize> = None;
+    let mut next = 0;
+    for q in query {
+        let i = (next..chars.len()).find(|i| eq_ignore_case(chars[*i].1, q))?;
+        score += 1;
+        if is_word_start(&chars, i) {
+            score += 2;
+        }
+        if last == Some(i.wrapping_sub(1)) {
+            score += 3;
+            if let Some(range) = ranges.last_mut() {
+                range.end = byte_at(i + 1);
+            }
+        } else {
+            ranges.push(byte_at(i)..byte_at(i + 1));
+        }
+        last = Some(i);
+        next = i + 1;
+    }
+    Some((score, ranges))
+}
+
+/// Filters and sorts the items, best matches first.
+pub(crate) fn filter_options(
+    items: &[DropdownItem],
+    query: &str,
+    filter: ComboBoxFilter,
+) -> Vec<OptionMatch> {
+    let mut matches = items
+        .iter()
+        .enumerate()
+        .filter_map(|(index, item)| {
+            match_label(query, &item.label, filter).map(|(score, ranges)| OptionMatch {
+                index,
+                score,
+                ranges,
+            })
+        })
+        .collect::<Vec<_>>();
+    // Stable, so equal scores keep the item order.
+    matches.sort_by_key(|option| -option.score);
+    matches
+}
+
+fn highlighted_position(matches: &[OptionMatch], highlighted: Option<usize>) -> Option<usize> {
+    matches
+        .iter()
+        .position(|option| Some(option.index) == highlighted)
+}
+
+/// Moves through the enabled options by `delta`, stopping at the ends.
+///
+/// With nothing highlighted, moving forward starts at the first option and backwards at the last.
+fn move_highlight(
+    items: &[DropdownItem],
+    matches: &[OptionMatch],
+    highlighted: Option<usize>,
+    delta: isize,
+) -> Option<usize> {
+    let enabled = matches
+        .iter()
+        .filter(|option| !items[option.index].disabled)
+        .map(|option| option.index)
+        .collect::<Vec<_>>();
+    if enabled.is_empty() {
+        return None;
+    }
+    let last = enabled.len() as isize - 1;
+    let current = highlighted.and_then(|index| enabled.iter().position(|i| *i == index));
+    let next = match current {
+        Some(current) => (current as isize + delta).clamp(0, last),
+        None if delta >= 0 => (delta - 1).clamp(0, last),
+        None => (last + delta + 1).clamp(0, last),
+    };
+    Some(enabled[next as usize])
+}
+
+/// The first visible position that keeps `position` inside a window of `visible` options.
+fn scroll_into_view(scroll: usize, position: Option<usize>, visible: usize) -> usize {
+    let Some(position) = position else {
+        return scroll;
+    };
+    let visible = visible.max(1);
+    if position < scroll {
+        position
+    } else if position >= scroll + visible {
+        position + 1 - visible
+    } else {
+        scroll
+    }
+}
+
+fn render(
+    mut commands: Commands,
+    mut hooks: ResMut<HookHelper>,
+    current_widget: Res<CurrentWidget>,
+    asset_server: Res<AssetServer>,
+    mut query: Query<(&ComboBox, &mut WoodpeckerStyle, &mut WidgetChildren)>,
+    state_query: Query<&ComboBoxState>,
+) {
+    let Ok((combo_box, mut styles, mut children)) = query.get_mut(**current_widget) else {
+        return;
+    };
+
+    let selected_item = combo_box
+        .selected
+        .and_then(|index| combo_box.items.get(index));
+    let text = selected_item
+        .map(|item| item.label.clone())
+        .unwrap_or_else(|| combo_box.initial_value.clone());
+    let state_entity = hooks.use_state(
+        &mut commands,
+        *current_widget,
+        ComboBoxState {
+            text: text.clone(),
+            synced_text: text,
+            value: selected_item
+                .map(|item| item.value.clone())
+                .unwrap_or_else(|| combo_box.initial_value.clone()),
+            selected: combo_box.selected,
+            highlighted: combo_box.selected,
+            ..Default::default()
+        },
+    );
+
+    let Ok(state) = state_query.get(state_entity) else {
+        return;
+    };
+
+    *styles = combo_box.styles.background;
+
+    let combo_box_entity = **current_widget;
+    let visible = combo_box.max_visible_options.max(1);
+    let matches = combo_box.matches(state);
+
+    *children = WidgetChildren::default();
+    children
+        .add::<Element>((
+            Element,
+            WoodpeckerStyle {
+                flex_grow: 1.0,
+                ..Default::default()
+            },
+            WidgetChildren::default()
+                .with_child::<TextBox>(TextBox {
+                    initial_value: state.synced_text.clone(),
+                    ..Default::default()
+                })
+                .with_observe(
+                    *current_widget,
+                    move |mut trigger: On<Change<TextChanged>>,
+                          mut state_query: Query<&mut ComboBoxState>,
+                          combo_box_query: Query<&ComboBox>| {
+                        // Consumers listen for ComboBoxChanged instead.
+                        trigger.propagate(false);
+                        let Ok(mut state) = state_query.get_mut(state_entity) else {
+                            return;
+                        };
+                        let Ok(combo_box) = combo_box_query.get(combo_box_entity) else {
+                            return;
+                        };
+                        state.text = trigger.data.value.clone();
+                        state.filtering = true;
+                        state.highlighted = None;
+                        combo_box.open(&mut state);
+                    },
+                )
+                .with_observe(
+                    *current_widget,
+                    move |trigger: On<WidgetFocus>, mut state_query: Query<&mut ComboBoxState>| {
+                        if let Ok(mut state) = state_query.get_mut(state_entity) {
+                            state.text_box = Some(trigger.target);
+                        }
+                    },
+                )
+                .with_observe(
+                    *current_widget,
+                    move |_trigger: On<WidgetBlur>,
+                          mut commands: Commands,
+                          mut state_query: Query<&mut ComboBoxState>,
+                          combo_box_query: Query<&ComboBox>| {
+                        let Ok(mut state) = state_query.get_mut(state_entity) else {
+                            return;
+                        };
+                        let Ok(combo_box) = combo_box_query.get(combo_box_entity) else {
+                            return;
+                        };
+                        // Clicking an option blurs the textbox first, let the click go through.
+                        if state.hovering_list {
+                            return;
+                        }
+                        combo_box.commit_text(&mut commands, combo_box_entity, &mut state);
+                    },
+                )
+                .with_observe(
+                    *current_widget,
+                    move |trigger: On<WidgetKeyboardButtonEvent>,
+                          mut commands: Commands,
+                          mut state_query: Query<&mut ComboBoxState>,
+                          combo_box_query: Query<&ComboBox>| {
+                        let Ok(mut state) = state_query.get_mut(state_entity) else {
+                            return;
+                        };
+                        let Ok(combo_box) = combo_box_query.get(combo_box_entity) else {
+                            return;
+                        };
+                        let page = combo_box.max_visible_options.max(1) as isize;
+
+                        if !state.is_open {
+                            match trigger.code {
+                                KeyCode::ArrowDown | KeyCode::ArrowUp => combo_box.open(&mut state),
+                                KeyCode::Enter => {
+                                    combo_box.commit_text(
+                                        &mut commands,
+                                        combo_box_entity,
+                                        &mut state,
+                                    );
+                                }
+                                _ => {}
+                            }
+                            return;
+                        }
+
+                        match trigger.code {
+                            KeyCode::ArrowDown => combo_box.move_highlight(&mut state, 1),
+                            KeyCode::ArrowUp => combo_box.move_highlight(&mut state, -1),
+                            KeyCode::PageDown => combo_box.move_highlight(&mut state, page),
+                            KeyCode::PageUp => combo_box.move_highlight(&mut state, -page),
+                            KeyCode::Enter => match state.highlighted {
+                                Some(index) => {
+                                    combo_box.select(
+                                        &mut commands,
+                                        combo_box_entity,
+                                        &mut state,
+                                        index,
+                                    );
+                                }
+                                None => {
+                                    combo_box.commit_text(
+                                        &mut commands,
+                                        combo_box_entity,
+                                        &mut state,
+                                    );
+                                }
+                            },
+                            KeyCode::Escape => {
+                                if !combo_box.allow_custom_value {
+                                    combo_box.commit_text(
+                                        &mut commands,
+                                        combo_box_entity,
+                                        &mut state,
+                                    );
+                                }
+                                state.close();
+                            }
+                            KeyCode::Tab => {
+                                combo_box.commit_text(&mut commands, combo_box_entity, &mut state);
+                            }
+                            _ => {}
+                        }
+                    },
+                ),
+        ))
+        // Arrow
+        .add::<Element>((
+            Element,
+            combo_box.styles.icon,
+            WidgetRender::Svg {
+                handle: if state.is_open {
+                    asset_server.load("embedded://woodpecker_ui/embedded_assets/icons/arrow-up.svg")
+                } else {
+                    asset_server
+                        .load("embedded://woodpecker_ui/embedded_assets/icons/arrow-down.svg")
+                },
+                color: Some(combo_box.styles.icon.color),
+            },
+            Pickable::default(),
+        ))
+        .observe(
+            *current_widget,
+            move |_trigger: On<Pointer<Click>>,
+                  mut state_query: Query<&mut ComboBoxState>,
+                  combo_box_query: Query<&ComboBox>| {
+                let Ok(mut state) = state_query.get_mut(state_entity) else {
+                    return;
+                };
+                let Ok(combo_box) = combo_box_query.get(combo_box_entity) else {
+                    return;
+                };
+                if state.is_open {
+                    state.close();
+                } else {
+                    // Show every option when opened with the arrow.
+                    state.filtering = false;
+                    combo_box.open(&mut state);
+                }
+            },
+        );
+
+    // Only the visible options are spawned.
+    let option_height = combo_box.styles.option.height.value_or(26.0);
+    let scroll = state.scroll.min(matches.len().saturating_sub(visible));
+    let mut list_children = WidgetChildren::default();
+    for option in matches.iter().skip(scroll).take(visible) {
+        let index = option.index;
+        let item = &combo_box.items[index];
+        let option_styles = if item.disabled {
+            combo_box.styles.option_disabled
+        } else if state.highlighted == Some(index) {
+            combo_box.styles.option_highlighted
+        } else {
+            combo_box.styles.option
+        };
+
+        let mut option_children = WidgetChildren::default();
+        if let Some(icon) = item.icon.clone() {
+            option_children.add::<Element>((
+                Element,
+                combo_box.styles.option_icon,
+                WidgetRender::Svg {
+                    handle: icon,
+                    color: Some(combo_box.styles.option_icon.color),
+                },
+            ));
+        }
+        option_children.add::<Element>((
+            Element,
+            WoodpeckerStyle {
+                font_size: option_styles.font_size,
+                color: option_styles.color,
+                text_wrap: TextWrap::None,
+                ..Default::default()
+            },
+            WidgetRender::RichText {
+                content: RichText::from_hightlighted(
+                    &item.label,
+                    Highlighted {
+                        color_text: option
+                            .ranges
+                            .iter()
+                            .map(|range| ColorText {
+                                color: combo_box.styles.match_color,
+                                range: range.clone(),
+                            })
+                            .collect(),
+                    },
+                ),
+            },
+        ));
+
+        list_children
+            .add::<Element>((
+                Element,
+                WoodpeckerStyle {
+                    height: option_height.into(),
+                    ..option_styles
+                },
+                option_children,
+                WidgetRender::Quad,
+                Pickable::default(),
+            ))
+            .observe(
+                *current_widget,
+                move |mut trigger: On<Pointer<Click>>,
+                      mut commands: Commands,
+                      mut current_focus: ResMut<CurrentFocus>,
+                      mut state_query: Query<&mut ComboBoxState>,
+                      combo_box_query: Query<&ComboBox>| {
+                    trigger.propagate(false);
+                    let Ok(mut state) = state_query.get_mut(state_entity) else {
+                        return;
+                    };
+                    let Ok(combo_box) = combo_box_query.get(combo_box_entity) else {
+                        return;
+                    };
+                    combo_box.select(&mut commands, combo_box_entity, &mut state, index);
+                    // Keep typing working after picking with the pointer.
+                    if let Some(text_box) = state.text_box {
+                        current_focus.refocus(&mut commands, text_box);
+                    }
+                },
+            )
+            .observe(
+                *current_widget,
+                move |_trigger: On<Pointer<Over>>,
+                      mut state_query: Query<&mut ComboBoxState>,
+                      combo_box_query: Query<&ComboBox>| {
+                    let Ok(mut state) = state_query.get_mut(state_entity) else {
+                        return;
+                    };
+                    let Ok(combo_box) = combo_box_query.get(combo_box_entity) else {
+                        return;
+                    };
+                    if combo_box
+                        .items
+                        .get(index)
+                        .is_some_and(|item| !item.disabled)
+                    {
+                        state.highlighted = Some(index);
+                    }
+                },
+            );
+    }
+
+    // Scrollbar thumb
+    if matches.len() > visible {
+        let track = option_height * visible as f32;
+        let thumb = (track * visible as f32 / matches.len() as f32).max(10.0);
+        let top = (track - thumb) * scroll as f32 / (matches.len() - visible) as f32;
+        list_children.add::<Element>((
+            Element,
+            WoodpeckerStyle {
+                top: (top + combo_box.styles.list.padding.top.value_or(0.0)).into(),
+                height: thumb.into(),
+                ..combo_box.styles.scroll_thumb
+            },
+            WidgetRender::Quad,
+        ));
+    }
+
+    children
+        .add::<Popover>((
+            Popover {
+                open: state.is_open && !matches.is_empty(),
+                match_anchor_size: true,
+                styles: combo_box.styles.list,
+                ..Default::default()
+            },
+            PassedChildren(list_children),
+        ))
+        .observe(
+            *current_widget,
+            move |_trigger: On<PopoverDismissed>,
+                  mut commands: Commands,
+                  mut state_query: Query<&mut ComboBoxState>,
+                  combo_box_query: Query<&ComboBox>| {
+                let Ok(mut state) = state_query.get_mut(state_entity) else {
+                    return;
+                };
+                let Ok(combo_box) = combo_box_query.get(combo_box_entity) else {
+                    return;
+                };
+                combo_box.commit_text(&mut commands, combo_box_entity, &mut state);
+            },
+        )
+        .observe(
+            *current_widget,
+            move |mut trigger: On<Pointer<MouseWheelScroll>>,
+                  mut state_query: Query<&mut ComboBoxState>,
+                  combo_box_query: Query<&ComboBox>| {
+                trigger.propagate(false);
+                let Ok(mut state) = state_query.get_mut(state_entity) else {
+                    return;
+                };
+                let Ok(combo_box) = combo_box_query.get(combo_box_entity) else {
+                    return;
+                };
+                let visible = combo_box.max_visible_options.max(1);
+                let max_scroll = combo_box.matches(&state).len().saturating_sub(visible);
+                let lines = trigger.lines(option_height).y;
+                if lines == 0.0 {
+                    return;
+                }
+                let rows = lines.abs().ceil() as usize;
+                state.scroll = if lines > 0.0 {
+                    state.scroll.saturating_sub(rows)
+                } else {
+                    (state.scroll + rows).min(max_scroll)
+                };
+            },
+        )
+        .observe(
+            *current_widget,
+            move |_trigger: On<Pointer<Over>>, mut state_query: Query<&mut ComboBoxState>| {
+                if let Ok(mut state) = state_query.get_mut(state_entity) {
+                    state.hovering_list = true;
+                }
+            },
+        )
+        .observe(
+            *current_widget,
+            move |_trigger: On<Pointer<Out>>, mut state_query: Query<&mut ComboBoxState>| {
+                if let Ok(mut state) = state_query.get_mut(state_entity) {
+                    state.hovering_list = false;
+                }
+            },
+        );
+
+    children.apply(current_widget.as_parent());
+}
+
+#[test]
+fn test_combo_box_matching() {
+    assert_eq!(
+        match_label("app", "Apple", ComboBoxFilter::Prefix),
+        Some((3000, vec![0..3]))
+    );
+    assert_eq!(match_label("ple", "Apple", ComboBoxFilter::Prefix), None);
+    assert_eq!(
+        match_label("ple", "Apple", ComboBoxFilter::Fuzzy),
+        Some((998, vec![2..5]))
+    );
+    // Word starts rank above other substrings.
+    assert_eq!(
+        match_label("pie", "Apple pie", ComboBoxFilter::Fuzzy),
+        Some((1994, vec![6..9]))
+    );
+    // Characters in order.
+    assert_eq!(
+        match_label("rdsw", "Red Sword", ComboBoxFilter::Fuzzy).map(|(_, ranges)| ranges),
+        Some(vec![0..1, 2..3, 4..6])
+    );
+    assert_eq!(
+        match_label("swr", "Red Sword", ComboBoxFilter::Fuzzy).map(|(_, r)| r),
+        Some(vec![4..6, 7..8])
+    );
+    assert_eq!(match_label("xyz", "Red Sword", ComboBoxFilter::Fuzzy), None);
+    // Byte ranges with multibyte characters.
+    assert_eq!(
+        match_label("É", "Crème Éclair", ComboBoxFilter::Fuzzy),
+        Some((2000 - 6, vec![7..9]))
+    );
+
+    let items = vec![
+        DropdownItem::from("Short Sword"),
+        DropdownItem::from("Sword").disabled(),
+        DropdownItem::from("Swordfish"),
+        DropdownItem::from("Shield"),
+    ];
+    let matches = filter_options(&items, "sw", ComboBoxFilter::Fuzzy);
+    assert_eq!(
+        matches
+            .iter()
+            .map(|option| option.index)
+            .collect::<Vec<_>>(),
+        vec![1, 2, 0]
+    );
+    assert_eq!(filter_options(&items, "", ComboBoxFilter::Fuzzy).len(), 4);
+
+    // Disabled items are skipped.
+    assert_eq!(move_highlight(&items, &matches, None, 1), Some(2));
+    assert_eq!(move_highlight(&items, &matches, Some(2), 1), Some(0));
+    assert_eq!(move_highlight(&items, &matches, Some(0), 5), Some(0));
+    assert_eq!(move_highlight(&items, &matches, None, -1), Some(0));
+
+    assert_eq!(scroll_into_view(0, Some(9), 8), 2);
+    assert_eq!(scroll_into_view(5, Some(3), 8), 3);
+    assert_eq!(scroll_into_view(2, Some(4), 8), 2);
+}
@@ -0,0 +1,200 @@
+use std::ops::Range;
+
+use bevy::prelude::*;
+use bevy_vello::vello::{kurbo, peniko::Brush};
+
+use super::find_replace::range_geometry;
+
+/// How severe a diagnostic is.
+///
+/// Ordered from least to most severe.
+#[derive(Debug, Default, Reflect, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DiagnosticSeverity {
+    /// A suggestion
+    Hint,
+    /// Information
+    Info,
+    /// A warning
+    Warning,
+    /// An error
+    #[default]
+    Error,
+}
+
+impl DiagnosticSeverity {
+    /// The color used for the underline and gutter icon.
+    pub fn color(&self) -> Color {
+        match self {
+            Self::Hint => Srgba::new(0.6, 0.6, 0.6, 1.0).into(),
+            Self::Info => Srgba::new(0.349, 0.616, 0.969, 1.0).into(),
+            Self::Warning => Srgba::new(0.957, 0.769, 0.282, 1.0).into(),
+            Self::Error => Srgba::new(0.957, 0.329, 0.329, 1.0).into(),
+        }
+    }
+}
+
+/// A diagnostic shown in a textbox, ex: a compiler error.
+#[derive(Debug, Default, Reflect, Clone, PartialEq)]
+pub struct Diagnostic {
+    /// The byte range the diagnostic covers.
+    pub range: Range<usize>,
+    /// The severity
+    pub severity: DiagnosticSeverity,
+    /// The message shown when hovering the diagnostic.
+    pub message: String,
+}
+
+impl Diagnostic {
+    /// Creates a new diagnostic.
+    pub fn new(
+        range: Range<usize>,
+        severity: DiagnosticSeverity,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            range,
+            severity,
+            message: message.into(),
+        }
+    }
+}
+
+/// Clamps a diagnostic range to the text so diagnostics computed for
+/// an older version of the text still render.
+///
+/// Empty ranges are widened to cover one character.
+pub(crate) fn clamp_range(text: &str, range: &Range<usize>) -> Range<usize> {
+    let floor = |mut index: usize| {
+        index = index.min(text.len());
+        while !text.is_char_boundary(index) {
+            index -= 1;
+        }
+        index
+    };
+    let mut start = floor(range.start);
+    let mut end = floor(range.end.max(range.start));
+    if start == end {
+        if let Some(c) = text[end..].chars().next() {
+            end += c.len_utf8();
+        } else if let Some(c) = text[..start].chars().next_back() {
+            start -= c.len_utf8();
+        }
+    }
+    start..end
+}
+
+/// The bounding boxes covering each diagnostic.
+pub(crate) fn diagnostics_geometry(
+    layout: &parley::Layout<Brush>,
+    text: &str,
+    diagnostics: &[Diagnostic],
+) -> Vec<Vec<parley::BoundingBox>> {
+    diagnostics
+        .iter()
+        .map(|diagnostic| range_geometry(layout, clamp_range(text, &diagnostic.range)))
+        .collect()
+}
+
+/// Finds the diagnostic under a point local to the text.
+///
+/// Points left of the text(in the gutter) match any diagnostic on the same line.
+pub(crate) fn diagnostic_at(
+    geometry: &[Vec<parley::BoundingBox>],
+    point: Vec2,
+    single_line: bool,
+) -> Option<usize> {
+    let (x, y) = (point.x as f64, point.y as f64);
+    let on_line = |bounds: &parley::BoundingBox| single_line || (bounds.y0 <= y && y <= bounds.y1);
+    geometry.iter().position(|boxes| {
+        boxes
+            .iter()
+            .any(|bounds| on_line(bounds) && (x < 0.0 || (bounds.x0 <= x && x <= bounds.x1)))
+    })
+}
+
+/// The gutter icons, one per line with the most severe diagnostic on that line.
+pub(crate) fn gutter_markers(
+    geometry: &[Vec<parley::BoundingBox>],
+    diagnostics: &[Diagnostic],
+) -> Vec<(parley::BoundingBox, DiagnosticSeverity)> {
+    let mut markers: Vec<(parley::BoundingBox, DiagnosticSeverity)> = vec![];
+    for (boxes, diagnostic) in geometry.iter().zip(diagnostics) {
+        let Some(first) = boxes.first() else {
+            continue;
+        };
+        match markers
+            .iter_mut()
+            .find(|(bounds, _)| (bounds.y0 - first.y0).abs() < 0.5)
+        {
+            Some((_, severity)) => *severity = (*severity).max(diagnostic.severity),
+            None => markers.push((*first, diagnostic.severity)),
+        }
+    }
+    markers
+}
+
+/// A squiggly line from `x0` to `x1` centered on `y`.
+pub(crate) fn squiggle(x0: f64, x1: f64, y: f64) -> kurbo::BezPath {
+    const HALF_WAVE: f64 = 2.0;
+    const AMPLITUDE: f64 = 1.5;
+    let mut path = kurbo::BezPath::new();
+    path.move_to((x0, y));
+    let mut x = x0;
+    let mut up = true;
+    while x < x1 {
+        x = (x + HALF_WAVE).min(x1);
+        path.line_to((x, if up { y - AMPLITUDE } else { y + AMPLITUDE }));
+        up = !up;
+    }
+    path
+}
+
+/// The shape drawn in the gutter for a severity, centered on `center`.
+pub(crate) fn gutter_icon(severity: DiagnosticSeverity, center: kurbo::Point) -> kurbo::BezPath {
+    use kurbo::Shape;
+    match severity {
+        DiagnosticSeverity::Warning => {
+            let mut path = kurbo::BezPath::new();
+            path.move_to((center.x, center.y - 4.5));
+            path.line_to((center.x + 4.5, center.y + 3.5));
+            path.line_to((center.x - 4.5, center.y + 3.5));
+            path.close_path();
+            path
+        }
+        DiagnosticSeverity::Error => kurbo::Circle::new(center, 4.0).to_path(0.1),
+        DiagnosticSeverity::Info | DiagnosticSeverity::Hint => {
+            kurbo::Circle::new(center, 3.0).to_path(0.1)
+        }
+    }
+}
+
+#[test]
+fn test_diagnostics() {
+    let text = "let é = 1;";
+    assert_eq!(clamp_range(text, &(4..6)), 4..6);
+    // Inside a multibyte char.
+    assert_eq!(clamp_range(text, &(5..5)), 4..6);
+    // Past the end of the text.
+    assert_eq!(clamp_range(text, &(20..30)), 10..11);
+    assert_eq!(clamp_range("", &(0..3)), 0..0);
+
+    let line = |y0: f64, x0: f64, x1: f64| parley::BoundingBox::new(x0, y0, x1, y0 + 10.0);
+    let geometry = vec![vec![line(0.0, 10.0, 20.0)], vec![line(0.0, 30.0, 40.0)]];
+    assert_eq!(
+        diagnostic_at(&geometry, Vec2::new(35.0, 5.0), false),
+        Some(1)
+    );
+    assert_eq!(diagnostic_at(&geometry, Vec2::new(35.0, 15.0), false), None);
+    assert_eq!(
+        diagnostic_at(&geometry, Vec2::new(-5.0, 5.0), false),
+        Some(0)
+    );
+
+    let diagnostics = vec![
+        Diagnostic::new(0..1, DiagnosticSeverity::Warning, "unused"),
+        Diagnostic::new(0..1, DiagnosticSeverity::Error, "mismatched types"),
+    ];
+    let markers = gutter_markers(&geometry, &diagnostics);
+    assert_eq!(markers.len(), 1);
+    assert_eq!(markers[0].1, DiagnosticSeverity::Error);
+}
@@ -1,11 +1,66 @@
 use crate::prelude::*;
 use bevy::prelude::*;
 
-/// A textbox change event.
+/// How long type-ahead keeps adding to the search before starting over, in seconds.
+const TYPE_AHEAD_TIMEOUT: f64 = 1.0;
+
+/// A dropdown change event.
 #[derive(Debug, Clone, Reflect)]
 pub struct DropdownChanged {
-    /// The current text value
+    /// The index of the selected item
+    pub index: usize,
+    /// The value of the selected item
     pub value: String,
+    /// The label of the selected item
+    pub label: String,
+}
+
+/// An item in a [`Dropdown`].
+#[derive(Debug, Default, Clone, PartialEq, Reflect)]
+pub struct DropdownItem {
+    /// The text shown for the item
+    pub label: String,
+    /// The value reported when the item is selected
+    pub value: String,
+    /// An optional icon shown before the label
+    pub icon: Option<Handle<SvgAsset>>,
+    /// Disabled items are shown but can't be selected
+    pub disabled: bool,
+}
+
+impl DropdownItem {
+    /// Creates a new item.
+    pub fn new(label: impl Into<String>, value: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            value: value.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Adds an icon to the item.
+    pub fn with_icon(mut self, icon: Handle<SvgAsset>) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    /// Disables the item.
+    pub fn disabled(mut self) -> Self {
+        self.disabled = true;
+        self
+    }
+}
+
+impl From<&str> for DropdownItem {
+    fn from(label: &str) -> Self {
+        Self::new(label, label)
+    }
+}
+
+impl From<String> for DropdownItem {
+    fn from(label: String) -> Self {
+        Self::new(label.clone(), label)
+    }
 }
 
 /// Dropdown Styles
@@ -21,6 +76,10 @@ pub struct DropdownStyles {
     pub list_area: WoodpeckerStyle,
     /// Dropdown List Item Styles
     pub list_item: ButtonStyles,
+    /// Text Styles of disabled list items
+    pub disabled_text: WoodpeckerStyle,
+    /// List Item Icon Styles
+    pub item_icon: WoodpeckerStyle,
 }
 
 impl Default for DropdownStyles {
@@ -49,10 +108,6 @@ impl Default for DropdownStyles {
             },
             list_area: WoodpeckerStyle {
                 background_color: colors::BACKGROUND,
-                position: WidgetPosition::Absolute,
-                left: 0.0.into(), // 0px because padding is 30px.
-                top: (54.0 + 20.0).into(),
-                width: Units::Percentage(100.0),
                 min_height: 54.0.into(),
                 flex_direction: WidgetFlexDirection::Column,
                 ..Default::default()
@@ -75,6 +130,20 @@ impl Default for DropdownStyles {
                     ..Default::default()
                 },
             },
+            disabled_text: WoodpeckerStyle {
+                color: colors::BACKGROUND_LIGHT,
+                font_size: 32.0,
+                flex_grow: 1.0,
+                text_wrap: TextWrap::None,
+                ..Default::default()
+            },
+            item_icon: WoodpeckerStyle {
+                color: Color::WHITE,
+                width: 24.0.into(),
+                height: 24.0.into(),
+                margin: Edge::all(0.0).right(10.0),
+                ..Default::default()
+            },
         }
     }
 }
@@ -82,27 +151,113 @@ impl Default for DropdownStyles {
 /// Dropdown state
 #[derive(Default, Debug, Component, Clone, PartialEq, Reflect)]
 pub struct DropdownState {
-    /// Is open?
-    is_open: bool,
-    /// The current value
-    current_value: String,
+    /// Is the list open?
+    pub is_open: bool,
+    /// The selected item, unused when the dropdown is controlled
+    pub selected: Option<usize>,
+    /// The item highlighted by the keyboard or pointer while the list is open
+    pub highlighted: Option<usize>,
+    type_ahead: String,
+    type_ahead_time: f64,
+    hovering_list: bool,
+}
+
+impl DropdownState {
+    fn open(&mut self, selected: Option<usize>, items: &[DropdownItem]) {
+        self.is_open = true;
+        self.highlighted = selected.or_else(|| next_enabled(items, None, true));
+    }
+
+    fn close(&mut self) {
+        self.is_open = false;
+        self.highlighted = None;
+        self.hovering_list = false;
+    }
 }
 
-/// A dropdown widget
+/// A select widget that lets the user pick one of a list of items.
+///
+/// While focused the list can be opened with Enter, Space or the arrow keys, navigated
+/// with Up/Down/Home/End or by typing the start of a label, Enter selects the highlighted
+/// item and Escape closes the list.
+///
+/// By default the dropdown keeps track of the selection itself, starting at `selected`.
+/// When `controlled` is set the parent owns the selection: `selected` is always shown and
+/// picking an item only emits [`DropdownChanged`], the parent is expected to update `selected`.
 #[derive(Widget, Default, Component, Clone, PartialEq, Reflect)]
 #[auto_update(render)]
 #[props(Dropdown)]
 #[state(DropdownState)]
 #[require(WoodpeckerStyle, WidgetRender = WidgetRender::Quad, WidgetChildren, Pickable, Focusable)]
 pub struct Dropdown {
-    /// The current value
-    pub current_value: String,
-    /// A list of items in the dropdown
-    pub list: Vec<String>,
+    /// The items in the dropdown
+    pub items: Vec<DropdownItem>,
+    /// The selected item
+    pub selected: Option<usize>,
+    /// If true the parent owns the selection
+    pub controlled: bool,
+    /// The text shown when nothing is selected
+    pub placeholder: String,
     /// Styles
     pub styles: DropdownStyles,
 }
 
+impl Dropdown {
+    /// The selected item index, taking controlled mode into account.
+    pub fn current_selection(&self, state: &DropdownState) -> Option<usize> {
+        let selected = if self.controlled {
+            self.selected
+        } else {
+            state.selected
+        };
+        selected.filter(|index| *index < self.items.len())
+    }
+
+    /// Selects an item, closes the list and emits [`DropdownChanged`].
+    fn select(
+        &self,
+        commands: &mut Commands,
+        dropdown_entity: Entity,
+        state: &mut DropdownState,
+        index: usize,
+    ) {
+        let Some(item) = self.items.get(index).filter(|item| !item.disabled) else {
+            return;
+        };
+        if !self.controlled {
+            state.selected = Some(index);
+        }
+        state.close();
+        commands.trigger(Change {
+            target: dropdown_entity,
+            data: DropdownChanged {
+                index,
+                value: item.value.clone(),
+                label: item.label.clone(),
+            },
+        });
+    }
+}
+
+/// The next enabled item after `from`, or the first(last when going backwards) if `from` is `None`.
+fn next_enabled(items: &[DropdownItem], from: Option<usize>, forward: bool) -> Option<usize> {
+    let enabled = |i: &usize| !items[*i].disabled;
+    match (from, forward) {
+        (None, true) => (0..items.len()).find(enabled),
+        (None, false) => (0..items.len()).rev().find(enabled),
+        (Some(from), true) => (from + 1..items.len()).find(enabled),
+        (Some(from), false) => (0..from.min(items.len())).rev().find(enabled),
+    }
+}
+
+/// The first enabled item at or after `start`, wrapping around, whose label starts with `query`.
+fn type_ahead_match(items: &[DropdownItem], query: &str, start: usize) -> Option<usize> {
+    let query = query.to_lowercase();
+    (0..items.len())
+        .map(|i| (start + i) % items.len())
+        .find(|i| !items[*i].disabled && items[*i].label.to_lowercase().starts_with(&query))
+}
+
 fn render(
     mut commands: Commands,
     mut hooks: ResMut<HookHelper>,
@@ -119,8 +274,8 @@ fn render(
         &mut commands,
         *current_widget,
         DropdownState {
-            is_open: false,
-            current_value: dropdown.current_value.clone(),
+            selected: dropdown.selected,
+            ..Default::default()
         },
     );
 
@@ -130,27 +285,168 @@ fn render(
 
     *styles = dropdown.styles.background;
 
+    let dropdown_entity = **current_widget;
+    let selected = dropdown
+        .current_selection(state)
+        .map(|index| &dropdown.items[index]);
+
     *children = WidgetChildren::default()
         .with_observe(
             *current_widget,
-            move |_trigger: On<Pointer<Click>>, mut state_query: Query<&mut DropdownState>| {
+            move |_trigger: On<Pointer<Click>>,
+                  mut state_query: Query<&mut DropdownState>,
+                  dropdown_query: Query<&Dropdown>| {
+                let Ok(mut state) = state_query.get_mut(state_entity) else {
+                    return;
+                };
+                let Ok(dropdown) = dropdown_query.get(dropdown_entity) else {
+                    return;
+                };
+
+                if state.is_open {
+                    state.close();
+                } else {
+                    let selected = dropdown.current_selection(&state);
+                    state.open(selected, &dropdown.items);
+                }
+            },
+        )
+        .with_observe(
+            *current_widget,
+            move |_trigger: On<WidgetBlur>, mut state_query: Query<&mut DropdownState>| {
                 let Ok(mut state) = state_query.get_mut(state_entity) else {
                     return;
                 };
+                // Clicking an item blurs the dropdown first, let the click go through.
+                if state.is_open && !state.hovering_list {
+                    state.close();
+                }
+            },
+        )
+        .with_observe(
+            *current_widget,
+            move |trigger: On<WidgetKeyboardButtonEvent>,
+                  mut commands: Commands,
+                  mut state_query: Query<&mut DropdownState>,
+                  dropdown_query: Query<&Dropdown>| {
+                let Ok(mut state) = state_query.get_mut(state_entity) else {
+                    return;
+                };
+                let Ok(dropdown) = dropdown_query.get(dropdown_entity) else {
+                    return;
+                };
+                let items = &dropdown.items;
+
+                if !state.is_open {
+                    if matches!(
+                        trigger.code,
+                        KeyCode::Enter | KeyCode::Space | KeyCode::ArrowDown | KeyCode::ArrowUp
+                    ) {
+                        let selected = dropdown.current_selection(&state);
+                        state.open(selected, items);
+                    }
+                    return;
+                }
 
-                state.is_open = !state.is_open;
+                match trigger.code {
+                    KeyCode::ArrowDown => {
+                        state.highlighted =
+                            next_enabled(items, state.highlighted, true).or(state.highlighted);
+                    }
+                    KeyCode::ArrowUp => {
+                        state.highlighted =
+                            next_enabled(items, state.highlighted, false).or(state.highlighted);
+                    }
+                    KeyCode::Home => state.highlighted = next_enabled(items, None, true),
+                    KeyCode::End => state.highlighted = next_enabled(items, None, false),
+                    KeyCode::Enter => {
+                        if let Some(index) = state.highlighted {
+                            dropdown.select(&mut commands, dropdown_entity, &mut state, index);
+                        }
+                    }
+                    KeyCode::Escape | KeyCode::Tab => state.close(),
+                    _ => {}
+                }
             },
         )
-        // Text
-        .with_child::<Element>((
+        .with_observe(
+            *current_widget,
+            move |trigger: On<WidgetKeyboardCharEvent>,
+                  time: Res<Time>,
+                  mut commands: Commands,
+                  mut state_query: Query<&mut DropdownState>,
+                  dropdown_query: Query<&Dropdown>| {
+                let Ok(mut state) = state_query.get_mut(state_entity) else {
+                    return;
+                };
+                let Ok(dropdown) = dropdown_query.get(dropdown_entity) else {
+                    return;
+                };
+                if trigger.c.trim().is_empty() || dropdown.items.is_empty() {
+                    return;
+                }
+
+                let now = time.elapsed_secs_f64();
+                if now - state.type_ahead_time > TYPE_AHEAD_TIMEOUT {
+                    state.type_ahead.clear();
+                }
+                state.type_ahead_time = now;
+                state.type_ahead.push_str(&trigger.c.to_lowercase());
+
+                // Repeating the same letter cycles through the items starting with it.
+                let first = state.type_ahead.chars().next().unwrap_or_default();
+                let cycling = state.type_ahead.chars().all(|c| c == first);
+                let search = if cycling {
+                    first.to_string()
+                } else {
+                    state.type_ahead.clone()
+                };
+                let current = if state.is_open {
+                    state.highlighted
+                } else {
+                    dropdown.current_selection(&state)
+                };
+                let start = match current {
+                    Some(index) if cycling => index + 1,
+                    Some(index) => index,
+                    None => 0,
+                };
+                let Some(index) = type_ahead_match(&dropdown.items, &search, start) else {
+                    return;
+                };
+                if state.is_open {
+                    state.highlighted = Some(index);
+                } else {
+                    dropdown.select(&mut commands, dropdown_entity, &mut state, index);
+                }
+            },
+        );
+
+    // Selected icon
+    if let Some(icon) = selected.and_then(|item| item.icon.clone()) {
+        children.add::<Element>((
+            Element,
+            dropdown.styles.item_icon,
+            WidgetRender::Svg {
+                handle: icon,
+                color: Some(dropdown.styles.item_icon.color),
+            },
+        ));
+    }
+
+    // Text
+    children
+        .add::<Element>((
             Element,
             dropdown.styles.text,
             WidgetRender::Text {
-                content: state.current_value.clone(),
+                content: selected
+                    .map(|item| item.label.clone())
+                    .unwrap_or_else(|| dropdown.placeholder.clone()),
             },
         ))
         // Icon
-        .with_child::<Element>((
+        .add::<Element>((
             Element,
             dropdown.styles.icon,
             WidgetRender::Svg {
@@ -164,27 +460,55 @@ fn render(
             },
         ));
 
-    let dropdown_entity = **current_widget;
     let mut list_children = WidgetChildren::default();
 
     // List area
-    for (i, item) in dropdown.list.iter().enumerate() {
+    for (i, item) in dropdown.items.iter().enumerate() {
+        let list_item = dropdown.styles.list_item;
+        let item_styles = if item.disabled {
+            ButtonStyles {
+                hovered: list_item.normal,
+                ..list_item
+            }
+        } else if state.highlighted == Some(i) {
+            ButtonStyles {
+                normal: list_item.hovered,
+                ..list_item
+            }
+        } else {
+            list_item
+        };
+
+        let mut item_children = WidgetChildren::default();
+        if let Some(icon) = item.icon.clone() {
+            item_children.add::<Element>((
+                Element,
+                dropdown.styles.item_icon,
+                WidgetRender::Svg {
+                    handle: icon,
+                    color: Some(dropdown.styles.item_icon.color),
+                },
+            ));
+        }
+        item_children.add::<Element>((
+            Element,
+            if item.disabled {
+                dropdown.styles.disabled_text
+            } else {
+                dropdown.styles.text
+            },
+            WidgetRender::Text {
+                content: item.label.clone(),
+            },
+        ));
+
         list_children
-            .add::<WButton>((
-                WButton,
-                WidgetChildren::default().with_child::<Element>((
-                    Element,
-                    dropdown.styles.text,
-                    WidgetRender::Text {
-                        content: item.clone(),
-                    },
-                )),
-                dropdown.styles.list_item,
-            ))
+            .add::<WButton>((WButton, item_children, item_styles))
             .observe(
                 *current_widget,
                 move |mut trigger: On<Pointer<Click>>,
                       mut commands: Commands,
+                      mut current_focus: ResMut<CurrentFocus>,
                       mut state_query: Query<&mut DropdownState>,
                       dropdown_query: Query<&Dropdown>| {
                     trigger.propagate(false);
@@ -194,34 +518,108 @@ fn render(
                     let Ok(dropdown) = dropdown_query.get(dropdown_entity) else {
                         return;
                     };
-                    state.current_value.clone_from(&dropdown.list[i]);
-                    state.is_open = false;
-                    commands.trigger(
-                        Change {
-                            target: dropdown_entity,
-                            data: DropdownChanged {
-                                value: state.current_value.clone(),
-                            },
-                        },
-                        //dropdown_entity,
-                    );
+                    if dropdown.items.get(i).is_none_or(|item| item.disabled) {
+                        return;
+                    }
+                    dropdown.select(&mut commands, dropdown_entity, &mut state, i);
+                    // Keep keyboard navigation working after picking with the pointer.
+                    current_focus.refocus(&mut commands, dropdown_entity);
+                },
+            )
+            .observe(
+                *current_widget,
+                move |_trigger: On<Pointer<Over>>,
+                      mut state_query: Query<&mut DropdownState>,
+                      dropdown_query: Query<&Dropdown>| {
+                    let Ok(mut state) = state_query.get_mut(state_entity) else {
+                        return;
+                    };
+                    let Ok(dropdown) = dropdown_query.get(dropdown_entity) else {
+                        return;
+                    };
+                    if dropdown.items.get(i).is_some_and(|item| !item.disabled) {
+                        state.highlighted = Some(i);
+                    }
                 },
             );
     }
-    children.add::<Element>((
-        Element,
-        WoodpeckerStyle {
-            display: if state.is_open {
-                WidgetDisplay::Flex
-            } else {
-                WidgetDisplay::None
+    children
+        .add::<Popover>((
+            Popover {
+                open: state.is_open,
+                match_anchor_size: true,
+                styles: dropdown.styles.list_area,
+                ..Default::default()
             },
-            z_index: Some(WidgetZ::Global(1000)),
-            ..dropdown.styles.list_area
-        },
-        list_children,
-        WidgetRender::Quad,
-    ));
+            PassedChildren(list_children),
+        ))
+        .observe(
+            *current_widget,
+            move |_trigger: On<PopoverDismissed>, mut state_query: Query<&mut DropdownState>| {
+                if let Ok(mut state) = state_query.get_mut(state_entity) {
+                    state.close();
+                }
+            },
+        )
+        .observe(*current_widget, move |mut trigger: On<Pointer<Click>>| {
+            // The list is a child of the dropdown, don't toggle it.
+            trigger.propagate(false);
+        })
+        .observe(
+            *current_widget,
+            move |_trigger: On<Pointer<Over>>, mut state_query: Query<&mut DropdownState>| {
+                if let Ok(mut state) = state_query.get_mut(state_entity) {
+                    state.hovering_list = true;
+                }
+            },
+        )
+        .observe(
+            *current_widget,
+            move |_trigger: On<Pointer<Out>>, mut state_query: Query<&mut DropdownState>| {
+                if let Ok(mut state) = state_query.get_mut(state_entity) {
+                    state.hovering_list = false;
+                }
+            },
+        );
 
     children.apply(current_widget.as_parent());
 }
+
+#[test]
+fn test_dropdown_navigation() {
+    let items = vec![
+        DropdownItem::from("Apple").disabled(),
+        DropdownItem::from("Banana"),
+        DropdownItem::from("Blueberry"),
+        DropdownItem::from("Cherry"),
+        DropdownItem::from("Date").disabled(),
+    ];
+    assert_eq!(next_enabled(&items, None, true), Some(1));
+    assert_eq!(next_enabled(&items, None, false), Some(3));
+    assert_eq!(next_enabled(&items, Some(1), true), Some(2));
+    assert_eq!(next_enabled(&items, Some(1), false), None);
+    assert_eq!(next_enabled(&items, Some(3), true), None);
+
+    assert_eq!(type_ahead_match(&items, "b", 0), Some(1));
+    assert_eq!(type_ahead_match(&items, "B", 2), Some(2));
+    // Wraps around.
+    assert_eq!(type_ahead_match(&items, "b", 3), Some(1));
+    assert_eq!(type_ahead_match(&items, "blu", 0), Some(2));
+    // Disabled items are skipped.
+    assert_eq!(type_ahead_match(&items, "a", 0), None);
+
+    let mut dropdown = Dropdown {
+        items,
+        selected: Some(3),
+        ..Default::default()
+    };
+    let state = DropdownState {
+        selected: Some(2),
+        ..Default::default()
+    };
+    assert_eq!(dropdown.current_selection(&state), Some(2));
+    dropdown.controlled = true;
+    assert_eq!(dropdown.current_selection(&state), Some(3));
+    dropdown.selected = Some(10);
+    assert_eq!(dropdown.current_selection(&state), None);
+}
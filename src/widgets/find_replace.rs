@@ -0,0 +1,196 @@
+use std::ops::Range;
+
+use bevy::prelude::*;
+use bevy_vello::vello::peniko::Brush;
+use regex::{Regex, RegexBuilder};
+
+/// Options used when searching text.
+#[derive(Debug, Default, Reflect, Clone, Copy, PartialEq)]
+pub struct FindOptions {
+    /// Match upper and lower case exactly.
+    pub case_sensitive: bool,
+    /// Only match whole words.
+    pub whole_word: bool,
+    /// Treat the query as a regular expression.
+    pub regex: bool,
+}
+
+/// The find/replace state of a textbox.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct FindState {
+    /// Is the find bar open?
+    pub open: bool,
+    /// Is the replace row open?
+    pub replace_open: bool,
+    /// The search query.
+    pub query: String,
+    /// The replacement text.
+    /// In regex mode capture groups can be referenced with `$1`, `${name}`, etc.
+    pub replacement: String,
+    /// Search options
+    pub options: FindOptions,
+    /// The index of the currently selected match.
+    pub current: Option<usize>,
+    /// The query last pushed into the query textbox, typing doesn't change it so the textbox
+    /// isn't reset.
+    pub(crate) synced_query: String,
+    /// The replacement the replace textbox was created with.
+    pub(crate) synced_replacement: String,
+    /// The query textbox, once the user typed in it.
+    pub(crate) query_box: Option<Entity>,
+}
+
+impl FindState {
+    /// Hides the find bar, its textboxes are despawned.
+    pub(crate) fn close(&mut self) {
+        self.open = false;
+        self.current = None;
+        self.query_box = None;
+    }
+}
+
+fn build_regex(query: &str, options: FindOptions) -> Option<Regex> {
+    if query.is_empty() {
+        return None;
+    }
+    let mut pattern = if options.regex {
+        query.to_string()
+    } else {
+        regex::escape(query)
+    };
+    if options.whole_word {
+        pattern = format!(r"\b(?:{pattern})\b");
+    }
+    RegexBuilder::new(&pattern)
+        .case_insensitive(!options.case_sensitive)
+        .multi_line(true)
+        .build()
+        .ok()
+}
+
+/// Finds every non-empty match of the query in the text.
+///
+/// Invalid regular expressions produce no matches.
+pub(crate) fn find_matches(text: &str, query: &str, options: FindOptions) -> Vec<Range<usize>> {
+    let Some(regex) = build_regex(query, options) else {
+        return vec![];
+    };
+    regex
+        .find_iter(text)
+        .filter(|m| !m.is_empty())
+        .map(|m| m.range())
+        .collect()
+}
+
+/// Returns the replacement for the match at `range`, expanding capture groups in regex mode.
+///
+/// Returns `None` if `range` is not a match.
+pub(crate) fn replace_match(
+    text: &str,
+    range: Range<usize>,
+    query: &str,
+    replacement: &str,
+    options: FindOptions,
+) -> Option<String> {
+    let regex = build_regex(query, options)?;
+    let captures = regex.captures_at(text, range.start)?;
+    let whole = captures.get(0)?;
+    if whole.range() != range {
+        return None;
+    }
+    if !options.regex {
+        return Some(replacement.to_string());
+    }
+    let mut expanded = String::new();
+    captures.expand(replacement, &mut expanded);
+    Some(expanded)
+}
+
+/// Replaces every match of the query in the text.
+///
+/// Empty matches are skipped like in [`find_matches`]. Returns `None` if nothing was replaced.
+pub(crate) fn replace_all(
+    text: &str,
+    query: &str,
+    replacement: &str,
+    options: FindOptions,
+) -> Option<String> {
+    let regex = build_regex(query, options)?;
+    let mut replaced = String::with_capacity(text.len());
+    let mut last = 0;
+    let mut any = false;
+    for captures in regex.captures_iter(text) {
+        let Some(whole) = captures.get(0).filter(|m| !m.is_empty()) else {
+            continue;
+        };
+        replaced.push_str(&text[last..whole.start()]);
+        if options.regex {
+            captures.expand(replacement, &mut replaced);
+        } else {
+            replaced.push_str(replacement);
+        }
+        last = whole.end();
+        any = true;
+    }
+    if !any {
+        return None;
+    }
+    replaced.push_str(&text[last..]);
+    Some(replaced)
+}
+
+/// The bounding boxes covering a byte range of the layout.
+pub(crate) fn range_geometry(
+    layout: &parley::Layout<Brush>,
+    range: Range<usize>,
+) -> Vec<parley::BoundingBox> {
+    parley::Selection::new(
+        parley::Cursor::from_byte_index(layout, range.start, parley::Affinity::Downstream),
+        parley::Cursor::from_byte_index(layout, range.end, parley::Affinity::Upstream),
+    )
+    .geometry(layout)
+    .into_iter()
+    .map(|(bounds, _)| bounds)
+    .collect()
+}
+
+#[test]
+fn test_find_replace() {
+    let text = "let Foo = foo(foobar);";
+    let options = FindOptions::default();
+    assert_eq!(
+        find_matches(text, "foo", options),
+        vec![4..7, 10..13, 14..17]
+    );
+
+    let whole_word = FindOptions {
+        whole_word: true,
+        ..options
+    };
+    assert_eq!(find_matches(text, "foo", whole_word), vec![4..7, 10..13]);
+
+    let case_sensitive = FindOptions {
+        case_sensitive: true,
+        whole_word: true,
+        ..options
+    };
+    assert_eq!(find_matches(text, "foo", case_sensitive), vec![10..13]);
+    assert_eq!(
+        replace_all(text, "foo", "$1", case_sensitive).as_deref(),
+        Some("let Foo = $1(foobar);")
+    );
+
+    let regex = FindOptions {
+        regex: true,
+        case_sensitive: true,
+        ..options
+    };
+    assert_eq!(
+        replace_match(text, 14..20, r"foo(\w+)", "${1}_baz", regex).as_deref(),
+        Some("bar_baz")
+    );
+    assert_eq!(replace_match(text, 10..13, r"foo(\w+)", "", regex), None);
+    assert!(find_matches(text, "(", regex).is_empty());
+    assert_eq!(replace_all("ab", "x*", "-", regex).as_deref(), None);
+    assert_eq!(replace_all("axb", "x*", "-", regex).as_deref(), Some("a-b"));
+}
@@ -2,50 +2,102 @@ use crate::prelude::Change;
 use crate::WidgetRegisterExt;
 use bevy::prelude::*;
 
+mod animation;
 mod app;
+mod autocomplete;
 mod button;
 mod checkbox;
 mod clip;
+mod code_editor;
 mod color_picker;
 /// A set of default colors used by Woodpecker UI.
 pub mod colors;
+mod combo_box;
+mod diagnostics;
 mod dropdown;
 mod element;
+mod find_replace;
 mod icon_button;
 mod modal;
+mod mount_transition;
+mod multi_select;
+mod number_input;
+mod popover;
+mod radio;
 mod scroll;
 mod slider;
+mod spring;
 mod tab;
+mod table;
+mod tag_input;
 mod text_box;
 mod toggle;
 mod transition;
+mod tree_view;
+mod undo;
+mod virtual_list;
 mod window;
 mod windowing_context;
 
 mod syntax_highlighting;
-pub use syntax_highlighting::highlight;
+pub use syntax_highlighting::{highlight, SyntaxHighlighter};
 
+pub use animation::*;
 pub use app::WoodpeckerApp;
+pub use autocomplete::{AutocompleteState, SuggestionProvider};
 // use bevy_mod_picking::prelude::EventListenerPlugin;
 pub use button::{ButtonStyles, WButton};
 pub use checkbox::{
     Checkbox, CheckboxChanged, CheckboxState, CheckboxStyles, CheckboxWidgetStyles,
 };
 pub use clip::Clip;
+pub use code_editor::{BracketPair, CodeEditorSettings};
 pub use color_picker::{ColorPicker, ColorPickerChanged};
-pub use dropdown::{Dropdown, DropdownChanged, DropdownStyles};
+pub use combo_box::{ComboBox, ComboBoxChanged, ComboBoxFilter, ComboBoxState, ComboBoxStyles};
+pub use diagnostics::{Diagnostic, DiagnosticSeverity};
+pub use dropdown::{Dropdown, DropdownChanged, DropdownItem, DropdownState, DropdownStyles};
 pub use element::Element;
+pub use find_replace::{FindOptions, FindState};
 pub use icon_button::{IconButton, IconButtonStyles};
 pub use modal::{Modal, ModalStyles, TitleChildren};
+pub use mount_transition::{EnterTransition, ExitTransition, Exiting};
+pub use multi_select::{MultiSelect, MultiSelectChanged, MultiSelectState, MultiSelectStyles};
+pub use number_input::{NumberChanged, NumberInput, NumberInputState, NumberInputStyles};
+pub use popover::{
+    DismissReason, Popover, PopoverAlign, PopoverDismissed, PopoverPlacement, PopoverSide,
+};
+pub use radio::{
+    RadioButton, RadioButtonState, RadioButtonStyles, RadioContext, RadioGroup, RadioGroupChanged,
+    RadioOrientation,
+};
 pub use scroll::content::ScrollContent;
 pub use scroll::scroll_bar::ScrollBar;
 pub use scroll::scroll_box::ScrollBox;
+pub use scroll::{ScrollAlign, ScrollMode, ScrollSnapAlign, ScrollSnapType, ScrollTo};
 pub use scroll::{ScrollContext, ScrollContextProvider /*, TaggedContext*/};
-pub use slider::{Slider, SliderChanged, SliderState, SliderStyles};
+pub use slider::{
+    Slider, SliderChanged, SliderOrientation, SliderRangeChanged, SliderState, SliderStyles,
+    SliderThumb, SliderTick,
+};
+pub use spring::{PropertySpring, ReducedMotion, Spring, StyleSprings};
 pub use tab::*;
-pub use text_box::{ApplyHighlighting, TextBox, TextBoxState, TextChanged, TextboxStyles};
+pub use table::{
+    SortDirection, Table, TableColumn, TableSelection, TableSelectionChanged, TableSortChanged,
+    TableState, TableStyles,
+};
+pub use tag_input::{TagInput, TagInputState, TagInputStyles, TagsChanged};
+pub use text_box::{
+    ApplyHighlighting, SetTextBoxValue, TabMode, TextBox, TextBoxState, TextChanged, TextboxStyles,
+};
 pub use toggle::{Toggle, ToggleChanged, ToggleState, ToggleStyles, ToggleWidgetStyles};
 pub use transition::*;
+pub use tree_view::{
+    TreeNode, TreeNodeToggled, TreeView, TreeViewChanged, TreeViewState, TreeViewStyles,
+};
+pub use undo::{EditKind, TextSnapshot, UndoStack};
+pub use virtual_list::{
+    RowHeight, ScrollToIndex, VirtualGrid, VirtualItems, VirtualList, VirtualListState,
+};
 pub use window::{WindowState, WoodpeckerWindow};
 pub use windowing_context::{WindowingContext, WindowingContextProvider};
 
@@ -79,16 +131,44 @@ impl Plugin for WoodpeckerUIWidgetPlugin {
             .register_widget::<WoodpeckerWindow>()
             .register_widget::<WindowingContextProvider>()
             .register_widget::<Dropdown>()
+            .register_widget::<Popover>()
+            .register_widget::<ComboBox>()
+            .register_widget::<MultiSelect>()
+            .register_widget::<TagInput>()
+            .register_widget::<NumberInput>()
+            .register_widget::<RadioGroup>()
+            .register_widget::<RadioButton>()
+            .register_widget::<TabBar>()
             .register_widget::<TabButton>()
             .register_widget::<TabContextProvider>()
             .register_widget::<TabContent>()
             .register_widget::<Checkbox>()
             .register_widget::<ColorPicker>()
+            .register_widget::<VirtualList>()
+            .register_widget::<VirtualGrid>()
+            .register_widget::<Table>()
+            .register_widget::<TreeView>()
+            .init_resource::<ReducedMotion>()
+            .add_observer(scroll::scroll_to)
+            .add_observer(scroll::scroll_focus_into_view)
             .add_systems(
                 Update,
                 (
                     text_box::cursor_animation_system,
+                    text_box::apply_background_highlighting,
                     transition::update_transitions,
+                    transition::update_style_transitions.after(crate::runner::system),
+                    animation::update_animations,
+                    spring::update_style_springs.after(crate::runner::system),
+                    spring::update_scroll_springs,
+                    scroll::snap_scroll_boxes.after(spring::update_scroll_springs),
+                    mount_transition::update_enter_transitions.after(crate::runner::system),
+                    mount_transition::update_exit_transitions.after(crate::runner::system),
+                    popover::update_popover_placement.after(crate::runner::system),
+                    popover::dismiss_popovers,
+                    virtual_list::measure_virtual_rows.after(crate::runner::system),
+                    tab::report_tab_changes,
+                    tab::switch_tabs_with_keyboard,
                 ),
             );
     }
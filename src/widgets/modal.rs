@@ -85,7 +85,7 @@ fn get_transition() -> Transition {
     let styles = get_styles();
     Transition {
         easing: TransitionEasing::Linear,
-        looping: false,
+        looping: TransitionLooping::None,
         playing: false,
         style_a: WoodpeckerStyle {
             opacity: 0.0,
@@ -166,7 +166,7 @@ fn render(
             transition.start();
         }
         // Make sure initial state is correct.
-        *styles = transition.update();
+        *styles = transition.update(None);
         state.previous_visibility = modal.visible;
     }
 
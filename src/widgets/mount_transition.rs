@@ -0,0 +1,223 @@
+use bevy::prelude::*;
+
+use crate::prelude::*;
+
+/// Animates a widget in the first time it is mounted.
+///
+/// The listed properties start at the values in `style` and transition to the
+/// widget's own styles.
+///
+/// ```ignore
+/// EnterTransition::new(
+///     [TransitionProperty::Opacity, TransitionProperty::Left],
+///     WoodpeckerStyle {
+///         opacity: 0.0,
+///         left: (-20.0).into(),
+///         ..Default::default()
+///     },
+///     200.0,
+///     TransitionEasing::QuadraticOut,
+/// )
+/// ```
+#[derive(Component, Debug, Reflect, Clone, PartialEq)]
+pub struct EnterTransition {
+    /// The properties that animate.
+    pub properties: Vec<TransitionProperty>,
+    /// The styles the properties start from.
+    pub style: WoodpeckerStyle,
+    /// The time in milliseconds the transition takes.
+    pub duration: f32,
+    /// The easing function that dictates the interpolation factor.
+    pub easing: TransitionEasing,
+}
+
+impl EnterTransition {
+    /// Creates a new enter transition.
+    pub fn new(
+        properties: impl IntoIterator<Item = TransitionProperty>,
+        style: WoodpeckerStyle,
+        duration: f32,
+        easing: TransitionEasing,
+    ) -> Self {
+        Self {
+            properties: properties.into_iter().collect(),
+            style,
+            duration,
+            easing,
+        }
+    }
+}
+
+/// Animates a widget out when its parent stops rendering it.
+///
+/// The widget is kept alive, but can't be picked, until the listed properties have
+/// transitioned to the values in `style`, then it is despawned. Give list items
+/// a key with [`WidgetChildren::with_key`] so reordering isn't seen as a removal.
+///
+/// ```ignore
+/// ExitTransition::new(
+///     [TransitionProperty::Opacity],
+///     WoodpeckerStyle {
+///         opacity: 0.0,
+///         ..Default::default()
+///     },
+///     200.0,
+///     TransitionEasing::QuadraticIn,
+/// )
+/// ```
+#[derive(Component, Debug, Reflect, Clone, PartialEq)]
+pub struct ExitTransition {
+    /// The properties that animate.
+    pub properties: Vec<TransitionProperty>,
+    /// The styles the properties end at.
+    pub style: WoodpeckerStyle,
+    /// The time in milliseconds the transition takes.
+    pub duration: f32,
+    /// The easing function that dictates the interpolation factor.
+    pub easing: TransitionEasing,
+}
+
+impl ExitTransition {
+    /// Creates a new exit transition.
+    pub fn new(
+        properties: impl IntoIterator<Item = TransitionProperty>,
+        style: WoodpeckerStyle,
+        duration: f32,
+        easing: TransitionEasing,
+    ) -> Self {
+        Self {
+            properties: properties.into_iter().collect(),
+            style,
+            duration,
+            easing,
+        }
+    }
+}
+
+/// Added to a widget that has been removed from its parent and is playing its [`ExitTransition`].
+#[derive(Component, Debug, Reflect, Clone, Copy, PartialEq)]
+pub struct Exiting {
+    from: WoodpeckerStyle,
+    elapsed: f32,
+    finished: bool,
+}
+
+impl Exiting {
+    pub(crate) fn new(from: WoodpeckerStyle) -> Self {
+        Self {
+            from,
+            elapsed: 0.0,
+            finished: false,
+        }
+    }
+
+    /// Has the exit transition finished?
+    ///
+    /// Finished widgets are despawned the next time the widget tree updates.
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+}
+
+/// Tracks the progress of an [`EnterTransition`].
+///
+/// This lives outside of [`EnterTransition`] because parents re-insert their
+/// children's components every time they render.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub(crate) struct EnterState {
+    elapsed: f32,
+    target: WoodpeckerStyle,
+    displayed: WoodpeckerStyle,
+    finished: bool,
+}
+
+/// The progress of a transition in the range 0..=1.
+fn progress(elapsed: f32, duration: f32, reduced_motion: bool) -> f32 {
+    if reduced_motion || duration <= 0.0 {
+        1.0
+    } else {
+        (elapsed / duration).clamp(0.0, 1.0)
+    }
+}
+
+// IMPORTANT: When modifying widget entities we need to verify we aren't modifying previous widget values.
+pub(crate) fn update_enter_transitions(
+    mut commands: Commands,
+    time: Res<Time>,
+    reduced_motion: Res<ReducedMotion>,
+    mut query: Query<
+        (
+            Entity,
+            &EnterTransition,
+            &mut WoodpeckerStyle,
+            Option<&mut EnterState>,
+        ),
+        (Without<PreviousWidget>, Without<Exiting>),
+    >,
+) {
+    let delta = time.delta_secs() * 1000.0;
+    for (entity, enter, mut style, state) in query.iter_mut() {
+        let mut state = match state {
+            Some(state) if state.finished => continue,
+            Some(state) => state,
+            None => {
+                // Just mounted, start from the enter styles right away so nothing flashes.
+                let mut from = *style;
+                for property in enter.properties.iter() {
+                    property.copy(&enter.style, &mut from);
+                }
+                commands.entity(entity).insert(EnterState {
+                    elapsed: 0.0,
+                    target: *style,
+                    displayed: from,
+                    finished: false,
+                });
+                style.set_if_neq(from);
+                continue;
+            }
+        };
+
+        // The widget wrote new styles.
+        if *style != state.displayed {
+            state.target = *style;
+        }
+        state.elapsed += delta;
+        let x = progress(state.elapsed, enter.duration, reduced_motion.0);
+        let mut from = state.target;
+        for property in enter.properties.iter() {
+            property.copy(&enter.style, &mut from);
+        }
+        let displayed = if x >= 1.0 {
+            state.finished = true;
+            state.target
+        } else {
+            from.lerp(&state.target, enter.easing.ease(x))
+        };
+        state.displayed = displayed;
+        style.set_if_neq(displayed);
+    }
+}
+
+pub(crate) fn update_exit_transitions(
+    time: Res<Time>,
+    reduced_motion: Res<ReducedMotion>,
+    mut query: Query<
+        (&ExitTransition, &mut Exiting, &mut WoodpeckerStyle),
+        Without<PreviousWidget>,
+    >,
+) {
+    let delta = time.delta_secs() * 1000.0;
+    for (exit, mut exiting, mut style) in query.iter_mut() {
+        if exiting.finished {
+            continue;
+        }
+        exiting.elapsed += delta;
+        let x = progress(exiting.elapsed, exit.duration, reduced_motion.0);
+        let mut to = exiting.from;
+        for property in exit.properties.iter() {
+            property.copy(&exit.style, &mut to);
+        }
+        exiting.finished = x >= 1.0;
+        style.set_if_neq(exiting.from.lerp(&to, exit.easing.ease(x)));
+    }
+}
@@ -0,0 +1,406 @@
+use crate::prelude::*;
+use bevy::prelude::*;
+
+use super::colors;
+
+/// A multi-select change event.
+#[derive(Debug, Clone, Reflect)]
+pub struct MultiSelectChanged {
+    /// The indices of the selected items, in item order.
+    pub selected: Vec<usize>,
+    /// The values of the selected items, in item order.
+    pub values: Vec<String>,
+}
+
+/// Multi-select styles
+#[derive(Component, Clone, PartialEq, Reflect)]
+pub struct MultiSelectStyles {
+    /// List background styles
+    pub background: WoodpeckerStyle,
+    /// Item styles
+    pub item: WoodpeckerStyle,
+    /// Styles of the item highlighted by the keyboard or pointer
+    pub item_highlighted: WoodpeckerStyle,
+    /// The box in front of each item, uses the checked styles for selected items
+    /// and the hovered styles for the highlighted item.
+    pub check_box: CheckboxStyles,
+    /// Checkmark styles
+    pub check: WoodpeckerStyle,
+    /// Text styles
+    pub text: WoodpeckerStyle,
+    /// Text styles of disabled items
+    pub disabled_text: WoodpeckerStyle,
+}
+
+impl Default for MultiSelectStyles {
+    fn default() -> Self {
+        let item = WoodpeckerStyle {
+            width: Units::Percentage(100.0),
+            height: 32.0.into(),
+            padding: Edge::all(0.0).left(6.0).right(6.0),
+            align_items: Some(WidgetAlignItems::Center),
+            background_color: colors::BACKGROUND,
+            ..Default::default()
+        };
+        let check_box = WoodpeckerStyle {
+            background_color: colors::BACKGROUND_LIGHT,
+            width: 20.0.into(),
+            height: 20.0.into(),
+            margin: Edge::all(0.0).right(8.0),
+            border_radius: Corner::all(2.0),
+            ..Default::default()
+        };
+        let text = WoodpeckerStyle {
+            color: Color::WHITE,
+            font_size: 16.0,
+            text_wrap: TextWrap::None,
+            ..Default::default()
+        };
+        Self {
+            background: WoodpeckerStyle {
+                width: Units::Percentage(100.0),
+                flex_direction: WidgetFlexDirection::Column,
+                background_color: colors::BACKGROUND,
+                ..Default::default()
+            },
+            item,
+            item_highlighted: WoodpeckerStyle {
+                background_color: colors::BACKGROUND_LIGHT,
+                ..item
+            },
+            check_box: CheckboxStyles {
+                normal: check_box,
+                hovered: WoodpeckerStyle {
+                    background_color: colors::BACKGROUND_MID,
+                    ..check_box
+                },
+                checked: WoodpeckerStyle {
+                    background_color: colors::PRIMARY_LIGHT,
+                    ..check_box
+                },
+                hovered_checked: WoodpeckerStyle {
+                    background_color: colors::PRIMARY,
+                    ..check_box
+                },
+            },
+            check: WoodpeckerStyle {
+                color: Color::WHITE,
+                width: Units::Percentage(100.0),
+                height: Units::Percentage(100.0),
+                ..Default::default()
+            },
+            text,
+            disabled_text: WoodpeckerStyle {
+                color: colors::BACKGROUND_LIGHT,
+                ..text
+            },
+        }
+    }
+}
+
+/// Multi-select state
+#[derive(Component, Debug, Default, Clone, PartialEq, Reflect)]
+pub struct MultiSelectState {
+    /// The indices of the selected items, in item order.
+    pub selected: Vec<usize>,
+    /// The item highlighted by the keyboard or pointer.
+    pub highlighted: Option<usize>,
+}
+
+impl MultiSelectState {
+    /// Is the item selected?
+    pub fn is_selected(&self, index: usize) -> bool {
+        self.selected.binary_search(&index).is_ok()
+    }
+
+    /// Selects or deselects an item, keeping the selection in item order.
+    fn toggle(&mut self, index: usize) {
+        match self.selected.binary_search(&index) {
+            Ok(position) => {
+                self.selected.remove(position);
+            }
+            Err(position) => self.selected.insert(position, index),
+        }
+    }
+}
+
+/// A list where any number of items can be selected, each item has a checkmark.
+///
+/// While focused Up/Down/Home/End move the highlight and Space or Enter toggles the
+/// highlighted item.
+#[derive(Widget, Component, Clone, PartialEq, Reflect, Default)]
+#[auto_update(render)]
+#[props(MultiSelect, MultiSelectStyles)]
+#[state(MultiSelectState)]
+#[require(WoodpeckerStyle, MultiSelectStyles, WidgetRender = WidgetRender::Quad, WidgetChildren, Pickable, Focusable)]
+pub struct MultiSelect {
+    /// The items, uses the same items as a [`Dropdown`]
+    pub items: Vec<DropdownItem>,
+    /// The initially selected items
+    pub initial_selection: Vec<usize>,
+}
+
+impl MultiSelect {
+    fn toggle(
+        &self,
+        commands: &mut Commands,
+        multi_select_entity: Entity,
+        state: &mut MultiSelectState,
+        index: usize,
+    ) {
+        if self.items.get(index).is_none_or(|item| item.disabled) {
+            return;
+        }
+        state.toggle(index);
+        commands.trigger(Change {
+            target: multi_select_entity,
+            data: MultiSelectChanged {
+                selected: state.selected.clone(),
+                values: state
+                    .selected
+                    .iter()
+                    .map(|index| self.items[*index].value.clone())
+                    .collect(),
+            },
+        });
+    }
+}
+
+/// The next enabled item after `from` in a direction, staying on `from` at the ends.
+fn step_enabled(items: &[DropdownItem], from: Option<usize>, forward: bool) -> Option<usize> {
+    let enabled = |i: &usize| !items[*i].disabled;
+    let next = match (from, forward) {
+        (None, true) => (0..items.len()).find(enabled),
+        (None, false) => (0..items.len()).rev().find(enabled),
+        (Some(from), true) => (from + 1..items.len()).find(enabled),
+        (Some(from), false) => (0..from.min(items.len())).rev().find(enabled),
+    };
+    next.or(from)
+}
+
+fn render(
+    mut commands: Commands,
+    mut hooks: ResMut<HookHelper>,
+    current_widget: Res<CurrentWidget>,
+    asset_server: Res<AssetServer>,
+    mut query: Query<(
+        &MultiSelect,
+        &MultiSelectStyles,
+        &mut WoodpeckerStyle,
+        &mut WidgetChildren,
+    )>,
+    state_query: Query<&MultiSelectState>,
+) {
+    let Ok((multi_select, multi_select_styles, mut styles, mut children)) =
+        query.get_mut(**current_widget)
+    else {
+        return;
+    };
+
+    let mut initial_selection = multi_select
+        .initial_selection
+        .iter()
+        .copied()
+        .filter(|index| *index < multi_select.items.len())
+        .collect::<Vec<_>>();
+    initial_selection.sort_unstable();
+    initial_selection.dedup();
+    let state_entity = hooks.use_state(
+        &mut commands,
+        *current_widget,
+        MultiSelectState {
+            selected: initial_selection,
+            highlighted: None,
+        },
+    );
+
+    let Ok(state) = state_query.get(state_entity) else {
+        return;
+    };
+
+    *styles = multi_select_styles.background;
+
+    let multi_select_entity = **current_widget;
+    *children = WidgetChildren::default()
+        .with_observe(
+            *current_widget,
+            move |trigger: On<WidgetKeyboardButtonEvent>,
+                  mut commands: Commands,
+                  mut state_query: Query<&mut MultiSelectState>,
+                  multi_select_query: Query<&MultiSelect>| {
+                let Ok(mut state) = state_query.get_mut(state_entity) else {
+                    return;
+                };
+                let Ok(multi_select) = multi_select_query.get(multi_select_entity) else {
+                    return;
+                };
+                let items = &multi_select.items;
+                match trigger.code {
+                    KeyCode::ArrowDown => {
+                        state.highlighted = step_enabled(items, state.highlighted, true)
+                    }
+                    KeyCode::ArrowUp => {
+                        state.highlighted = step_enabled(items, state.highlighted, false)
+                    }
+                    KeyCode::Home => state.highlighted = step_enabled(items, None, true),
+                    KeyCode::End => state.highlighted = step_enabled(items, None, false),
+                    KeyCode::Space | KeyCode::Enter => {
+                        if let Some(index) = state.highlighted {
+                            multi_select.toggle(
+                                &mut commands,
+                                multi_select_entity,
+                                &mut state,
+                                index,
+                            );
+                        }
+                    }
+                    _ => {}
+                }
+            },
+        )
+        .with_observe(
+            *current_widget,
+            move |_trigger: On<WidgetBlur>, mut state_query: Query<&mut MultiSelectState>| {
+                if let Ok(mut state) = state_query.get_mut(state_entity) {
+                    state.highlighted = None;
+                }
+            },
+        );
+
+    for (i, item) in multi_select.items.iter().enumerate() {
+        let check_state = CheckboxState {
+            is_hovering: state.highlighted == Some(i),
+            is_checked: state.is_selected(i),
+        };
+
+        let mut check_box_children = WidgetChildren::default();
+        if check_state.is_checked {
+            check_box_children.add::<Element>((
+                Element,
+                multi_select_styles.check,
+                WidgetRender::Svg {
+                    handle: asset_server
+                        .load("embedded://woodpecker_ui/embedded_assets/icons/checkmark.svg"),
+                    color: Some(multi_select_styles.check.color),
+                },
+            ));
+        }
+
+        let mut item_children = WidgetChildren::default();
+        item_children.add::<Element>((
+            Element,
+            multi_select_styles.check_box.get_style(&check_state),
+            WidgetRender::Quad,
+            check_box_children,
+        ));
+        if let Some(icon) = item.icon.clone() {
+            let icon_styles = WoodpeckerStyle {
+                width: multi_select_styles.text.font_size.into(),
+                height: multi_select_styles.text.font_size.into(),
+                margin: Edge::all(0.0).right(6.0),
+                ..multi_select_styles.text
+            };
+            item_children.add::<Element>((
+                Element,
+                icon_styles,
+                WidgetRender::Svg {
+                    handle: icon,
+                    color: Some(icon_styles.color),
+                },
+            ));
+        }
+        item_children.add::<Element>((
+            Element,
+            if item.disabled {
+                multi_select_styles.disabled_text
+            } else {
+                multi_select_styles.text
+            },
+            WidgetRender::Text {
+                content: item.label.clone(),
+            },
+        ));
+
+        children
+            .add::<Element>((
+                Element,
+                if check_state.is_hovering {
+                    multi_select_styles.item_highlighted
+                } else {
+                    multi_select_styles.item
+                },
+                item_children,
+                WidgetRender::Quad,
+                Pickable::default(),
+            ))
+            .observe(
+                *current_widget,
+                move |_trigger: On<Pointer<Click>>,
+                      mut commands: Commands,
+                      mut state_query: Query<&mut MultiSelectState>,
+                      multi_select_query: Query<&MultiSelect>| {
+                    let Ok(mut state) = state_query.get_mut(state_entity) else {
+                        return;
+                    };
+                    let Ok(multi_select) = multi_select_query.get(multi_select_entity) else {
+                        return;
+                    };
+                    multi_select.toggle(&mut commands, multi_select_entity, &mut state, i);
+                },
+            )
+            .observe(
+                *current_widget,
+                move |_trigger: On<Pointer<Over>>,
+                      mut state_query: Query<&mut MultiSelectState>,
+                      multi_select_query: Query<&MultiSelect>| {
+                    let Ok(mut state) = state_query.get_mut(state_entity) else {
+                        return;
+                    };
+                    let Ok(multi_select) = multi_select_query.get(multi_select_entity) else {
+                        return;
+                    };
+                    if multi_select.items.get(i).is_some_and(|item| !item.disabled) {
+                        state.highlighted = Some(i);
+                    }
+                },
+            )
+            .observe(
+                *current_widget,
+                move |_trigger: On<Pointer<Out>>, mut state_query: Query<&mut MultiSelectState>| {
+                    let Ok(mut state) = state_query.get_mut(state_entity) else {
+                        return;
+                    };
+                    if state.highlighted == Some(i) {
+                        state.highlighted = None;
+                    }
+                },
+            );
+    }
+
+    children.apply(current_widget.as_parent());
+}
+
+#[test]
+fn test_multi_select() {
+    let mut state = MultiSelectState::default();
+    state.toggle(3);
+    state.toggle(1);
+    state.toggle(2);
+    assert_eq!(state.selected, vec![1, 2, 3]);
+    state.toggle(2);
+    assert_eq!(state.selected, vec![1, 3]);
+    assert!(state.is_selected(3));
+    assert!(!state.is_selected(2));
+
+    let items = vec![
+        DropdownItem::from("Fire").disabled(),
+        DropdownItem::from("Water"),
+        DropdownItem::from("Earth").disabled(),
+        DropdownItem::from("Air"),
+    ];
+    assert_eq!(step_enabled(&items, None, true), Some(1));
+    assert_eq!(step_enabled(&items, Some(1), true), Some(3));
+    // Stays put at the ends.
+    assert_eq!(step_enabled(&items, Some(3), true), Some(3));
+    assert_eq!(step_enabled(&items, Some(1), false), Some(1));
+}
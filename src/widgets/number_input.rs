@@ -0,0 +1,570 @@
+use crate::{picking_backend::MouseWheelScroll, prelude::*};
+use bevy::prelude::*;
+
+use super::{colors, text_box::sync_text_box};
+
+/// The number of pixels the pointer has to move while scrubbing to change the value by one step.
+const SCRUB_PIXELS_PER_STEP: f32 = 4.0;
+
+/// A number input change event.
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub struct NumberChanged {
+    /// The new value
+    pub value: f32,
+}
+
+/// Number input styles
+#[derive(Component, Clone, PartialEq, Reflect)]
+pub struct NumberInputStyles {
+    /// Styles of the row holding the label, textbox and buttons
+    pub background: WoodpeckerStyle,
+    /// Label styles, dragging the label scrubs the value
+    pub label: WoodpeckerStyle,
+    /// The styles of the wrapper around the textbox
+    pub input: WoodpeckerStyle,
+    /// Increment and decrement button styles
+    pub button: ButtonStyles,
+    /// Increment and decrement icon styles
+    pub icon: WoodpeckerStyle,
+}
+
+impl Default for NumberInputStyles {
+    fn default() -> Self {
+        let button = WoodpeckerStyle {
+            width: 20.0.into(),
+            height: 15.0.into(),
+            justify_content: Some(WidgetAlignContent::Center),
+            align_items: Some(WidgetAlignItems::Center),
+            background_color: colors::BACKGROUND_MID,
+            ..Default::default()
+        };
+        Self {
+            background: WoodpeckerStyle {
+                width: Units::Percentage(100.0),
+                align_items: Some(WidgetAlignItems::Center),
+                ..Default::default()
+            },
+            label: WoodpeckerStyle {
+                color: Color::WHITE,
+                font_size: 16.0,
+                margin: Edge::all(0.0).right(8.0),
+                text_wrap: TextWrap::None,
+                ..Default::default()
+            },
+            input: WoodpeckerStyle {
+                flex_grow: 1.0,
+                ..Default::default()
+            },
+            button: ButtonStyles {
+                normal: button,
+                hovered: WoodpeckerStyle {
+                    background_color: colors::BACKGROUND_LIGHT,
+                    ..button
+                },
+            },
+            icon: WoodpeckerStyle {
+                color: Color::WHITE,
+                width: 12.0.into(),
+                height: 12.0.into(),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Number input state
+#[derive(Component, Debug, Default, Clone, PartialEq, Reflect)]
+pub struct NumberInputState {
+    /// The current value
+    pub value: f32,
+    /// The text in the textbox, committed when Enter is pressed or the textbox is blurred.
+    pub text: String,
+    /// The value when scrubbing started
+    scrub_start: Option<f32>,
+    /// The text last pushed into the textbox, typing doesn't change it so the textbox isn't reset.
+    synced_text: String,
+    /// The textbox, once the user typed in it.
+    text_box: Option<Entity>,
+}
+
+/// A textbox for numbers with increment and decrement buttons.
+///
+/// Typed text is evaluated as an expression(ex: `2 * (3 + 4)`) when Enter is pressed or the
+/// textbox loses focus. Dragging the label left or right, scrolling the mouse wheel over the
+/// input and the Up/Down arrow keys change the value by the step.
+#[derive(Widget, Component, Clone, PartialEq, Reflect)]
+#[auto_update(render)]
+#[props(NumberInput, NumberInputStyles)]
+#[state(NumberInputState)]
+#[require(WoodpeckerStyle, NumberInputStyles, WidgetRender = WidgetRender::Quad, WidgetChildren, Pickable)]
+pub struct NumberInput {
+    /// A label shown before the textbox, drag it to scrub the value
+    pub label: String,
+    /// The initial value
+    pub initial_value: f32,
+    /// The minimum value
+    pub min: Option<f32>,
+    /// The maximum value
+    pub max: Option<f32>,
+    /// The amount the buttons, arrow keys, mouse wheel and scrubbing change the value by
+    pub step: f32,
+    /// The number of decimal places values are rounded to
+    pub precision: usize,
+}
+
+impl Default for NumberInput {
+    fn default() -> Self {
+        Self {
+            label: String::new(),
+            initial_value: 0.0,
+            min: None,
+            max: None,
+            step: 1.0,
+            precision: 2,
+        }
+    }
+}
+
+impl NumberInput {
+    /// Clamps a value between the min and max and rounds it to the precision.
+    pub fn clamp(&self, value: f32) -> f32 {
+        let factor = 10f32.powi(self.precision as i32);
+        let mut value = (value * factor).round() / factor;
+        if let Some(min) = self.min {
+            value = value.max(min);
+        }
+        if let Some(max) = self.max {
+            value = value.min(max);
+        }
+        value
+    }
+
+    /// Formats a value with the precision.
+    pub fn format(&self, value: f32) -> String {
+        format!("{:.*}", self.precision, value)
+    }
+
+    fn set_value(
+        &self,
+        commands: &mut Commands,
+        number_input_entity: Entity,
+        state: &mut NumberInputState,
+        value: f32,
+    ) {
+        let value = self.clamp(value);
+        state.text = self.format(value);
+        sync_text_box(
+            commands,
+            &mut state.synced_text,
+            &state.text,
+            state.text_box,
+        );
+        if state.value == value {
+            return;
+        }
+        state.value = value;
+        commands.trigger(Change {
+            target: number_input_entity,
+            data: NumberChanged { value },
+        });
+    }
+
+    /// Evaluates the text, keeping the current value if it isn't a valid expression.
+    fn commit_text(
+        &self,
+        commands: &mut Commands,
+        number_input_entity: Entity,
+        state: &mut NumberInputState,
+    ) {
+        let value = evaluate(&state.text)
+            .map(|value| value as f32)
+            .unwrap_or(state.value);
+        self.set_value(commands, number_input_entity, state, value);
+    }
+
+    fn step_by(
+        &self,
+        commands: &mut Commands,
+        number_input_entity: Entity,
+        state: &mut NumberInputState,
+        steps: f32,
+    ) {
+        let value = state.value + self.step * steps;
+        self.set_value(commands, number_input_entity, state, value);
+    }
+}
+
+/// Evaluates an arithmetic expression with `+ - * / % ^`, parentheses and unary minus.
+///
+/// Returns `None` if the expression is invalid or the result isn't a finite number.
+pub(crate) fn evaluate(expression: &str) -> Option<f64> {
+    let tokens = expression
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect::<Vec<_>>();
+    let mut parser = ExpressionParser {
+        tokens,
+        position: 0,
+        depth: 0,
+    };
+    let value = parser.expression()?;
+    (parser.position == parser.tokens.len() && value.is_finite()).then_some(value)
+}
+
+struct ExpressionParser {
+    tokens: Vec<char>,
+    position: usize,
+    depth: usize,
+}
+
+impl ExpressionParser {
+    /// Deeper nesting is rejected instead of overflowing the stack.
+    const MAX_DEPTH: usize = 64;
+
+    fn nested(&mut self, parse: fn(&mut Self) -> Option<f64>) -> Option<f64> {
+        if self.depth >= Self::MAX_DEPTH {
+            return None;
+        }
+        self.depth += 1;
+        let value = parse(self);
+        self.depth -= 1;
+        value
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.tokens.get(self.position).copied()
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.position += 1;
+            return true;
+        }
+        false
+    }
+
+    fn expression(&mut self) -> Option<f64> {
+        let mut value = self.term()?;
+        loop {
+            if self.eat('+') {
+                value += self.term()?;
+            } else if self.eat('-') {
+                value -= self.term()?;
+            } else {
+                return Some(value);
+            }
+        }
+    }
+
+    fn term(&mut self) -> Option<f64> {
+        let mut value = self.unary()?;
+        loop {
+            if self.eat('*') {
+                value *= self.unary()?;
+            } else if self.eat('/') {
+                value /= self.unary()?;
+            } else if self.eat('%') {
+                value %= self.unary()?;
+            } else {
+                return Some(value);
+            }
+        }
+    }
+
+    fn unary(&mut self) -> Option<f64> {
+        if self.eat('-') {
+            return self.nested(Self::unary).map(|value| -value);
+        }
+        if self.eat('+') {
+            return self.nested(Self::unary);
+        }
+        self.power()
+    }
+
+    fn power(&mut self) -> Option<f64> {
+        let base = self.atom()?;
+        // Right associative and binds tighter than unary minus on the left: -2^2 = -4.
+        if self.eat('^') {
+            return Some(base.powf(self.nested(Self::unary)?));
+        }
+        Some(base)
+    }
+
+    fn atom(&mut self) -> Option<f64> {
+        if self.eat('(') {
+            let value = self.nested(Self::expression)?;
+            return self.eat(')').then_some(value);
+        }
+        let start = self.position;
+        while self.peek().is_some_and(|c| c.is_ascii_digit() || c == '.') {
+            self.position += 1;
+        }
+        self.tokens[start..self.position]
+            .iter()
+            .collect::<String>()
+            .parse()
+            .ok()
+    }
+}
+
+fn render(
+    mut commands: Commands,
+    mut hooks: ResMut<HookHelper>,
+    current_widget: Res<CurrentWidget>,
+    asset_server: Res<AssetServer>,
+    mut query: Query<(
+        &NumberInput,
+        &NumberInputStyles,
+        &mut WoodpeckerStyle,
+        &mut WidgetChildren,
+    )>,
+    state_query: Query<&NumberInputState>,
+) {
+    let Ok((number_input, number_input_styles, mut styles, mut children)) =
+        query.get_mut(**current_widget)
+    else {
+        return;
+    };
+
+    let initial_value = number_input.clamp(number_input.initial_value);
+    let state_entity = hooks.use_state(
+        &mut commands,
+        *current_widget,
+        NumberInputState {
+            value: initial_value,
+            text: number_input.format(initial_value),
+            synced_text: number_input.format(initial_value),
+            ..Default::default()
+        },
+    );
+
+    let Ok(state) = state_query.get(state_entity) else {
+        return;
+    };
+
+    *styles = number_input_styles.background;
+
+    let number_input_entity = **current_widget;
+    *children = WidgetChildren::default().with_observe(
+        *current_widget,
+        move |mut trigger: On<Pointer<MouseWheelScroll>>,
+              mut commands: Commands,
+              mut state_query: Query<&mut NumberInputState>,
+              number_input_query: Query<&NumberInput>| {
+            trigger.propagate(false);
+            let Ok(mut state) = state_query.get_mut(state_entity) else {
+                return;
+            };
+            let Ok(number_input) = number_input_query.get(number_input_entity) else {
+                return;
+            };
+            if trigger.scroll.y == 0.0 {
+                return;
+            }
+            let steps = trigger.scroll.y.signum();
+            number_input.step_by(&mut commands, number_input_entity, &mut state, steps);
+        },
+    );
+
+    if !number_input.label.is_empty() {
+        children
+            .add::<Element>((
+                Element,
+                number_input_styles.label,
+                WidgetRender::Text {
+                    content: number_input.label.clone(),
+                },
+                Pickable::default(),
+            ))
+            .observe(
+                *current_widget,
+                move |_trigger: On<Pointer<DragStart>>,
+                      mut state_query: Query<&mut NumberInputState>| {
+                    if let Ok(mut state) = state_query.get_mut(state_entity) {
+                        state.scrub_start = Some(state.value);
+                    }
+                },
+            )
+            .observe(
+                *current_widget,
+                move |trigger: On<Pointer<Drag>>,
+                      mut commands: Commands,
+                      mut state_query: Query<&mut NumberInputState>,
+                      number_input_query: Query<&NumberInput>| {
+                    let Ok(mut state) = state_query.get_mut(state_entity) else {
+                        return;
+                    };
+                    let Ok(number_input) = number_input_query.get(number_input_entity) else {
+                        return;
+                    };
+                    let Some(scrub_start) = state.scrub_start else {
+                        return;
+                    };
+                    let steps = (trigger.distance.x / SCRUB_PIXELS_PER_STEP).round();
+                    let value = scrub_start + steps * number_input.step;
+                    number_input.set_value(&mut commands, number_input_entity, &mut state, value);
+                },
+            )
+            .observe(
+                *current_widget,
+                move |_trigger: On<Pointer<DragEnd>>,
+                      mut state_query: Query<&mut NumberInputState>| {
+                    if let Ok(mut state) = state_query.get_mut(state_entity) {
+                        state.scrub_start = None;
+                    }
+                },
+            );
+    }
+
+    children.add::<Element>((
+        Element,
+        number_input_styles.input,
+        WidgetChildren::default()
+            .with_child::<TextBox>(TextBox {
+                initial_value: state.synced_text.clone(),
+                ..Default::default()
+            })
+            .with_observe(
+                *current_widget,
+                move |mut trigger: On<Change<TextChanged>>,
+                      mut state_query: Query<&mut NumberInputState>| {
+                    // Consumers listen for NumberChanged instead.
+                    trigger.propagate(false);
+                    if let Ok(mut state) = state_query.get_mut(state_entity) {
+                        state.text = trigger.data.value.clone();
+                        state.text_box = Some(trigger.target);
+                    }
+                },
+            )
+            .with_observe(
+                *current_widget,
+                move |_trigger: On<WidgetBlur>,
+                      mut commands: Commands,
+                      mut state_query: Query<&mut NumberInputState>,
+                      number_input_query: Query<&NumberInput>| {
+                    let Ok(mut state) = state_query.get_mut(state_entity) else {
+                        return;
+                    };
+                    let Ok(number_input) = number_input_query.get(number_input_entity) else {
+                        return;
+                    };
+                    number_input.commit_text(&mut commands, number_input_entity, &mut state);
+                },
+            )
+            .with_observe(
+                *current_widget,
+                move |trigger: On<WidgetKeyboardButtonEvent>,
+                      mut commands: Commands,
+                      mut state_query: Query<&mut NumberInputState>,
+                      number_input_query: Query<&NumberInput>| {
+                    let Ok(mut state) = state_query.get_mut(state_entity) else {
+                        return;
+                    };
+                    let Ok(number_input) = number_input_query.get(number_input_entity) else {
+                        return;
+                    };
+                    match trigger.code {
+                        KeyCode::Enter => {
+                            number_input.commit_text(
+                                &mut commands,
+                                number_input_entity,
+                                &mut state,
+                            );
+                        }
+                        KeyCode::ArrowUp => {
+                            number_input.step_by(
+                                &mut commands,
+                                number_input_entity,
+                                &mut state,
+                                1.0,
+                            );
+                        }
+                        KeyCode::ArrowDown => {
+                            number_input.step_by(
+                                &mut commands,
+                                number_input_entity,
+                                &mut state,
+                                -1.0,
+                            );
+                        }
+                        _ => {}
+                    }
+                },
+            ),
+    ));
+
+    let mut buttons = WidgetChildren::default();
+    for (icon, steps) in [("arrow-up.svg", 1.0), ("arrow-down.svg", -1.0)] {
+        buttons
+            .add::<WButton>((
+                WButton,
+                number_input_styles.button,
+                WidgetChildren::default().with_child::<Element>((
+                    Element,
+                    number_input_styles.icon,
+                    WidgetRender::Svg {
+                        handle: asset_server.load(format!(
+                            "embedded://woodpecker_ui/embedded_assets/icons/{icon}"
+                        )),
+                        color: Some(number_input_styles.icon.color),
+                    },
+                )),
+            ))
+            .observe(
+                *current_widget,
+                move |mut trigger: On<Pointer<Click>>,
+                      mut commands: Commands,
+                      mut state_query: Query<&mut NumberInputState>,
+                      number_input_query: Query<&NumberInput>| {
+                    trigger.propagate(false);
+                    let Ok(mut state) = state_query.get_mut(state_entity) else {
+                        return;
+                    };
+                    let Ok(number_input) = number_input_query.get(number_input_entity) else {
+                        return;
+                    };
+                    number_input.step_by(&mut commands, number_input_entity, &mut state, steps);
+                },
+            );
+    }
+    children.add::<Element>((
+        Element,
+        WoodpeckerStyle {
+            flex_direction: WidgetFlexDirection::Column,
+            ..Default::default()
+        },
+        buttons,
+    ));
+
+    children.apply(current_widget.as_parent());
+}
+
+#[test]
+fn test_number_input() {
+    assert_eq!(evaluate("1 + 2 * 3"), Some(7.0));
+    assert_eq!(evaluate("(1 + 2) * 3"), Some(9.0));
+    assert_eq!(evaluate("-2^2"), Some(-4.0));
+    assert_eq!(evaluate("2^3^2"), Some(512.0));
+    assert_eq!(evaluate("10 % 4 - -1.5"), Some(3.5));
+    assert_eq!(evaluate("1 / 0"), None);
+    assert_eq!(evaluate("2 +"), None);
+    assert_eq!(evaluate("(2"), None);
+    assert_eq!(evaluate("abc"), None);
+    assert_eq!(
+        evaluate(&format!("{}1{}", "(".repeat(8), ")".repeat(8))),
+        Some(1.0)
+    );
+    assert_eq!(evaluate(&"(".repeat(100_000)), None);
+    assert_eq!(evaluate(&format!("{}1", "-".repeat(100_000))), None);
+
+    let number_input = NumberInput {
+        min: Some(0.0),
+        max: Some(10.0),
+        precision: 1,
+        ..Default::default()
+    };
+    assert_eq!(number_input.clamp(3.14159), 3.1);
+    assert_eq!(number_input.clamp(-3.0), 0.0);
+    assert_eq!(number_input.clamp(12.0), 10.0);
+    assert_eq!(number_input.format(3.0), "3.0");
+}
@@ -0,0 +1,425 @@
+use bevy::{picking::hover::HoverMap, prelude::*};
+
+use crate::prelude::*;
+
+/// The side of the anchor a [`Popover`] is placed on.
+#[derive(Debug, Default, Reflect, Clone, Copy, PartialEq, Eq)]
+pub enum PopoverSide {
+    /// Above the anchor
+    Top,
+    /// Below the anchor
+    #[default]
+    Bottom,
+    /// Left of the anchor
+    Left,
+    /// Right of the anchor
+    Right,
+}
+
+impl PopoverSide {
+    /// The side across from this one.
+    pub fn opposite(&self) -> Self {
+        match self {
+            Self::Top => Self::Bottom,
+            Self::Bottom => Self::Top,
+            Self::Left => Self::Right,
+            Self::Right => Self::Left,
+        }
+    }
+
+    fn is_vertical(&self) -> bool {
+        matches!(self, Self::Top | Self::Bottom)
+    }
+}
+
+/// How a [`Popover`] lines up with its anchor along the side it is placed on.
+#[derive(Debug, Default, Reflect, Clone, Copy, PartialEq, Eq)]
+pub enum PopoverAlign {
+    /// Lines up the left or top edges
+    #[default]
+    Start,
+    /// Centers the popover on the anchor
+    Center,
+    /// Lines up the right or bottom edges
+    End,
+}
+
+/// Why a [`Popover`] asked to be closed.
+#[derive(Debug, Reflect, Clone, Copy, PartialEq, Eq)]
+pub enum DismissReason {
+    /// The user pressed outside of the popover and its anchor.
+    OutsideClick,
+    /// The user pressed escape.
+    Escape,
+}
+
+/// Triggered on a [`Popover`] when the user dismisses it.
+///
+/// The popover doesn't close itself, whoever owns `open` should set it to false.
+#[derive(Clone, PartialEq, Debug, Reflect, EntityEvent)]
+pub struct PopoverDismissed {
+    /// The target of this event
+    #[event_target]
+    pub target: Entity,
+    /// What dismissed the popover.
+    pub reason: DismissReason,
+}
+
+/// Where a [`Popover`] ended up after flipping and shifting.
+#[derive(Component, Debug, Default, Reflect, Clone, Copy, PartialEq)]
+pub struct PopoverPlacement {
+    /// The side of the anchor the popover is on.
+    pub side: PopoverSide,
+    /// The top-left corner of the popover.
+    pub position: Vec2,
+}
+
+/// A floating container rendered above the rest of the widget tree.
+///
+/// Popovers are taken out of their parent's layout and placed next to an anchor widget,
+/// so they aren't clipped by a [`Clip`] or [`ScrollBox`] they are declared in. If the
+/// popover doesn't fit on the preferred side it is flipped to the other side and shifted
+/// to stay inside the viewport.
+///
+/// Useful for dropdowns, tooltips, context menus and autocomplete lists.
+#[derive(Widget, Component, Reflect, PartialEq, Clone, Debug)]
+#[auto_update(render)]
+#[props(Popover, PassedChildren)]
+#[require(WoodpeckerStyle, PassedChildren, WidgetRender = WidgetRender::Quad, WidgetChildren, Pickable)]
+pub struct Popover {
+    /// The widget the popover is placed next to, defaults to the popover's parent.
+    pub anchor: Option<Entity>,
+    /// Is the popover open?
+    pub open: bool,
+    /// The preferred side of the anchor.
+    pub side: PopoverSide,
+    /// How the popover lines up with the anchor.
+    pub align: PopoverAlign,
+    /// The gap between the anchor and the popover in pixels.
+    pub offset: f32,
+    /// Move to the opposite side if the popover doesn't fit on the preferred side.
+    pub flip: bool,
+    /// Slide the popover along the anchor to keep it inside the viewport.
+    pub shift: bool,
+    /// The space kept between the popover and the edges of the viewport.
+    pub viewport_padding: f32,
+    /// Makes the popover at least as wide(or tall for left and right) as the anchor.
+    pub match_anchor_size: bool,
+    /// Trigger [`PopoverDismissed`] when the user presses outside of the popover and anchor.
+    pub dismiss_on_outside_click: bool,
+    /// Trigger [`PopoverDismissed`] when the user presses escape.
+    pub dismiss_on_escape: bool,
+    /// The global z index the popover is drawn at.
+    pub z_index: u32,
+    /// Styles for the popover
+    pub styles: WoodpeckerStyle,
+}
+
+impl Default for Popover {
+    fn default() -> Self {
+        Self {
+            anchor: None,
+            open: false,
+            side: PopoverSide::Bottom,
+            align: PopoverAlign::Start,
+            offset: 4.0,
+            flip: true,
+            shift: true,
+            viewport_padding: 4.0,
+            match_anchor_size: false,
+            dismiss_on_outside_click: true,
+            dismiss_on_escape: true,
+            z_index: 1000,
+            styles: WoodpeckerStyle {
+                background_color: colors::BACKGROUND,
+                flex_direction: WidgetFlexDirection::Column,
+                ..Default::default()
+            },
+        }
+    }
+}
+
+fn render(
+    current_widget: Res<CurrentWidget>,
+    mut query: Query<(
+        &Popover,
+        &PassedChildren,
+        &mut WidgetChildren,
+        &mut WoodpeckerStyle,
+        Option<&PopoverPlacement>,
+    )>,
+) {
+    let Ok((popover, passed_children, mut children, mut styles, placement)) =
+        query.get_mut(**current_widget)
+    else {
+        return;
+    };
+
+    *styles = WoodpeckerStyle {
+        position: WidgetPosition::Fixed,
+        display: if popover.open {
+            popover.styles.display
+        } else {
+            WidgetDisplay::None
+        },
+        z_index: Some(WidgetZ::Global(popover.z_index)),
+        ..popover.styles
+    };
+    // Keep the last placement so re-rendering doesn't move the popover for a frame,
+    // a popover that was never placed stays hidden until it is.
+    match placement {
+        Some(placement) => {
+            styles.left = placement.position.x.into();
+            styles.top = placement.position.y.into();
+        }
+        None => styles.visibility = WidgetVisibility::Hidden,
+    }
+
+    *children = passed_children.0.clone();
+    children.apply(current_widget.as_parent());
+}
+
+/// The distance a rect sticks out of the viewport along one axis.
+fn overflow(min: f32, max: f32, viewport_min: f32, viewport_max: f32) -> f32 {
+    (viewport_min - min).max(0.0) + (max - viewport_max).max(0.0)
+}
+
+/// Places a popover of `size` next to `anchor`, returning its top-left corner and the side used.
+pub(crate) fn compute_placement(
+    popover: &Popover,
+    anchor: Rect,
+    size: Vec2,
+    viewport: Rect,
+) -> (Vec2, PopoverSide) {
+    let viewport = viewport.inflate(-popover.viewport_padding);
+
+    let main_axis = |side: PopoverSide| match side {
+        PopoverSide::Top => anchor.min.y - popover.offset - size.y,
+        PopoverSide::Bottom => anchor.max.y + popover.offset,
+        PopoverSide::Left => anchor.min.x - popover.offset - size.x,
+        PopoverSide::Right => anchor.max.x + popover.offset,
+    };
+    let main_overflow = |side: PopoverSide| {
+        let start = main_axis(side);
+        if side.is_vertical() {
+            overflow(start, start + size.y, viewport.min.y, viewport.max.y)
+        } else {
+            overflow(start, start + size.x, viewport.min.x, viewport.max.x)
+        }
+    };
+
+    let mut side = popover.side;
+    if popover.flip {
+        let current = main_overflow(side);
+        if current > 0.0 && main_overflow(side.opposite()) < current {
+            side = side.opposite();
+        }
+    }
+
+    let (anchor_min, anchor_max, length, viewport_min, viewport_max) = if side.is_vertical() {
+        (
+            anchor.min.x,
+            anchor.max.x,
+            size.x,
+            viewport.min.x,
+            viewport.max.x,
+        )
+    } else {
+        (
+            anchor.min.y,
+            anchor.max.y,
+            size.y,
+            viewport.min.y,
+            viewport.max.y,
+        )
+    };
+    let mut cross_axis = match popover.align {
+        PopoverAlign::Start => anchor_min,
+        PopoverAlign::Center => (anchor_min + anchor_max - length) / 2.0,
+        PopoverAlign::End => anchor_max - length,
+    };
+    if popover.shift {
+        // Prefer showing the start of the popover if it's bigger than the viewport.
+        cross_axis = cross_axis.min(viewport_max - length).max(viewport_min);
+    }
+
+    let position = if side.is_vertical() {
+        Vec2::new(cross_axis, main_axis(side))
+    } else {
+        Vec2::new(main_axis(side), cross_axis)
+    };
+    (position, side)
+}
+
+// IMPORTANT: When modifying widget entities we need to verify we aren't modifying previous widget values.
+pub(crate) fn update_popover_placement(
+    mut commands: Commands,
+    context: Res<WoodpeckerContext>,
+    layout_query: Query<&WidgetLayout>,
+    mut query: Query<
+        (
+            Entity,
+            &Popover,
+            &mut WoodpeckerStyle,
+            Option<&WidgetLayout>,
+            Option<&ChildOf>,
+        ),
+        Without<PreviousWidget>,
+    >,
+) {
+    let Ok(root_layout) = layout_query.get(context.get_root_widget()) else {
+        return;
+    };
+    let viewport = Rect::from_corners(
+        root_layout.location,
+        root_layout.location + root_layout.size,
+    );
+
+    for (entity, popover, mut style, layout, parent) in query.iter_mut() {
+        if !popover.open {
+            continue;
+        }
+        let anchor = popover.anchor.or(parent.map(|parent| parent.parent()));
+        let Some(anchor_layout) = anchor.and_then(|anchor| layout_query.get(anchor).ok()) else {
+            continue;
+        };
+        let anchor_rect = Rect::from_corners(
+            anchor_layout.location,
+            anchor_layout.location + anchor_layout.size,
+        );
+
+        let mut new_style = *style;
+        if popover.match_anchor_size {
+            if popover.side.is_vertical() {
+                new_style.min_width = anchor_layout.size.x.into();
+            } else {
+                new_style.min_height = anchor_layout.size.y.into();
+            }
+        }
+
+        // The popover wasn't laid out while it was closed, keep it hidden until it has a size.
+        let size = layout.map(|layout| layout.size).unwrap_or_default();
+        if size.x <= 0.0 && size.y <= 0.0 {
+            new_style.visibility = WidgetVisibility::Hidden;
+            style.set_if_neq(new_style);
+            continue;
+        }
+
+        let (position, side) = compute_placement(popover, anchor_rect, size, viewport);
+        new_style.left = position.x.into();
+        new_style.top = position.y.into();
+        new_style.visibility = popover.styles.visibility;
+        style.set_if_neq(new_style);
+        commands
+            .entity(entity)
+            .insert(PopoverPlacement { side, position });
+    }
+}
+
+pub(crate) fn dismiss_popovers(
+    mut commands: Commands,
+    mouse_input: Res<ButtonInput<MouseButton>>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    hover_map: Res<HoverMap>,
+    query: Query<
+        (Entity, &Popover, Option<&WidgetLayout>, Option<&ChildOf>),
+        Without<PreviousWidget>,
+    >,
+    parent_query: Query<&ChildOf>,
+) {
+    if mouse_input.just_pressed(MouseButton::Left) {
+        let hovered = hover_map
+            .values()
+            .flat_map(|hits| hits.keys().copied())
+            .collect::<Vec<_>>();
+        for (entity, popover, _, parent) in query.iter() {
+            if !popover.open || !popover.dismiss_on_outside_click {
+                continue;
+            }
+            let anchor = popover.anchor.or(parent.map(|parent| parent.parent()));
+            // Presses on the anchor are left to the anchor, it usually toggles the popover.
+            let inside = hovered.iter().any(|hit| {
+                std::iter::once(*hit)
+                    .chain(parent_query.iter_ancestors(*hit))
+                    .any(|ancestor| ancestor == entity || Some(ancestor) == anchor)
+            });
+            if !inside {
+                commands.trigger(PopoverDismissed {
+                    target: entity,
+                    reason: DismissReason::OutsideClick,
+                });
+            }
+        }
+    }
+
+    if keyboard_input.just_pressed(KeyCode::Escape) {
+        // Only the top-most popover is dismissed so nested popovers close one at a time.
+        let top_most = query
+            .iter()
+            .filter(|(_, popover, _, _)| popover.open && popover.dismiss_on_escape)
+            .max_by_key(|(_, _, layout, _)| layout.map(|layout| (layout.z, layout.order)));
+        if let Some((entity, ..)) = top_most {
+            commands.trigger(PopoverDismissed {
+                target: entity,
+                reason: DismissReason::Escape,
+            });
+        }
+    }
+}
+
+#[test]
+fn test_popover_placement() {
+    let viewport = Rect::new(0.0, 0.0, 400.0, 300.0);
+    let anchor = Rect::new(100.0, 100.0, 200.0, 130.0);
+    let size = Vec2::new(150.0, 50.0);
+    let popover = Popover {
+        offset: 5.0,
+        viewport_padding: 0.0,
+        ..Default::default()
+    };
+    assert_eq!(
+        compute_placement(&popover, anchor, size, viewport),
+        (Vec2::new(100.0, 135.0), PopoverSide::Bottom)
+    );
+
+    let centered = Popover {
+        align: PopoverAlign::Center,
+        side: PopoverSide::Top,
+        ..popover.clone()
+    };
+    assert_eq!(
+        compute_placement(&centered, anchor, size, viewport),
+        (Vec2::new(75.0, 45.0), PopoverSide::Top)
+    );
+
+    // Flips to the top near the bottom of the viewport.
+    let low_anchor = Rect::new(100.0, 260.0, 200.0, 290.0);
+    assert_eq!(
+        compute_placement(&popover, low_anchor, size, viewport),
+        (Vec2::new(100.0, 205.0), PopoverSide::Top)
+    );
+    let no_flip = Popover {
+        flip: false,
+        ..popover.clone()
+    };
+    assert_eq!(
+        compute_placement(&no_flip, low_anchor, size, viewport).1,
+        PopoverSide::Bottom
+    );
+
+    // Shifts back into the viewport near the right edge.
+    let right_anchor = Rect::new(330.0, 100.0, 390.0, 130.0);
+    assert_eq!(
+        compute_placement(&popover, right_anchor, size, viewport).0,
+        Vec2::new(250.0, 135.0)
+    );
+
+    let right = Popover {
+        side: PopoverSide::Right,
+        ..popover.clone()
+    };
+    assert_eq!(
+        compute_placement(&right, right_anchor, size, viewport),
+        (Vec2::new(175.0, 100.0), PopoverSide::Left)
+    );
+}
@@ -0,0 +1,217 @@
+mod radio_button;
+
+use crate::prelude::*;
+use bevy::prelude::*;
+
+pub use radio_button::*;
+
+/// A radio group change event.
+#[derive(Debug, Clone, Reflect)]
+pub struct RadioGroupChanged {
+    /// The index of the selected radio button
+    pub index: usize,
+    /// The value of the selected radio button
+    pub value: String,
+}
+
+/// The direction radio buttons are laid out in.
+#[derive(Debug, Default, Reflect, Clone, Copy, PartialEq, Eq)]
+pub enum RadioOrientation {
+    /// Side by side
+    Horizontal,
+    /// Stacked
+    #[default]
+    Vertical,
+}
+
+/// Radio context, shared by the [`RadioButton`]s in a [`RadioGroup`].
+#[derive(Component, Clone, PartialEq, Reflect)]
+pub struct RadioContext {
+    selected: Option<usize>,
+    group: Entity,
+}
+
+impl Default for RadioContext {
+    fn default() -> Self {
+        Self {
+            selected: None,
+            group: Entity::PLACEHOLDER,
+        }
+    }
+}
+
+impl RadioContext {
+    /// The index of the selected radio button.
+    pub fn selected(&self) -> Option<usize> {
+        self.selected
+    }
+
+    /// Selects a radio button and triggers [`RadioGroupChanged`] on the group.
+    pub(crate) fn select(&mut self, commands: &mut Commands, index: usize, value: String) {
+        if self.selected == Some(index) {
+            return;
+        }
+        self.selected = Some(index);
+        commands.trigger(Change {
+            target: self.group,
+            data: RadioGroupChanged { index, value },
+        });
+    }
+}
+
+/// A group of mutually exclusive [`RadioButton`]s.
+///
+/// The radio buttons can be anywhere inside the group's children. While the group is
+/// focused the arrow keys select the previous or next radio button, Home and End select
+/// the first and last.
+#[derive(Widget, Component, Clone, PartialEq, Reflect)]
+#[auto_update(render)]
+#[props(RadioGroup, PassedChildren)]
+#[require(WoodpeckerStyle, PassedChildren, WidgetChildren, WidgetRender = WidgetRender::Quad, Pickable, Focusable)]
+pub struct RadioGroup {
+    /// The initially selected radio button
+    pub initial_index: Option<usize>,
+    /// The direction the radio buttons are laid out in
+    pub orientation: RadioOrientation,
+    /// Styles, the gap is used between the radio buttons
+    pub styles: WoodpeckerStyle,
+}
+
+impl Default for RadioGroup {
+    fn default() -> Self {
+        Self {
+            initial_index: None,
+            orientation: RadioOrientation::Vertical,
+            styles: WoodpeckerStyle {
+                gap: (8.0.into(), 8.0.into()),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// The radio button selected by a key press, wrapping around at the ends.
+///
+/// `buttons` holds the index, value and disabled flag of every radio button, sorted by index.
+fn navigate(
+    buttons: &[(usize, String, bool)],
+    selected: Option<usize>,
+    code: KeyCode,
+) -> Option<(usize, String)> {
+    let enabled = buttons
+        .iter()
+        .filter(|(_, _, disabled)| !disabled)
+        .collect::<Vec<_>>();
+    if enabled.is_empty() {
+        return None;
+    }
+    let current =
+        selected.and_then(|selected| enabled.iter().position(|(index, _, _)| *index == selected));
+    let last = enabled.len() - 1;
+    let next = match code {
+        KeyCode::ArrowDown | KeyCode::ArrowRight => {
+            current.map(|i| (i + 1) % enabled.len()).unwrap_or(0)
+        }
+        KeyCode::ArrowUp | KeyCode::ArrowLeft => current
+            .map(|i| (i + enabled.len() - 1) % enabled.len())
+            .unwrap_or(last),
+        KeyCode::Home => 0,
+        KeyCode::End => last,
+        _ => return None,
+    };
+    enabled
+        .get(next)
+        .map(|(index, value, _)| (*index, value.clone()))
+}
+
+fn render(
+    mut commands: Commands,
+    mut hooks: ResMut<HookHelper>,
+    current_widget: Res<CurrentWidget>,
+    mut query: Query<(
+        &RadioGroup,
+        &mut WoodpeckerStyle,
+        &mut WidgetChildren,
+        &PassedChildren,
+    )>,
+) {
+    let Ok((radio_group, mut styles, mut children, passed_children)) =
+        query.get_mut(**current_widget)
+    else {
+        return;
+    };
+
+    let context_entity = hooks.use_context(
+        &mut commands,
+        *current_widget,
+        RadioContext {
+            selected: radio_group.initial_index,
+            group: **current_widget,
+        },
+    );
+
+    *styles = radio_group.styles;
+
+    let group_entity = **current_widget;
+    *children = WidgetChildren::default()
+        .with_observe(
+            *current_widget,
+            move |trigger: On<WidgetKeyboardButtonEvent>,
+                  mut commands: Commands,
+                  mut context_query: Query<&mut RadioContext>,
+                  children_query: Query<&Children>,
+                  radio_query: Query<&RadioButton>| {
+                let Ok(mut context) = context_query.get_mut(context_entity) else {
+                    return;
+                };
+                let mut buttons = children_query
+                    .iter_descendants(group_entity)
+                    .filter_map(|entity| radio_query.get(entity).ok())
+                    .map(|radio| (radio.index, radio.value.clone(), radio.disabled))
+                    .collect::<Vec<_>>();
+                buttons.sort_by_key(|(index, _, _)| *index);
+                buttons.dedup_by_key(|(index, _, _)| *index);
+                if let Some((index, value)) = navigate(&buttons, context.selected, trigger.code) {
+                    context.select(&mut commands, index, value);
+                }
+            },
+        )
+        .with_child::<Element>((
+            Element,
+            WoodpeckerStyle {
+                flex_direction: match radio_group.orientation {
+                    RadioOrientation::Horizontal => WidgetFlexDirection::Row,
+                    RadioOrientation::Vertical => WidgetFlexDirection::Column,
+                },
+                gap: radio_group.styles.gap,
+                ..Default::default()
+            },
+            passed_children.0.clone(),
+        ));
+
+    children.apply(current_widget.as_parent());
+}
+
+#[test]
+fn test_radio_navigation() {
+    let buttons = vec![
+        (0, "small".to_string(), false),
+        (1, "medium".to_string(), true),
+        (2, "large".to_string(), false),
+        (3, "huge".to_string(), false),
+    ];
+    let index = |selected, code| navigate(&buttons, selected, code).map(|(index, _)| index);
+    assert_eq!(index(Some(0), KeyCode::ArrowDown), Some(2));
+    assert_eq!(index(Some(2), KeyCode::ArrowLeft), Some(0));
+    // Wraps around.
+    assert_eq!(index(Some(3), KeyCode::ArrowRight), Some(0));
+    assert_eq!(index(Some(0), KeyCode::ArrowUp), Some(3));
+    assert_eq!(index(None, KeyCode::ArrowDown), Some(0));
+    assert_eq!(index(None, KeyCode::ArrowUp), Some(3));
+    assert_eq!(index(Some(2), KeyCode::End), Some(3));
+    assert_eq!(index(Some(2), KeyCode::KeyA), None);
+    assert_eq!(
+        navigate(&buttons, Some(3), KeyCode::Home),
+        Some((0, "small".to_string()))
+    );
+}
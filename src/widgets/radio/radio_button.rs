@@ -0,0 +1,206 @@
+use super::RadioContext;
+use crate::prelude::*;
+use bevy::prelude::*;
+
+/// The state of a radio button
+#[derive(Component, Debug, Default, Reflect, PartialEq, Clone)]
+pub struct RadioButtonState {
+    /// Is hovering
+    pub is_hovering: bool,
+}
+
+/// A collection of styles for the radio button widget
+#[derive(Component, Reflect, PartialEq, Clone)]
+pub struct RadioButtonStyles {
+    /// Circle styles
+    pub background: CheckboxStyles,
+    /// The dot shown inside the circle of the selected radio button
+    pub dot: CheckboxStyles,
+    /// Label styles
+    pub label: WoodpeckerStyle,
+    /// Label styles of a disabled radio button
+    pub disabled_label: WoodpeckerStyle,
+}
+
+impl Default for RadioButtonStyles {
+    fn default() -> Self {
+        let background_normal = WoodpeckerStyle {
+            background_color: colors::BACKGROUND_LIGHT,
+            width: 20.0.into(),
+            height: 20.0.into(),
+            border_radius: Corner::all(10.0),
+            justify_content: Some(WidgetAlignContent::Center),
+            align_items: Some(WidgetAlignItems::Center),
+            ..Default::default()
+        };
+        let dot = WoodpeckerStyle {
+            background_color: Color::WHITE,
+            width: 8.0.into(),
+            height: 8.0.into(),
+            border_radius: Corner::all(4.0),
+            ..Default::default()
+        };
+        let label = WoodpeckerStyle {
+            color: Color::WHITE,
+            font_size: 16.0,
+            margin: Edge::all(0.0).left(8.0),
+            text_wrap: TextWrap::None,
+            ..Default::default()
+        };
+        Self {
+            background: CheckboxStyles {
+                normal: background_normal,
+                hovered: WoodpeckerStyle {
+                    background_color: colors::BACKGROUND,
+                    ..background_normal
+                },
+                checked: WoodpeckerStyle {
+                    background_color: colors::PRIMARY_LIGHT,
+                    ..background_normal
+                },
+                hovered_checked: WoodpeckerStyle {
+                    background_color: colors::PRIMARY,
+                    ..background_normal
+                },
+            },
+            dot: CheckboxStyles {
+                normal: dot,
+                hovered: dot,
+                checked: dot,
+                hovered_checked: dot,
+            },
+            label,
+            disabled_label: WoodpeckerStyle {
+                color: colors::BACKGROUND_LIGHT,
+                ..label
+            },
+        }
+    }
+}
+
+/// A radio button, only one radio button in a [`super::RadioGroup`] can be selected.
+#[derive(Widget, Component, Reflect, PartialEq, Clone, Default)]
+#[auto_update(render)]
+#[props(RadioButton, RadioButtonStyles)]
+#[state(RadioButtonState)]
+#[context(RadioContext)]
+#[require(RadioButtonStyles, WidgetChildren, WoodpeckerStyle, WidgetRender = WidgetRender::Quad, Pickable)]
+pub struct RadioButton {
+    /// The index of the radio button in the group
+    pub index: usize,
+    /// The text shown next to the radio button
+    pub label: String,
+    /// The value reported when the radio button is selected
+    pub value: String,
+    /// Disabled radio buttons can't be selected
+    pub disabled: bool,
+}
+
+fn render(
+    mut commands: Commands,
+    current_widget: Res<CurrentWidget>,
+    mut hooks: ResMut<HookHelper>,
+    mut query: Query<(
+        &RadioButton,
+        &RadioButtonStyles,
+        &mut WoodpeckerStyle,
+        &mut WidgetChildren,
+    )>,
+    state_query: Query<&RadioButtonState>,
+    context_query: Query<&RadioContext>,
+) {
+    let Ok((radio_button, radio_styles, mut styles, mut children)) =
+        query.get_mut(**current_widget)
+    else {
+        return;
+    };
+
+    let state_entity = hooks.use_state(&mut commands, *current_widget, RadioButtonState::default());
+    let context_entity = hooks.use_context(&mut commands, *current_widget, RadioContext::default());
+
+    let default_state = RadioButtonState::default();
+    let state = state_query.get(state_entity).unwrap_or(&default_state);
+    let Ok(context) = context_query.get(context_entity) else {
+        return;
+    };
+
+    let check_state = CheckboxState {
+        is_hovering: state.is_hovering && !radio_button.disabled,
+        is_checked: context.selected() == Some(radio_button.index),
+    };
+
+    *styles = WoodpeckerStyle {
+        align_items: Some(WidgetAlignItems::Center),
+        ..Default::default()
+    };
+
+    let index = radio_button.index;
+    let value = radio_button.value.clone();
+    let disabled = radio_button.disabled;
+    *children = WidgetChildren::default();
+    children
+        .observe(
+            *current_widget,
+            move |_: On<Pointer<Over>>, mut state_query: Query<&mut RadioButtonState>| {
+                if let Ok(mut state) = state_query.get_mut(state_entity) {
+                    state.is_hovering = true;
+                }
+            },
+        )
+        .observe(
+            *current_widget,
+            move |_: On<Pointer<Out>>, mut state_query: Query<&mut RadioButtonState>| {
+                if let Ok(mut state) = state_query.get_mut(state_entity) {
+                    state.is_hovering = false;
+                }
+            },
+        )
+        .observe(
+            *current_widget,
+            move |mut trigger: On<Pointer<Click>>,
+                  mut commands: Commands,
+                  mut current_focus: ResMut<CurrentFocus>,
+                  mut context_query: Query<&mut RadioContext>| {
+                trigger.propagate(false);
+                if disabled {
+                    return;
+                }
+                let Ok(mut context) = context_query.get_mut(context_entity) else {
+                    return;
+                };
+                context.select(&mut commands, index, value.clone());
+                // Focus the group so the arrow keys work after clicking.
+                current_focus.refocus(&mut commands, context.group);
+            },
+        );
+
+    let mut circle_children = WidgetChildren::default();
+    if check_state.is_checked {
+        circle_children.add::<Element>((
+            Element,
+            radio_styles.dot.get_style(&check_state),
+            WidgetRender::Quad,
+        ));
+    }
+
+    children
+        .add::<Element>((
+            Element,
+            radio_styles.background.get_style(&check_state),
+            WidgetRender::Quad,
+            circle_children,
+        ))
+        .add::<Element>((
+            Element,
+            if disabled {
+                radio_styles.disabled_label
+            } else {
+                radio_styles.label
+            },
+            WidgetRender::Text {
+                content: radio_button.label.clone(),
+            },
+        ));
+
+    children.apply(current_widget.as_parent());
+}
@@ -2,12 +2,16 @@
 pub mod content;
 pub mod scroll_bar;
 pub mod scroll_box;
+mod snap;
 
 use std::sync::Arc;
 
 use crate::prelude::*;
 use bevy::prelude::*;
 
+pub(crate) use snap::snap_scroll_boxes;
+pub use snap::{ScrollSnapAlign, ScrollSnapType};
+
 /// Context data provided by a [`ScrollBox`](crate::prelude::ScrollBox) widget
 #[derive(Component, Default, Reflect, Debug, Copy, Clone, PartialEq)]
 pub struct ScrollContext {
@@ -23,8 +27,23 @@ pub struct ScrollContext {
     pub(super) is_dragging: bool,
     pub(super) start_pos: Vec2,
     pub(super) start_offset: Vec2,
+    pub(super) spring: Spring,
+    pub(super) spring_target_x: Option<f32>,
+    pub(super) spring_target_y: Option<f32>,
+    pub(super) velocity: Vec2,
+    pub(super) momentum: Vec2,
+    pub(super) friction: f32,
+    pub(super) last_drag: f64,
+    pub(super) settled_offset: Vec2,
+    pub(super) settled_time: f64,
 }
 
+/// Kinetic scrolling stops below this speed in pixels per second.
+const MIN_MOMENTUM: f32 = 5.0;
+/// A drag that hasn't moved for this many seconds doesn't fling the content when released.
+const FLING_TIMEOUT: f64 = 0.1;
+
+/// How the scroll offset is limited, set with [`ScrollContext::with_mode`]
 #[non_exhaustive]
 #[derive(Default, Debug, Reflect, Copy, Clone, PartialEq, Eq)]
 pub enum ScrollMode {
@@ -33,9 +52,18 @@ pub enum ScrollMode {
     Clamped,
     /// Allows infinite scrolling
     Infinite,
+    /// Like `Clamped`, but dragging and flinging the content can move it past the edges
+    /// where it springs back
+    Bounce,
 }
 
 impl ScrollContext {
+    /// Sets the scroll mode
+    pub fn with_mode(mut self, mode: ScrollMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
     /// Get the current x-axis scroll offset
     pub fn scroll_x(&self) -> f32 {
         self.scroll_x
@@ -83,22 +111,203 @@ impl ScrollContext {
     ///
     /// This automatically accounts for the scroll mode
     pub fn set_scroll_x(&mut self, x: f32) {
-        let min = -self.scrollable_width();
-        self.scroll_x = match self.mode {
-            ScrollMode::Clamped => ScrollContext::clamped(x, min, 0.0),
-            ScrollMode::Infinite => x,
-        }
+        self.scroll_x = self.limit_x(x);
+        self.spring_target_x = None;
+        self.velocity.x = 0.0;
+        self.momentum.x = 0.0;
     }
 
     /// Set the scroll offset along the y-axis
     ///
     /// This automatically accounts for the scroll mode
     pub fn set_scroll_y(&mut self, y: f32) {
+        self.scroll_y = self.limit_y(y);
+        self.spring_target_y = None;
+        self.velocity.y = 0.0;
+        self.momentum.y = 0.0;
+    }
+
+    /// Animates the x-axis scroll offset to `x` with a spring
+    ///
+    /// If already animating, the current velocity is kept
+    pub fn spring_scroll_x(&mut self, x: f32, spring: Spring) {
+        self.spring = spring;
+        self.spring_target_x = Some(self.limit_x(x));
+        self.momentum.x = 0.0;
+    }
+
+    /// Animates the y-axis scroll offset to `y` with a spring
+    ///
+    /// If already animating, the current velocity is kept
+    pub fn spring_scroll_y(&mut self, y: f32, spring: Spring) {
+        self.spring = spring;
+        self.spring_target_y = Some(self.limit_y(y));
+        self.momentum.y = 0.0;
+    }
+
+    /// The x-axis scroll offset the context is animating toward, or the current offset
+    pub fn scroll_target_x(&self) -> f32 {
+        self.spring_target_x.unwrap_or(self.scroll_x)
+    }
+
+    /// The y-axis scroll offset the context is animating toward, or the current offset
+    pub fn scroll_target_y(&self) -> f32 {
+        self.spring_target_y.unwrap_or(self.scroll_y)
+    }
+
+    /// Is a spring currently animating the scroll offset?
+    pub fn is_spring_scrolling(&self) -> bool {
+        self.spring_target_x.is_some() || self.spring_target_y.is_some()
+    }
+
+    /// Is the content moving on its own after being flung?
+    pub fn is_kinetic_scrolling(&self) -> bool {
+        !self.is_dragging && self.momentum != Vec2::ZERO
+    }
+
+    /// Is the content being dragged by a touch pointer?
+    pub fn is_dragging(&self) -> bool {
+        self.is_dragging
+    }
+
+    /// Starts dragging the content, stopping any scroll animation.
+    pub(super) fn start_drag(&mut self, time: f64) {
+        // Grabbing the content stops it where it is, even past an edge.
+        self.spring_target_x = None;
+        self.spring_target_y = None;
+        self.velocity = Vec2::ZERO;
+        self.momentum = Vec2::ZERO;
+        self.is_dragging = true;
+        self.start_offset = Vec2::new(self.scroll_x, self.scroll_y);
+        self.last_drag = time;
+    }
+
+    /// Moves the dragged content to an offset, `velocity` is the speed of the pointer in
+    /// pixels per second.
+    ///
+    /// In bounce mode the content can be pulled past the edges with increasing resistance.
+    pub(super) fn drag_to(&mut self, offset: Vec2, velocity: Vec2, time: f64) {
+        if self.mode == ScrollMode::Bounce {
+            self.scroll_x = rubber_band(offset.x, -self.scrollable_width(), self.scrollbox_width);
+            self.scroll_y = rubber_band(offset.y, -self.scrollable_height(), self.scrollbox_height);
+        } else {
+            self.scroll_x = self.limit_x(offset.x);
+            self.scroll_y = self.limit_y(offset.y);
+        }
+        // Smooth out the pointer speed so one uneven frame doesn't decide the fling.
+        self.momentum = self.momentum.lerp(velocity, 0.8);
+        self.last_drag = time;
+    }
+
+    /// Stops dragging the content, with a friction the content keeps moving and slows down.
+    pub(super) fn end_drag(&mut self, friction: Option<f32>, time: f64) {
+        self.is_dragging = false;
+        match friction {
+            Some(friction) if time - self.last_drag < FLING_TIMEOUT => self.friction = friction,
+            _ => self.momentum = Vec2::ZERO,
+        }
+        // Pulled past an edge, spring back.
+        if self.momentum.x == 0.0 && self.limit_x(self.scroll_x) != self.scroll_x {
+            self.spring_scroll_x(self.scroll_x, Spring::DEFAULT);
+        }
+        if self.momentum.y == 0.0 && self.limit_y(self.scroll_y) != self.scroll_y {
+            self.spring_scroll_y(self.scroll_y, Spring::DEFAULT);
+        }
+    }
+
+    /// Moves flung content forward by `delta` seconds
+    pub(crate) fn update_kinetic(&mut self, delta: f32, reduced_motion: bool) {
+        if reduced_motion {
+            self.momentum = Vec2::ZERO;
+        }
+        let decay = (-self.friction * delta).exp();
+        if self.momentum.x != 0.0 {
+            let x = self.scroll_x + self.momentum.x * delta;
+            let limited = self.limit_x(x);
+            if limited != x {
+                self.hit_edge_x(x, limited);
+            } else {
+                self.scroll_x = x;
+                self.momentum.x = decayed(self.momentum.x, decay);
+            }
+        }
+        if self.momentum.y != 0.0 {
+            let y = self.scroll_y + self.momentum.y * delta;
+            let limited = self.limit_y(y);
+            if limited != y {
+                self.hit_edge_y(y, limited);
+            } else {
+                self.scroll_y = y;
+                self.momentum.y = decayed(self.momentum.y, decay);
+            }
+        }
+    }
+
+    fn hit_edge_x(&mut self, x: f32, edge: f32) {
+        if self.mode == ScrollMode::Bounce {
+            // Keep moving past the edge and let a spring pull the content back.
+            self.scroll_x = x;
+            self.velocity.x = self.momentum.x;
+            self.spring_scroll_x(edge, Spring::DEFAULT);
+        } else {
+            self.set_scroll_x(edge);
+        }
+        self.momentum.x = 0.0;
+    }
+
+    fn hit_edge_y(&mut self, y: f32, edge: f32) {
+        if self.mode == ScrollMode::Bounce {
+            // Keep moving past the edge and let a spring pull the content back.
+            self.scroll_y = y;
+            self.velocity.y = self.momentum.y;
+            self.spring_scroll_y(edge, Spring::DEFAULT);
+        } else {
+            self.set_scroll_y(edge);
+        }
+        self.momentum.y = 0.0;
+    }
+
+    /// Moves the scroll springs forward by `delta` seconds
+    pub(crate) fn update_spring(&mut self, delta: f32, reduced_motion: bool) {
+        let delta = if reduced_motion { f32::INFINITY } else { delta };
+        if let Some(target) = self.spring_target_x.map(|x| self.limit_x(x)) {
+            let (x, velocity) = self
+                .spring
+                .step(self.scroll_x, self.velocity.x, target, delta);
+            if Spring::is_at_rest(x, velocity, target) {
+                self.set_scroll_x(target);
+            } else {
+                self.scroll_x = x;
+                self.velocity.x = velocity;
+            }
+        }
+        if let Some(target) = self.spring_target_y.map(|y| self.limit_y(y)) {
+            let (y, velocity) = self
+                .spring
+                .step(self.scroll_y, self.velocity.y, target, delta);
+            if Spring::is_at_rest(y, velocity, target) {
+                self.set_scroll_y(target);
+            } else {
+                self.scroll_y = y;
+                self.velocity.y = velocity;
+            }
+        }
+    }
+
+    fn limit_x(&self, x: f32) -> f32 {
+        let min = -self.scrollable_width();
+        match self.mode {
+            ScrollMode::Clamped | ScrollMode::Bounce => ScrollContext::clamped(x, min, 0.0),
+            ScrollMode::Infinite => x,
+        }
+    }
+
+    fn limit_y(&self, y: f32) -> f32 {
         let min = -self.scrollable_height();
-        self.scroll_y = match self.mode {
-            ScrollMode::Clamped => ScrollContext::clamped(y, min, 0.0),
+        match self.mode {
+            ScrollMode::Clamped | ScrollMode::Bounce => ScrollContext::clamped(y, min, 0.0),
             ScrollMode::Infinite => y,
-        };
+        }
     }
 
     /// The percent scrolled along the x-axis
@@ -189,8 +398,8 @@ pub fn render(
         return;
     };
 
-    // Setup scroll context.
-    let entity = context.use_context(&mut commands, *current_widget, provider.initial_value);
+    // Setup scroll context, always a new one so providers can be nested in scroll boxes.
+    let entity = context.provide_context(&mut commands, *current_widget, provider.initial_value);
     /*
     if let Some(tag) = provider.tag.as_ref() {
         (tag.f)(commands.entity(entity));
@@ -199,6 +408,176 @@ pub fn render(
     children.apply(current_widget.as_parent());
 }
 
+/// Slows down a speed, stopping it once it's too slow to notice.
+fn decayed(momentum: f32, decay: f32) -> f32 {
+    let momentum = momentum * decay;
+    if momentum.abs() < MIN_MOMENTUM {
+        0.0
+    } else {
+        momentum
+    }
+}
+
+/// Limits how far content can be pulled past the edges of the scroll range `min..=0`,
+/// the further it's pulled the more it resists. `size` is the size of the visible area.
+fn rubber_band(offset: f32, min: f32, size: f32) -> f32 {
+    let (edge, over) = if offset > 0.0 {
+        (0.0, offset)
+    } else if offset < min {
+        (min, offset - min)
+    } else {
+        return offset;
+    };
+    let size = size.max(1.0);
+    edge + over.signum() * (1.0 - 1.0 / (over.abs() * 0.55 / size + 1.0)) * size
+}
+
+/// Where [`ScrollTo`] places the target in the visible area of a scroll box.
+#[derive(Default, Debug, Reflect, Copy, Clone, PartialEq, Eq)]
+pub enum ScrollAlign {
+    /// Aligns the start of the target with the start of the visible area
+    Start,
+    /// Centers the target in the visible area
+    Center,
+    /// Aligns the end of the target with the end of the visible area
+    End,
+    /// Scrolls as little as possible, doesn't scroll if the target is already visible
+    #[default]
+    Nearest,
+}
+
+/// Scrolls every scroll box containing `target` so that the target is visible.
+///
+/// Triggered automatically with [`ScrollAlign::Nearest`] when a widget gains focus.
+#[derive(Event, Debug, Reflect, Copy, Clone, PartialEq)]
+pub struct ScrollTo {
+    /// A descendant of the scroll box content
+    pub target: Entity,
+    /// Where the target ends up in the visible area
+    pub align: ScrollAlign,
+    /// Animates to the new offset with the scroll box spring, or the default spring
+    pub animated: bool,
+}
+
+/// The scroll offset that aligns a target starting `start` pixels into the content along one axis.
+///
+/// Scroll offsets are negative, `-scroll` is the first visible pixel of the content.
+pub(super) fn aligned_offset(
+    align: ScrollAlign,
+    scroll: f32,
+    start: f32,
+    size: f32,
+    view: f32,
+) -> f32 {
+    match align {
+        ScrollAlign::Start => -start,
+        ScrollAlign::Center => -(start + size / 2.0 - view / 2.0),
+        ScrollAlign::End => -(start + size - view),
+        ScrollAlign::Nearest => {
+            let visible_start = -scroll;
+            if start >= visible_start && start + size <= visible_start + view {
+                scroll
+            } else if start < visible_start || size > view {
+                -start
+            } else {
+                -(start + size - view)
+            }
+        }
+    }
+}
+
+pub(crate) fn scroll_to(
+    trigger: On<ScrollTo>,
+    hook_helper: Res<HookHelper>,
+    parent_query: Query<&ChildOf>,
+    content_query: Query<(), With<ScrollContent>>,
+    scroll_box_query: Query<&ScrollBox>,
+    layout_query: Query<&WidgetLayout>,
+    mut context_query: Query<&mut ScrollContext>,
+) {
+    let Ok(target_layout) = layout_query.get(trigger.target) else {
+        return;
+    };
+
+    // How far scrolling the inner scroll boxes moves the target.
+    let mut shift = Vec2::ZERO;
+    for content_entity in parent_query
+        .iter_ancestors(trigger.target)
+        .filter(|entity| content_query.contains(*entity))
+    {
+        let Ok(content_layout) = layout_query.get(content_entity) else {
+            continue;
+        };
+        // The content is wrapped by a clip that is the visible area.
+        let Some(view_layout) = parent_query
+            .get(content_entity)
+            .ok()
+            .and_then(|child_of| layout_query.get(child_of.parent()).ok())
+        else {
+            continue;
+        };
+        let Some(scroll_box) = parent_query
+            .iter_ancestors(content_entity)
+            .find_map(|entity| scroll_box_query.get(entity).ok())
+        else {
+            continue;
+        };
+        let Some(mut context) = hook_helper
+            .get_context::<ScrollContext>(CurrentWidget(content_entity))
+            .and_then(|context_entity| context_query.get_mut(context_entity).ok())
+        else {
+            continue;
+        };
+
+        let start = target_layout.location + shift - content_layout.location;
+        let size = target_layout.size;
+        let spring = trigger
+            .animated
+            .then(|| scroll_box.spring.unwrap_or(Spring::DEFAULT));
+        let (scroll_x, scroll_y) = (context.scroll_x(), context.scroll_y());
+
+        if !scroll_box.disable_horizontal {
+            let x = aligned_offset(
+                trigger.align,
+                context.scroll_target_x(),
+                start.x,
+                size.x,
+                view_layout.size.x,
+            );
+            match spring {
+                Some(spring) => context.spring_scroll_x(x, spring),
+                None => context.set_scroll_x(x),
+            }
+        }
+        if !scroll_box.disable_vertical {
+            let y = aligned_offset(
+                trigger.align,
+                context.scroll_target_y(),
+                start.y,
+                size.y,
+                view_layout.size.y,
+            );
+            match spring {
+                Some(spring) => context.spring_scroll_y(y, spring),
+                None => context.set_scroll_y(y),
+            }
+        }
+
+        shift += Vec2::new(
+            context.scroll_target_x() - scroll_x,
+            context.scroll_target_y() - scroll_y,
+        );
+    }
+}
+
+pub(crate) fn scroll_focus_into_view(trigger: On<WidgetFocus>, mut commands: Commands) {
+    commands.trigger(ScrollTo {
+        target: trigger.target,
+        align: ScrollAlign::Nearest,
+        animated: false,
+    });
+}
+
 /// Maps a value from one range to another range
 fn map_range(value: f32, from_range: (f32, f32), to_range: (f32, f32)) -> f32 {
     let from_diff = from_range.1 - from_range.0;
@@ -208,3 +587,67 @@ fn map_range(value: f32, from_range: (f32, f32), to_range: (f32, f32)) -> f32 {
         to_range.0 + (value - from_range.0) * (to_range.1 - to_range.0) / from_diff
     }
 }
+
+#[test]
+fn test_scroll_align() {
+    // The visible area is 100 pixels tall and scrolled 50 pixels down.
+    let offset = |align, start| aligned_offset(align, -50.0, start, 20.0, 100.0);
+    assert_eq!(offset(ScrollAlign::Start, 200.0), -200.0);
+    assert_eq!(offset(ScrollAlign::End, 200.0), -120.0);
+    assert_eq!(offset(ScrollAlign::Center, 200.0), -160.0);
+    // Already visible.
+    assert_eq!(offset(ScrollAlign::Nearest, 60.0), -50.0);
+    // Above and below.
+    assert_eq!(offset(ScrollAlign::Nearest, 10.0), -10.0);
+    assert_eq!(offset(ScrollAlign::Nearest, 140.0), -60.0);
+    // Targets taller than the visible area align to the start.
+    assert_eq!(
+        aligned_offset(ScrollAlign::Nearest, 0.0, 50.0, 300.0, 100.0),
+        -50.0
+    );
+}
+
+#[test]
+fn test_kinetic_scroll() {
+    assert_eq!(rubber_band(-50.0, -200.0, 100.0), -50.0);
+    let pulled = rubber_band(100.0, -200.0, 100.0);
+    assert!(pulled > 0.0 && pulled < 50.0);
+    assert!(rubber_band(-400.0, -200.0, 100.0) > -300.0);
+
+    let mut context = ScrollContext {
+        content_height: 1000.0,
+        scrollbox_height: 100.0,
+        friction: 4.0,
+        momentum: Vec2::new(0.0, -1000.0),
+        ..Default::default()
+    };
+    context.update_kinetic(0.1, false);
+    assert_eq!(context.scroll_y(), -100.0);
+    // Slowing down.
+    assert!(context.momentum.y > -1000.0 && context.momentum.y < 0.0);
+    for _ in 0..100 {
+        context.update_kinetic(0.1, false);
+    }
+    assert!(!context.is_kinetic_scrolling());
+    assert!(context.scroll_y() >= -900.0);
+
+    // Clamped content stops at the edge, bouncing content keeps going and springs back.
+    let flung = |mode| {
+        let mut context = ScrollContext {
+            content_height: 200.0,
+            scrollbox_height: 100.0,
+            friction: 4.0,
+            momentum: Vec2::new(0.0, -5000.0),
+            ..Default::default()
+        }
+        .with_mode(mode);
+        context.update_kinetic(0.1, false);
+        context
+    };
+    let clamped = flung(ScrollMode::Clamped);
+    assert_eq!(clamped.scroll_y(), -100.0);
+    assert!(!clamped.is_spring_scrolling());
+    let bounced = flung(ScrollMode::Bounce);
+    assert!(bounced.scroll_y() < -100.0);
+    assert_eq!(bounced.scroll_target_y(), -100.0);
+}
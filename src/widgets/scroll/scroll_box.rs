@@ -1,9 +1,9 @@
 use crate::{
     layout::system::{WidgetLayout, WidgetPreviousLayout},
-    picking_backend::MouseWheelScroll,
+    picking_backend::{compute_letterboxed_transform, MouseWheelScroll},
     prelude::*,
 };
-use bevy::prelude::*;
+use bevy::{input::mouse::MouseScrollUnit, prelude::*, window::PrimaryWindow};
 // use bevy_mod_picking::{
 //     events::Pointer,
 //     prelude::{ListenerInput, On},
@@ -13,7 +13,9 @@ use bevy::prelude::*;
 use super::ScrollContext;
 
 /// A widget that renders a scrollable "box" of content.
-/// Requires that itself be wrapped by the [`super::ScrollContextProvider`]
+///
+/// Wrap it in a [`super::ScrollContextProvider`] to access its [`ScrollContext`] from outside,
+/// otherwise it provides its own. Nested scroll boxes always scroll independently.
 #[derive(Widget, Component, Reflect, Default, Clone, PartialEq)]
 #[auto_update(render)]
 #[props(ScrollBox, PassedChildren, WidgetLayout)]
@@ -37,8 +39,18 @@ pub struct ScrollBox {
     pub scrollbar_thickness: Option<f32>,
     /// The thickness of the thumb in pixels
     pub thumb_thickness: Option<f32>,
-    /// The step to scroll by when `ScrollUnit::Line`
+    /// The number of pixels to scroll by for each line the mouse wheel scrolls, defaults to 64
     pub scroll_line: Option<f32>,
+    /// If set, wheel scrolling animates to the new offset with this spring
+    pub spring: Option<Spring>,
+    /// Enables kinetic scrolling, content flung with a touch drag keeps moving and slows down
+    /// by this much per second. Higher values stop sooner, 4.0 feels natural.
+    ///
+    /// Content can always be dragged with touch pointers, use [`ScrollMode::Bounce`] to let it
+    /// move past the edges.
+    pub friction: Option<f32>,
+    /// Snaps to descendants with a [`ScrollSnapAlign`] once scrolling ends
+    pub snap: Option<ScrollSnapType>,
     /// The color of the scrollbar thumb
     pub thumb_color: Option<Color>,
     /// The styles of the scrollbar thumb
@@ -49,6 +61,43 @@ pub struct ScrollBox {
     pub track_styles: Option<WoodpeckerStyle>,
 }
 
+/// The scroll offsets after scrolling `delta` pixels from `from`, and the part of `delta`
+/// left over at the edges for a parent to scroll.
+fn wheel_scroll(
+    context: &ScrollContext,
+    from: Vec2,
+    delta: Vec2,
+    disable_horizontal: bool,
+    disable_vertical: bool,
+) -> (Vec2, Vec2) {
+    let mut to = from;
+    if !disable_horizontal {
+        to.x = context.limit_x(from.x - delta.x);
+    }
+    if !disable_vertical {
+        to.y = context.limit_y(from.y + delta.y);
+    }
+    let remaining = Vec2::new(delta.x - (from.x - to.x), delta.y - (to.y - from.y));
+    (to, remaining)
+}
+
+/// Finds or creates the scroll context of a scroll box.
+///
+/// The context of a widget wrapping the box is used, ex: a [`super::ScrollContextProvider`] or a
+/// [`VirtualList`], but never the one of an outer scroll box. `ancestors` are the ancestors of the
+/// box up to the closest scroll box.
+pub(crate) fn scroll_box_context(
+    commands: &mut Commands,
+    hooks: &mut HookHelper,
+    scroll_box: CurrentWidget,
+    ancestors: impl IntoIterator<Item = Entity>,
+) -> Entity {
+    std::iter::once(*scroll_box)
+        .chain(ancestors)
+        .find_map(|entity| hooks.get_own_context::<ScrollContext>(CurrentWidget(entity)))
+        .unwrap_or_else(|| hooks.provide_context(commands, scroll_box, ScrollContext::default()))
+}
+
 pub fn render(
     mut commands: Commands,
     mut context_helper: ResMut<HookHelper>,
@@ -62,14 +111,22 @@ pub fn render(
         &WidgetPreviousLayout,
     )>,
     mut context_query: Query<&mut ScrollContext>,
+    parent_query: Query<&ChildOf>,
+    scroll_box_query: Query<(), With<ScrollBox>>,
 ) {
     let Ok((scroll_box, passed_children, mut children, mut styles, layout, prev_layout)) =
         query.get_mut(**current_widget)
     else {
         return;
     };
-    let context_entity =
-        context_helper.use_context(&mut commands, *current_widget, ScrollContext::default());
+    let context_entity = scroll_box_context(
+        &mut commands,
+        &mut context_helper,
+        *current_widget,
+        parent_query
+            .iter_ancestors(**current_widget)
+            .take_while(|ancestor| !scroll_box_query.contains(*ancestor)),
+    );
 
     let Ok(mut context) = context_query.get_mut(context_entity) else {
         return;
@@ -83,6 +140,8 @@ pub fn render(
     let hide_vertical = scroll_box.hide_vertical;
     let scrollbar_thickness = scroll_box.scrollbar_thickness.unwrap_or(10.0);
     let scroll_line = scroll_box.scroll_line.unwrap_or(64.0);
+    let spring = scroll_box.spring;
+    let friction = scroll_box.friction;
     let thumb_color = scroll_box.thumb_color;
     let thumb_styles = scroll_box.thumb_styles;
     let track_color = scroll_box.track_color;
@@ -174,21 +233,185 @@ pub fn render(
             *current_widget,
             move |mut trigger: On<Pointer<MouseWheelScroll>>,
                   mut context_query: Query<&mut ScrollContext>| {
-                let x = trigger.scroll.x;
-                let y = trigger.scroll.y;
-                trigger.propagate(false);
-                if let Ok(mut context) = context_query.get_mut(context_entity) {
-                    let scroll_x = context.scroll_x();
-                    let scroll_y = context.scroll_y();
-                    if !disable_horizontal {
-                        context.set_scroll_x(scroll_x - x * scroll_line);
+                let Ok(mut context) = context_query.get_mut(context_entity) else {
+                    return;
+                };
+                // Keep scrolling from where we are heading so fast wheel ticks add up.
+                let from = if spring.is_some() {
+                    Vec2::new(context.scroll_target_x(), context.scroll_target_y())
+                } else {
+                    Vec2::new(context.scroll_x(), context.scroll_y())
+                };
+                let (to, remaining) = wheel_scroll(
+                    &context,
+                    from,
+                    trigger.pixels(scroll_line),
+                    disable_horizontal,
+                    disable_vertical,
+                );
+                if remaining == Vec2::ZERO {
+                    trigger.propagate(false);
+                } else {
+                    // Let a parent scroll box have what's left at the edges.
+                    trigger.event_mut().event = MouseWheelScroll {
+                        scroll: remaining,
+                        unit: MouseScrollUnit::Pixel,
+                    };
+                }
+                if to == from {
+                    return;
+                }
+                if let Some(spring) = spring {
+                    if to.x != from.x {
+                        context.spring_scroll_x(to.x, spring);
                     }
-                    if !disable_vertical {
-                        context.set_scroll_y(scroll_y + y * scroll_line);
+                    if to.y != from.y {
+                        context.spring_scroll_y(to.y, spring);
                     }
+                } else {
+                    context.set_scroll_x(to.x);
+                    context.set_scroll_y(to.y);
+                }
+            },
+        )
+        .observe(
+            *current_widget,
+            move |mut trigger: On<Pointer<DragStart>>,
+                  time: Res<Time>,
+                  mut context_query: Query<&mut ScrollContext>| {
+                if !trigger.pointer_id.is_touch() {
+                    return;
+                }
+                trigger.propagate(false);
+                if let Ok(mut context) = context_query.get_mut(context_entity) {
+                    context.start_drag(time.elapsed_secs_f64());
+                }
+            },
+        )
+        .observe(
+            *current_widget,
+            move |mut trigger: On<Pointer<Drag>>,
+                  time: Res<Time>,
+                  window: Single<&Window, With<PrimaryWindow>>,
+                  camera: Query<&Camera, With<WoodpeckerView>>,
+                  mut context_query: Query<&mut ScrollContext>| {
+                if !trigger.pointer_id.is_touch() {
+                    return;
+                }
+                trigger.propagate(false);
+                let Ok(mut context) = context_query.get_mut(context_entity) else {
+                    return;
+                };
+                if !context.is_dragging() {
+                    return;
+                }
+                let Some(target_size) = camera
+                    .iter()
+                    .next()
+                    .and_then(|camera| camera.logical_target_size())
+                else {
+                    return;
+                };
+                // Pointer distances are in window pixels.
+                let (_offset, _size, scale) =
+                    compute_letterboxed_transform(window.size(), target_size);
+                let mut distance = trigger.distance / scale;
+                let mut velocity = trigger.delta / scale / time.delta_secs().max(0.001);
+                if disable_horizontal {
+                    distance.x = 0.0;
+                    velocity.x = 0.0;
+                }
+                if disable_vertical {
+                    distance.y = 0.0;
+                    velocity.y = 0.0;
+                }
+                // The content follows the pointer.
+                let offset = context.start_offset + distance;
+                context.drag_to(offset, velocity, time.elapsed_secs_f64());
+            },
+        )
+        .observe(
+            *current_widget,
+            move |mut trigger: On<Pointer<DragEnd>>,
+                  time: Res<Time>,
+                  mut context_query: Query<&mut ScrollContext>| {
+                if !trigger.pointer_id.is_touch() {
+                    return;
+                }
+                trigger.propagate(false);
+                if let Ok(mut context) = context_query.get_mut(context_entity) {
+                    context.end_drag(friction, time.elapsed_secs_f64());
                 }
             },
         );
 
     children.apply(current_widget.as_parent());
 }
+
+#[test]
+fn test_wheel_scroll() {
+    let context = ScrollContext {
+        content_width: 100.0,
+        content_height: 300.0,
+        scrollbox_width: 100.0,
+        scrollbox_height: 100.0,
+        ..Default::default()
+    };
+    // Scrolling down.
+    assert_eq!(
+        wheel_scroll(&context, Vec2::ZERO, Vec2::new(0.0, -64.0), false, false),
+        (Vec2::new(0.0, -64.0), Vec2::ZERO)
+    );
+    // Stops at the end, the rest is left for the parent.
+    assert_eq!(
+        wheel_scroll(
+            &context,
+            Vec2::new(0.0, -180.0),
+            Vec2::new(0.0, -64.0),
+            false,
+            false
+        ),
+        (Vec2::new(0.0, -200.0), Vec2::new(0.0, -44.0))
+    );
+    // Already at the edge, the parent should scroll instead.
+    assert_eq!(
+        wheel_scroll(&context, Vec2::ZERO, Vec2::new(0.0, 64.0), false, false),
+        (Vec2::ZERO, Vec2::new(0.0, 64.0))
+    );
+    assert_eq!(
+        wheel_scroll(&context, Vec2::ZERO, Vec2::new(64.0, 0.0), false, false),
+        (Vec2::ZERO, Vec2::new(64.0, 0.0))
+    );
+    assert_eq!(
+        wheel_scroll(&context, Vec2::ZERO, Vec2::new(0.0, -64.0), false, true),
+        (Vec2::ZERO, Vec2::new(0.0, -64.0))
+    );
+}
+
+#[test]
+fn test_nested_scroll_contexts() {
+    use bevy::ecs::world::CommandQueue;
+
+    let mut world = World::new();
+    let [provider, outer, inner] = [(); 3].map(|_| world.spawn_empty().id());
+    let mut queue = CommandQueue::default();
+    let mut commands = Commands::new(&mut queue, &world);
+    let mut hooks = HookHelper::default();
+
+    let provided = hooks.provide_context(
+        &mut commands,
+        CurrentWidget(provider),
+        ScrollContext::default(),
+    );
+    // The outer box uses the context of the provider wrapping it.
+    let outer_context =
+        scroll_box_context(&mut commands, &mut hooks, CurrentWidget(outer), [provider]);
+    assert_eq!(outer_context, provided);
+    // The inner box stops looking at the outer box and gets its own context.
+    let inner_context = scroll_box_context(&mut commands, &mut hooks, CurrentWidget(inner), []);
+    assert_ne!(inner_context, provided);
+    assert_eq!(
+        scroll_box_context(&mut commands, &mut hooks, CurrentWidget(inner), []),
+        inner_context
+    );
+}
@@ -0,0 +1,170 @@
+use crate::prelude::*;
+use bevy::prelude::*;
+
+use super::{aligned_offset, ScrollAlign, ScrollContext};
+
+/// How long the scroll offset has to stay still before snapping, in seconds.
+const SNAP_DELAY: f64 = 0.15;
+
+/// Marks a descendant of a [`ScrollBox`] as a snap position, the scroll box snaps so the
+/// widget is aligned with the visible area.
+#[derive(Component, Default, Debug, Reflect, Copy, Clone, PartialEq, Eq)]
+pub enum ScrollSnapAlign {
+    /// Aligns the start of the widget with the start of the visible area
+    #[default]
+    Start,
+    /// Centers the widget in the visible area
+    Center,
+    /// Aligns the end of the widget with the end of the visible area
+    End,
+}
+
+/// How a [`ScrollBox`] snaps to its [`ScrollSnapAlign`] descendants once scrolling ends.
+///
+/// Only the axes the scroll box can scroll along snap.
+#[derive(Debug, Reflect, Copy, Clone, PartialEq)]
+pub enum ScrollSnapType {
+    /// Always comes to rest on a snap position
+    Mandatory,
+    /// Only snaps when it comes to rest within this many pixels of a snap position
+    Proximity(f32),
+}
+
+/// The snap offset closest to `current`, `None` if nothing should snap.
+fn nearest_snap(
+    current: f32,
+    offsets: impl Iterator<Item = f32>,
+    snap_type: ScrollSnapType,
+) -> Option<f32> {
+    let nearest = offsets.min_by(|a, b| (a - current).abs().total_cmp(&(b - current).abs()))?;
+    let distance = (nearest - current).abs();
+    let in_range = match snap_type {
+        ScrollSnapType::Mandatory => true,
+        ScrollSnapType::Proximity(proximity) => distance <= proximity,
+    };
+    // Already there.
+    (in_range && distance > 0.5).then_some(nearest)
+}
+
+pub(crate) fn snap_scroll_boxes(
+    time: Res<Time>,
+    mouse_input: Res<ButtonInput<MouseButton>>,
+    hook_helper: Res<HookHelper>,
+    scroll_box_query: Query<(Entity, &ScrollBox)>,
+    children_query: Query<&Children>,
+    parent_query: Query<&ChildOf>,
+    content_query: Query<(), With<ScrollContent>>,
+    snap_query: Query<&ScrollSnapAlign>,
+    layout_query: Query<&WidgetLayout>,
+    mut context_query: Query<&mut ScrollContext>,
+) {
+    let now = time.elapsed_secs_f64();
+    for (scroll_box_entity, scroll_box) in scroll_box_query.iter() {
+        let Some(snap_type) = scroll_box.snap else {
+            continue;
+        };
+        let Some(mut context) = hook_helper
+            .get_context::<ScrollContext>(CurrentWidget(scroll_box_entity))
+            .and_then(|context_entity| context_query.get_mut(context_entity).ok())
+        else {
+            continue;
+        };
+
+        let offset = Vec2::new(context.scroll_x(), context.scroll_y());
+        if offset != context.settled_offset {
+            context.settled_offset = offset;
+            context.settled_time = now;
+            continue;
+        }
+        if now - context.settled_time < SNAP_DELAY
+            || context.is_dragging()
+            || context.is_kinetic_scrolling()
+            || context.is_spring_scrolling()
+            // A scroll bar might still be held.
+            || mouse_input.pressed(MouseButton::Left)
+        {
+            continue;
+        }
+
+        // The scroll box's own content comes before the content of nested scroll boxes.
+        let Some(content_entity) = children_query
+            .iter_descendants(scroll_box_entity)
+            .find(|entity| content_query.contains(*entity))
+        else {
+            continue;
+        };
+        let (Ok(content_layout), Some(view_layout)) = (
+            layout_query.get(content_entity),
+            parent_query
+                .get(content_entity)
+                .ok()
+                .and_then(|child_of| layout_query.get(child_of.parent()).ok()),
+        ) else {
+            continue;
+        };
+
+        let snap_offsets = children_query
+            .iter_descendants(content_entity)
+            .filter(|entity| {
+                // Skip the snap positions of nested scroll boxes.
+                parent_query
+                    .iter_ancestors(*entity)
+                    .find(|ancestor| content_query.contains(*ancestor))
+                    == Some(content_entity)
+            })
+            .filter_map(|entity| {
+                let align = match snap_query.get(entity).ok()? {
+                    ScrollSnapAlign::Start => ScrollAlign::Start,
+                    ScrollSnapAlign::Center => ScrollAlign::Center,
+                    ScrollSnapAlign::End => ScrollAlign::End,
+                };
+                let layout = layout_query.get(entity).ok()?;
+                let start = layout.location - content_layout.location;
+                Some(Vec2::new(
+                    context.limit_x(aligned_offset(
+                        align,
+                        offset.x,
+                        start.x,
+                        layout.size.x,
+                        view_layout.size.x,
+                    )),
+                    context.limit_y(aligned_offset(
+                        align,
+                        offset.y,
+                        start.y,
+                        layout.size.y,
+                        view_layout.size.y,
+                    )),
+                ))
+            })
+            .collect::<Vec<_>>();
+
+        let spring = scroll_box.spring.unwrap_or(Spring::DEFAULT);
+        if !scroll_box.disable_horizontal {
+            if let Some(x) = nearest_snap(offset.x, snap_offsets.iter().map(|o| o.x), snap_type) {
+                context.spring_scroll_x(x, spring);
+            }
+        }
+        if !scroll_box.disable_vertical {
+            if let Some(y) = nearest_snap(offset.y, snap_offsets.iter().map(|o| o.y), snap_type) {
+                context.spring_scroll_y(y, spring);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_scroll_snap() {
+    let offsets = [0.0, -100.0, -200.0, -300.0];
+    let snap = |current, snap_type| nearest_snap(current, offsets.into_iter(), snap_type);
+    assert_eq!(snap(-140.0, ScrollSnapType::Mandatory), Some(-100.0));
+    assert_eq!(snap(-160.0, ScrollSnapType::Mandatory), Some(-200.0));
+    // Already snapped.
+    assert_eq!(snap(-200.0, ScrollSnapType::Mandatory), None);
+    assert_eq!(snap(-140.0, ScrollSnapType::Proximity(30.0)), None);
+    assert_eq!(snap(-120.0, ScrollSnapType::Proximity(30.0)), Some(-100.0));
+    assert_eq!(
+        nearest_snap(-50.0, std::iter::empty(), ScrollSnapType::Mandatory),
+        None
+    );
+}
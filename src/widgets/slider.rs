@@ -1,30 +1,156 @@
 use crate::prelude::*;
 use bevy::prelude::*;
 
+/// The space between the bar and the tick marks.
+const TICK_GAP: f32 = 6.0;
+
 /// A slider change event.
 #[derive(Reflect, Debug, Clone, PartialEq, Default)]
-
 pub struct SliderChanged {
-    /// The value of the slider
+    /// How far along the slider the value is, from 0.0 at the start to 1.0 at the end
     pub value: f32,
+    /// The value of the slider, between [`Slider::start`] and [`Slider::end`]
+    pub ranged_value: f32,
+}
+
+/// A range slider change event, sent instead of [`SliderChanged`] by sliders with two thumbs.
+#[derive(Reflect, Debug, Clone, PartialEq, Default)]
+pub struct SliderRangeChanged {
+    /// The value of the lower thumb
+    pub low: f32,
+    /// The value of the upper thumb
+    pub high: f32,
+}
+
+/// The direction a slider is laid out in.
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SliderOrientation {
+    /// The start is on the left
+    #[default]
+    Horizontal,
+    /// The start is at the bottom
+    Vertical,
+}
+
+/// One of the thumbs of a slider.
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SliderThumb {
+    /// The only thumb, or the lower thumb of a range slider
+    #[default]
+    Lower,
+    /// The upper thumb of a range slider
+    Upper,
+}
+
+/// A tick mark drawn next to the bar.
+#[derive(Reflect, Debug, Clone, PartialEq, Default)]
+pub struct SliderTick {
+    /// The value the tick mark is drawn at
+    pub value: f32,
+    /// An optional label drawn next to the tick mark
+    pub label: Option<String>,
+}
+
+impl SliderTick {
+    /// A tick mark without a label
+    pub fn new(value: f32) -> Self {
+        Self { value, label: None }
+    }
+
+    /// Adds a label to the tick mark
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
 }
 
 /// Slider state
 #[derive(Component, Debug, Reflect, Clone, Copy, PartialEq, Default)]
 pub struct SliderState {
-    /// The value of the slider
+    /// How far along the slider the value is, from 0.0 at the start to 1.0 at the end
     pub value: f32,
+    /// The value of the slider between [`Slider::start`] and [`Slider::end`], the lower value
+    /// of a range slider
+    pub ranged_value: f32,
+    /// The upper value of a range slider, between [`Slider::start`] and [`Slider::end`]
+    pub upper_value: Option<f32>,
+    /// The thumb moved by dragging and the keyboard
+    pub active: SliderThumb,
+    /// Is the slider focused
+    pub is_focused: bool,
+}
+
+impl SliderState {
+    fn new(slider: &Slider) -> Self {
+        let ranged_value = slider.snap(slider.value);
+        Self {
+            value: slider.fraction(ranged_value),
+            ranged_value,
+            upper_value: slider
+                .upper_value
+                .map(|upper_value| slider.snap(upper_value).max(ranged_value)),
+            ..Default::default()
+        }
+    }
+
+    fn thumb_value(&self, thumb: SliderThumb) -> f32 {
+        match thumb {
+            SliderThumb::Lower => self.ranged_value,
+            SliderThumb::Upper => self.upper_value.unwrap_or(self.ranged_value),
+        }
+    }
+
+    /// Moves a thumb without letting the thumbs of a range slider cross.
+    ///
+    /// Returns true if the value changed.
+    fn set_thumb_value(&mut self, slider: &Slider, thumb: SliderThumb, value: f32) -> bool {
+        let value = match (thumb, self.upper_value) {
+            (SliderThumb::Lower, Some(upper)) => value.min(upper),
+            (SliderThumb::Upper, Some(_)) => value.max(self.ranged_value),
+            _ => value,
+        };
+        if self.thumb_value(thumb) == value {
+            return false;
+        }
+        match (thumb, self.upper_value.as_mut()) {
+            (SliderThumb::Upper, Some(upper)) => *upper = value,
+            _ => {
+                self.ranged_value = value;
+                self.value = slider.fraction(value);
+            }
+        }
+        true
+    }
+
+    /// The thumb closest to a value, the upper thumb wins when both are on the value
+    /// and the value is above them.
+    fn closest_thumb(&self, value: f32) -> SliderThumb {
+        let Some(upper) = self.upper_value else {
+            return SliderThumb::Lower;
+        };
+        if (value - upper).abs() < (value - self.ranged_value).abs()
+            || (upper == self.ranged_value && value > upper)
+        {
+            SliderThumb::Upper
+        } else {
+            SliderThumb::Lower
+        }
+    }
 }
 
 /// A collection of slider styles
 #[derive(Component, Reflect, Clone, PartialEq)]
 pub struct SliderStyles {
     /// The "filled" background styles.
-    fill: WoodpeckerStyle,
-    /// The background styles
-    bar: WoodpeckerStyle,
-    /// The draggable button styles.
-    button: ButtonStyles,
+    pub fill: WoodpeckerStyle,
+    /// The background styles, the width and height are swapped for vertical sliders.
+    pub bar: WoodpeckerStyle,
+    /// The draggable button styles, the focused thumb uses the hovered styles.
+    pub button: ButtonStyles,
+    /// Tick mark styles, the width and height are swapped for vertical sliders.
+    pub tick: WoodpeckerStyle,
+    /// Tick label styles, the width is the space each label is centered in.
+    pub tick_label: WoodpeckerStyle,
 }
 
 impl Default for SliderStyles {
@@ -33,8 +159,6 @@ impl Default for SliderStyles {
             position: WidgetPosition::Absolute,
             width: 20.0.into(),
             height: 20.0.into(),
-            left: (-3.0).into(),
-            top: (-7.0).into(),
             border_radius: Corner::all(10.0),
             ..Default::default()
         };
@@ -65,23 +189,53 @@ impl Default for SliderStyles {
                     ..base_button_styles
                 },
             },
+            tick: WoodpeckerStyle {
+                position: WidgetPosition::Absolute,
+                background_color: colors::BACKGROUND_LIGHT,
+                width: 2.0.into(),
+                height: 6.0.into(),
+                ..Default::default()
+            },
+            tick_label: WoodpeckerStyle {
+                position: WidgetPosition::Absolute,
+                width: 40.0.into(),
+                font_size: 12.0,
+                color: Color::WHITE,
+                text_wrap: TextWrap::None,
+                text_alignment: Some(TextAlign::Center),
+                ..Default::default()
+            },
         }
     }
 }
 
 /// A slider widget for numerical values.
+///
+/// While focused the arrow keys move the active thumb by one step, PageUp and PageDown by
+/// ten steps and Home and End move it to the start or end. Clicking the bar moves the
+/// closest thumb to the pointer.
 #[derive(Widget, Component, Reflect, Clone, PartialEq)]
 #[auto_update(render)]
 #[props(Slider, SliderStyles)]
 #[state(SliderState)]
-#[require(SliderStyles, WidgetChildren, WoodpeckerStyle, WidgetRender = WidgetRender::Quad, Pickable)]
+#[require(SliderStyles, WidgetChildren, WoodpeckerStyle, WidgetRender = WidgetRender::Quad, Pickable, Focusable)]
 pub struct Slider {
     /// Start value
     pub start: f32,
     /// End value
     pub end: f32,
-    /// Initial Value
+    /// Initial Value, the initial lower value of a range slider
     pub value: f32,
+    /// The initial upper value, setting this turns the slider into a range slider
+    /// with two thumbs that sends [`SliderRangeChanged`].
+    pub upper_value: Option<f32>,
+    /// Values snap to multiples of the step counted from the start, when set.
+    /// Without a step the keyboard moves the value by 1% of the range.
+    pub step: Option<f32>,
+    /// The direction the slider is laid out in
+    pub orientation: SliderOrientation,
+    /// Tick marks drawn next to the bar
+    pub ticks: Vec<SliderTick>,
 }
 
 impl Default for Slider {
@@ -90,10 +244,121 @@ impl Default for Slider {
             start: 0.0,
             end: 1.0,
             value: 0.0,
+            upper_value: None,
+            step: None,
+            orientation: SliderOrientation::Horizontal,
+            ticks: vec![],
         }
     }
 }
 
+impl Slider {
+    /// Clamps a value between the start and end and snaps it to the step.
+    pub fn snap(&self, value: f32) -> f32 {
+        let (min, max) = (self.start.min(self.end), self.start.max(self.end));
+        let value = match self.step {
+            Some(step) if step > 0.0 => self.start + ((value - self.start) / step).round() * step,
+            _ => value,
+        };
+        value.clamp(min, max)
+    }
+
+    /// How far along the slider a value is, from 0.0 at the start to 1.0 at the end.
+    pub fn fraction(&self, value: f32) -> f32 {
+        if self.end == self.start {
+            return 0.0;
+        }
+        ((value - self.start) / (self.end - self.start)).clamp(0.0, 1.0)
+    }
+
+    /// The value under a position in screen space.
+    fn value_at(&self, layout: &WidgetLayout, position: Vec2) -> f32 {
+        let fraction = match self.orientation {
+            SliderOrientation::Horizontal => (position.x - layout.location.x) / layout.size.x,
+            SliderOrientation::Vertical => 1.0 - (position.y - layout.location.y) / layout.size.y,
+        };
+        let fraction = if fraction.is_finite() {
+            fraction.clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        self.snap(self.start + fraction * (self.end - self.start))
+    }
+
+    /// The value a key press moves a thumb to.
+    fn key_value(&self, value: f32, code: KeyCode) -> Option<f32> {
+        let step = self
+            .step
+            .filter(|step| *step > 0.0)
+            .unwrap_or((self.end - self.start).abs() / 100.0)
+            * (self.end - self.start).signum();
+        let value = match code {
+            KeyCode::ArrowRight | KeyCode::ArrowUp => value + step,
+            KeyCode::ArrowLeft | KeyCode::ArrowDown => value - step,
+            KeyCode::PageUp => value + step * 10.0,
+            KeyCode::PageDown => value - step * 10.0,
+            KeyCode::Home => self.start,
+            KeyCode::End => self.end,
+            _ => return None,
+        };
+        Some(self.snap(value))
+    }
+
+    fn changed(&self, commands: &mut Commands, slider_entity: Entity, state: &SliderState) {
+        if let Some(upper_value) = state.upper_value {
+            commands.trigger(Change {
+                target: slider_entity,
+                data: SliderRangeChanged {
+                    low: state.ranged_value,
+                    high: upper_value,
+                },
+            });
+        } else {
+            commands.trigger(Change {
+                target: slider_entity,
+                data: SliderChanged {
+                    value: state.value,
+                    ranged_value: state.ranged_value,
+                },
+            });
+        }
+    }
+}
+
+/// Places an absolutely positioned child centered on a point along the bar, `cross` is
+/// its position across the bar.
+fn place(
+    orientation: SliderOrientation,
+    styles: WoodpeckerStyle,
+    along: f32,
+    cross: f32,
+) -> WoodpeckerStyle {
+    match orientation {
+        SliderOrientation::Horizontal => WoodpeckerStyle {
+            left: (along - styles.width.value_or(0.0) / 2.0).into(),
+            top: cross.into(),
+            ..styles
+        },
+        SliderOrientation::Vertical => WoodpeckerStyle {
+            left: cross.into(),
+            top: (along - styles.height.value_or(0.0) / 2.0).into(),
+            ..styles
+        },
+    }
+}
+
+/// Swaps the width and height of vertical slider parts.
+fn orient(orientation: SliderOrientation, styles: WoodpeckerStyle) -> WoodpeckerStyle {
+    match orientation {
+        SliderOrientation::Horizontal => styles,
+        SliderOrientation::Vertical => WoodpeckerStyle {
+            width: styles.height,
+            height: styles.width,
+            ..styles
+        },
+    }
+}
+
 fn render(
     mut commands: Commands,
     current_widget: Res<CurrentWidget>,
@@ -113,93 +378,263 @@ fn render(
         return;
     };
 
-    let default_state = SliderState {
-        value: slider.value,
-    };
+    let default_state = SliderState::new(slider);
     let state_entity = hooks.use_state(&mut commands, *current_widget, default_state);
 
     let state = state_query.get(state_entity).unwrap_or(&default_state);
 
-    let slider_left = (widget_layout.size.x * state.value) - 3.0;
+    let orientation = slider.orientation;
+    *styles = orient(orientation, slider_styles.bar);
 
-    *styles = slider_styles.bar;
+    let length = match orientation {
+        SliderOrientation::Horizontal => widget_layout.size.x,
+        SliderOrientation::Vertical => widget_layout.size.y,
+    };
+    let thickness = slider_styles.bar.height.value_or(0.0);
+    // The distance from the start of the layout to a value, vertical sliders start at the bottom.
+    let along = |value: f32| match orientation {
+        SliderOrientation::Horizontal => slider.fraction(value) * length,
+        SliderOrientation::Vertical => (1.0 - slider.fraction(value)) * length,
+    };
 
+    let slider_entity = **current_widget;
     let current_widget = *current_widget;
-    *children = WidgetChildren::default().with_observe(
-        current_widget,
-        move |trigger: On<Pointer<Click>>,
-              mut commands: Commands,
-              layout_query: Query<&WidgetLayout>,
-              mut state_query: Query<&mut SliderState>| {
-            let Ok(mut state) = state_query.get_mut(state_entity) else {
-                return;
-            };
-            let Ok(widget_layout) = layout_query.get(*current_widget) else {
-                return;
-            };
-
-            state.value = (trigger.pointer_location.position.x - widget_layout.location.x)
-                / widget_layout.size.x;
-            state.value = state.value.clamp(0.0, 1.0);
-
-            commands.trigger(
-                Change {
-                    target: *current_widget,
-                    data: SliderChanged { value: state.value },
-                },
-                //*current_widget,
-            );
-        },
-    );
-
-    children.add::<Element>((
-        Element,
-        WoodpeckerStyle {
-            width: (slider_left + 10.0).into(),
-            ..slider_styles.fill
-        },
-        WidgetRender::Quad,
-    ));
-
-    children
-        .add::<WButton>((
-            WButton,
-            ButtonStyles {
-                normal: WoodpeckerStyle {
-                    left: slider_left.into(),
-                    ..slider_styles.button.normal
-                },
-                hovered: WoodpeckerStyle {
-                    left: slider_left.into(),
-                    ..slider_styles.button.hovered
-                },
+    *children = WidgetChildren::default()
+        .with_observe(
+            current_widget,
+            move |trigger: On<Pointer<Press>>,
+                  mut commands: Commands,
+                  layout_query: Query<&WidgetLayout>,
+                  slider_query: Query<&Slider>,
+                  mut state_query: Query<&mut SliderState>| {
+                let Ok(mut state) = state_query.get_mut(state_entity) else {
+                    return;
+                };
+                let (Ok(slider), Ok(widget_layout)) = (
+                    slider_query.get(slider_entity),
+                    layout_query.get(slider_entity),
+                ) else {
+                    return;
+                };
+                let value = slider.value_at(widget_layout, trigger.pointer_location.position);
+                state.active = state.closest_thumb(value);
+                let active = state.active;
+                if state.set_thumb_value(slider, active, value) {
+                    slider.changed(&mut commands, slider_entity, &state);
+                }
             },
-        ))
-        .observe(
+        )
+        .with_observe(
             current_widget,
             move |trigger: On<Pointer<Drag>>,
                   mut commands: Commands,
                   layout_query: Query<&WidgetLayout>,
+                  slider_query: Query<&Slider>,
                   mut state_query: Query<&mut SliderState>| {
                 let Ok(mut state) = state_query.get_mut(state_entity) else {
                     return;
                 };
-                let Ok(widget_layout) = layout_query.get(*current_widget) else {
+                let (Ok(slider), Ok(widget_layout)) = (
+                    slider_query.get(slider_entity),
+                    layout_query.get(slider_entity),
+                ) else {
                     return;
                 };
-                state.value = (trigger.pointer_location.position.x - widget_layout.location.x)
-                    / widget_layout.size.x;
-                state.value = state.value.clamp(0.0, 1.0);
-
-                commands.trigger(
-                    Change {
-                        target: *current_widget,
-                        data: SliderChanged { value: state.value },
-                    },
-                    //*current_widget,
-                );
+                let value = slider.value_at(widget_layout, trigger.pointer_location.position);
+                let active = state.active;
+                if state.set_thumb_value(slider, active, value) {
+                    slider.changed(&mut commands, slider_entity, &state);
+                }
+            },
+        )
+        .with_observe(
+            current_widget,
+            move |trigger: On<WidgetKeyboardButtonEvent>,
+                  mut commands: Commands,
+                  slider_query: Query<&Slider>,
+                  mut state_query: Query<&mut SliderState>| {
+                let Ok(mut state) = state_query.get_mut(state_entity) else {
+                    return;
+                };
+                let Ok(slider) = slider_query.get(slider_entity) else {
+                    return;
+                };
+                let active = state.active;
+                let Some(value) = slider.key_value(state.thumb_value(active), trigger.code) else {
+                    return;
+                };
+                if state.set_thumb_value(slider, active, value) {
+                    slider.changed(&mut commands, slider_entity, &state);
+                }
+            },
+        )
+        .with_observe(
+            current_widget,
+            move |_trigger: On<WidgetFocus>, mut state_query: Query<&mut SliderState>| {
+                if let Ok(mut state) = state_query.get_mut(state_entity) {
+                    state.is_focused = true;
+                }
+            },
+        )
+        .with_observe(
+            current_widget,
+            move |_trigger: On<WidgetBlur>, mut state_query: Query<&mut SliderState>| {
+                if let Ok(mut state) = state_query.get_mut(state_entity) {
+                    state.is_focused = false;
+                }
+            },
+        )
+        // Pressing a thumb blurs the slider, so focus it again once the pointer is released.
+        .with_observe(
+            current_widget,
+            move |_trigger: On<Pointer<Click>>,
+                  mut commands: Commands,
+                  mut current_focus: ResMut<CurrentFocus>| {
+                current_focus.refocus(&mut commands, slider_entity);
+            },
+        )
+        .with_observe(
+            current_widget,
+            move |_trigger: On<Pointer<DragEnd>>,
+                  mut commands: Commands,
+                  mut current_focus: ResMut<CurrentFocus>| {
+                current_focus.refocus(&mut commands, slider_entity);
             },
         );
 
+    for tick in slider.ticks.iter() {
+        let tick_styles = orient(orientation, slider_styles.tick);
+        let tick_cross = match orientation {
+            SliderOrientation::Horizontal => tick_styles.height.value_or(0.0),
+            SliderOrientation::Vertical => tick_styles.width.value_or(0.0),
+        };
+        children.add::<Element>((
+            Element,
+            place(
+                orientation,
+                tick_styles,
+                along(tick.value),
+                thickness + TICK_GAP,
+            ),
+            WidgetRender::Quad,
+        ));
+        if let Some(label) = tick.label.clone() {
+            let label_cross = thickness + TICK_GAP * 2.0 + tick_cross;
+            let label_styles = match orientation {
+                SliderOrientation::Horizontal => place(
+                    orientation,
+                    slider_styles.tick_label,
+                    along(tick.value),
+                    label_cross,
+                ),
+                SliderOrientation::Vertical => WoodpeckerStyle {
+                    left: label_cross.into(),
+                    top: (along(tick.value) - slider_styles.tick_label.font_size * 0.6).into(),
+                    text_alignment: Some(TextAlign::Left),
+                    ..slider_styles.tick_label
+                },
+            };
+            children.add::<Element>((Element, label_styles, WidgetRender::Text { content: label }));
+        }
+    }
+
+    let fill_styles = orient(orientation, slider_styles.fill);
+    let (fill_from, fill_to) = match state.upper_value {
+        Some(upper_value) => (along(state.ranged_value), along(upper_value)),
+        None => (along(slider.start), along(state.ranged_value)),
+    };
+    children.add::<Element>((
+        Element,
+        match orientation {
+            SliderOrientation::Horizontal => WoodpeckerStyle {
+                left: fill_from.into(),
+                width: (fill_to - fill_from).max(0.0).into(),
+                ..fill_styles
+            },
+            SliderOrientation::Vertical => WoodpeckerStyle {
+                top: fill_to.into(),
+                height: (fill_from - fill_to).max(0.0).into(),
+                ..fill_styles
+            },
+        },
+        WidgetRender::Quad,
+    ));
+
+    let mut thumbs = vec![(SliderThumb::Lower, state.ranged_value)];
+    if let Some(upper_value) = state.upper_value {
+        thumbs.push((SliderThumb::Upper, upper_value));
+    }
+    for (thumb, value) in thumbs {
+        let button_styles = orient(orientation, slider_styles.button.normal);
+        let thumb_cross = match orientation {
+            SliderOrientation::Horizontal => button_styles.height.value_or(0.0),
+            SliderOrientation::Vertical => button_styles.width.value_or(0.0),
+        };
+        let cross = (thickness - thumb_cross) / 2.0;
+        let hovered = place(
+            orientation,
+            orient(orientation, slider_styles.button.hovered),
+            along(value),
+            cross,
+        );
+        children
+            .add::<WButton>((
+                WButton,
+                ButtonStyles {
+                    normal: if state.is_focused && state.active == thumb {
+                        hovered
+                    } else {
+                        place(orientation, button_styles, along(value), cross)
+                    },
+                    hovered,
+                },
+            ))
+            .observe(
+                current_widget,
+                move |mut trigger: On<Pointer<Press>>, mut state_query: Query<&mut SliderState>| {
+                    // Grabbing a thumb doesn't move it.
+                    trigger.propagate(false);
+                    if let Ok(mut state) = state_query.get_mut(state_entity) {
+                        state.active = thumb;
+                    }
+                },
+            );
+    }
+
     children.apply(current_widget.as_parent());
 }
+
+#[test]
+fn test_slider_values() {
+    let slider = Slider {
+        start: 0.0,
+        end: 100.0,
+        step: Some(5.0),
+        ..Default::default()
+    };
+    assert_eq!(slider.snap(12.0), 10.0);
+    assert_eq!(slider.snap(13.0), 15.0);
+    assert_eq!(slider.snap(120.0), 100.0);
+    assert_eq!(slider.key_value(10.0, KeyCode::ArrowRight), Some(15.0));
+    assert_eq!(slider.key_value(10.0, KeyCode::ArrowDown), Some(5.0));
+    assert_eq!(slider.key_value(10.0, KeyCode::PageUp), Some(60.0));
+    assert_eq!(slider.key_value(10.0, KeyCode::PageDown), Some(0.0));
+    assert_eq!(slider.key_value(10.0, KeyCode::End), Some(100.0));
+    assert_eq!(slider.key_value(10.0, KeyCode::KeyA), None);
+
+    let mut state = SliderState::new(&Slider {
+        value: 20.0,
+        upper_value: Some(60.0),
+        ..slider.clone()
+    });
+    assert_eq!(state.value, 0.2);
+    assert_eq!(state.closest_thumb(30.0), SliderThumb::Lower);
+    assert_eq!(state.closest_thumb(50.0), SliderThumb::Upper);
+    // The thumbs can't cross.
+    assert!(state.set_thumb_value(&slider, SliderThumb::Lower, 80.0));
+    assert_eq!(state.ranged_value, 60.0);
+    assert_eq!(state.value, 0.6);
+    assert!(!state.set_thumb_value(&slider, SliderThumb::Upper, 40.0));
+    assert_eq!(state.closest_thumb(70.0), SliderThumb::Upper);
+    assert_eq!(state.closest_thumb(50.0), SliderThumb::Lower);
+}
@@ -0,0 +1,510 @@
+use bevy::prelude::*;
+
+use crate::prelude::{Corner, Edge, PreviousWidget, Units, WoodpeckerStyle};
+
+use super::{ScrollContext, TransitionProperty};
+
+/// When enabled, spring driven animations jump straight to their targets.
+///
+/// Set this from the user's reduced motion accessibility preference.
+#[derive(Resource, Debug, Default, Reflect, Clone, Copy, PartialEq, Eq)]
+pub struct ReducedMotion(pub bool);
+
+/// The physical parameters of a damped spring.
+///
+/// Unlike a [`Transition`](super::Transition) a spring has no fixed duration, it settles
+/// when it runs out of energy. Retargeting a moving spring keeps its velocity so
+/// animations that get interrupted stay smooth.
+#[derive(Debug, Reflect, Clone, Copy, PartialEq)]
+pub struct Spring {
+    /// How strongly the spring pulls toward its target.
+    pub stiffness: f32,
+    /// How quickly the spring loses energy.
+    pub damping: f32,
+    /// The mass at the end of the spring, heavier springs are slower.
+    pub mass: f32,
+}
+
+impl Default for Spring {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl Spring {
+    /// A balanced spring with very little overshoot.
+    pub const DEFAULT: Spring = Spring::new(170.0, 26.0);
+    /// A soft spring.
+    pub const GENTLE: Spring = Spring::new(120.0, 14.0);
+    /// A bouncy spring.
+    pub const WOBBLY: Spring = Spring::new(180.0, 12.0);
+    /// A fast spring.
+    pub const STIFF: Spring = Spring::new(210.0, 20.0);
+    /// A slow, heavily damped spring.
+    pub const SLOW: Spring = Spring::new(280.0, 60.0);
+
+    const REST_DISTANCE: f32 = 0.001;
+    const REST_VELOCITY: f32 = 0.01;
+    const MAX_STEP: f32 = 1.0 / 240.0;
+
+    /// Creates a new spring with a mass of 1.
+    pub const fn new(stiffness: f32, damping: f32) -> Self {
+        Self {
+            stiffness,
+            damping,
+            mass: 1.0,
+        }
+    }
+
+    /// Sets the mass of the spring.
+    pub const fn with_mass(mut self, mass: f32) -> Self {
+        self.mass = mass;
+        self
+    }
+
+    /// Moves `position` toward `target` over `delta` seconds.
+    ///
+    /// Returns the new position and velocity.
+    pub fn step(
+        &self,
+        mut position: f32,
+        mut velocity: f32,
+        target: f32,
+        delta: f32,
+    ) -> (f32, f32) {
+        if !delta.is_finite() {
+            return (target, 0.0);
+        }
+        let mass = self.mass.max(f32::EPSILON);
+        // Fixed sub steps keep stiff springs stable on slow frames.
+        let mut remaining = delta;
+        while remaining > 0.0 {
+            let dt = remaining.min(Self::MAX_STEP);
+            remaining -= dt;
+            let force = -self.stiffness * (position - target) - self.damping * velocity;
+            velocity += force / mass * dt;
+            position += velocity * dt;
+        }
+        (position, velocity)
+    }
+
+    /// Has the spring settled at the target?
+    pub fn is_at_rest(position: f32, velocity: f32, target: f32) -> bool {
+        (position - target).abs() < Self::REST_DISTANCE && velocity.abs() < Self::REST_VELOCITY
+    }
+}
+
+/// A style value a spring can drive.
+#[derive(Debug, Clone, PartialEq)]
+enum Field {
+    Color(Color),
+    Number(f32),
+    OptionalNumber(Option<f32>),
+    Units(Vec<Units>),
+}
+
+impl Field {
+    fn get(property: TransitionProperty, style: &WoodpeckerStyle) -> Option<Self> {
+        let edge = |edge: Edge| Self::Units(vec![edge.top, edge.right, edge.bottom, edge.left]);
+        Some(match property {
+            TransitionProperty::All => return None,
+            TransitionProperty::BackgroundColor => Self::Color(style.background_color),
+            TransitionProperty::BorderColor => Self::Color(style.border_color),
+            TransitionProperty::Color => Self::Color(style.color),
+            TransitionProperty::FontSize => Self::Number(style.font_size),
+            TransitionProperty::Opacity => Self::Number(style.opacity),
+            TransitionProperty::FlexGrow => Self::Number(style.flex_grow),
+            TransitionProperty::FlexShrink => Self::Number(style.flex_shrink),
+            TransitionProperty::LineHeight => Self::OptionalNumber(style.line_height),
+            TransitionProperty::Width => Self::Units(vec![style.width]),
+            TransitionProperty::Height => Self::Units(vec![style.height]),
+            TransitionProperty::MinWidth => Self::Units(vec![style.min_width]),
+            TransitionProperty::MinHeight => Self::Units(vec![style.min_height]),
+            TransitionProperty::MaxWidth => Self::Units(vec![style.max_width]),
+            TransitionProperty::MaxHeight => Self::Units(vec![style.max_height]),
+            TransitionProperty::Left => Self::Units(vec![style.left]),
+            TransitionProperty::Right => Self::Units(vec![style.right]),
+            TransitionProperty::Top => Self::Units(vec![style.top]),
+            TransitionProperty::Bottom => Self::Units(vec![style.bottom]),
+            TransitionProperty::FlexBasis => Self::Units(vec![style.flex_basis]),
+            TransitionProperty::Gap => Self::Units(vec![style.gap.0, style.gap.1]),
+            TransitionProperty::Padding => edge(style.padding),
+            TransitionProperty::Margin => edge(style.margin),
+            TransitionProperty::Border => edge(style.border),
+            TransitionProperty::BorderRadius => Self::Units(vec![
+                style.border_radius.top_left,
+                style.border_radius.top_right,
+                style.border_radius.bottom_left,
+                style.border_radius.bottom_right,
+            ]),
+        })
+    }
+
+    fn set(&self, property: TransitionProperty, style: &mut WoodpeckerStyle) {
+        match (property, self) {
+            (TransitionProperty::BackgroundColor, Self::Color(color)) => {
+                style.background_color = *color
+            }
+            (TransitionProperty::BorderColor, Self::Color(color)) => style.border_color = *color,
+            (TransitionProperty::Color, Self::Color(color)) => style.color = *color,
+            (TransitionProperty::FontSize, Self::Number(value)) => style.font_size = *value,
+            (TransitionProperty::Opacity, Self::Number(value)) => style.opacity = *value,
+            (TransitionProperty::FlexGrow, Self::Number(value)) => style.flex_grow = *value,
+            (TransitionProperty::FlexShrink, Self::Number(value)) => style.flex_shrink = *value,
+            (TransitionProperty::LineHeight, Self::OptionalNumber(value)) => {
+                style.line_height = *value
+            }
+            (property, Self::Units(units)) => {
+                let edge = || Edge::new(units[0], units[1], units[2], units[3]);
+                match property {
+                    TransitionProperty::Width => style.width = units[0],
+                    TransitionProperty::Height => style.height = units[0],
+                    TransitionProperty::MinWidth => style.min_width = units[0],
+                    TransitionProperty::MinHeight => style.min_height = units[0],
+                    TransitionProperty::MaxWidth => style.max_width = units[0],
+                    TransitionProperty::MaxHeight => style.max_height = units[0],
+                    TransitionProperty::Left => style.left = units[0],
+                    TransitionProperty::Right => style.right = units[0],
+                    TransitionProperty::Top => style.top = units[0],
+                    TransitionProperty::Bottom => style.bottom = units[0],
+                    TransitionProperty::FlexBasis => style.flex_basis = units[0],
+                    TransitionProperty::Gap => style.gap = (units[0], units[1]),
+                    TransitionProperty::Padding => style.padding = edge(),
+                    TransitionProperty::Margin => style.margin = edge(),
+                    TransitionProperty::Border => style.border = edge(),
+                    TransitionProperty::BorderRadius => {
+                        style.border_radius = Corner::new(units[0], units[1], units[2], units[3])
+                    }
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// The numeric values the spring moves, `None` if the value can't be animated.
+    fn values(&self) -> Option<Vec<f32>> {
+        match self {
+            Self::Color(color) => {
+                let color = color.to_srgba();
+                Some(vec![color.red, color.green, color.blue, color.alpha])
+            }
+            Self::Number(value) => Some(vec![*value]),
+            Self::OptionalNumber(value) => value.map(|value| vec![value]),
+            Self::Units(units) => units
+                .iter()
+                .map(|units| match units {
+                    Units::Pixels(value) | Units::Percentage(value) => Some(*value),
+                    Units::Auto => None,
+                })
+                .collect(),
+        }
+    }
+
+    /// Can a spring move between the two values?
+    fn compatible(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Color(_), Self::Color(_)) | (Self::Number(_), Self::Number(_)) => true,
+            (Self::OptionalNumber(a), Self::OptionalNumber(b)) => a.is_some() && b.is_some(),
+            (Self::Units(a), Self::Units(b)) => a.iter().zip(b).all(|(a, b)| {
+                matches!(
+                    (a, b),
+                    (Units::Pixels(_), Units::Pixels(_))
+                        | (Units::Percentage(_), Units::Percentage(_))
+                )
+            }),
+            _ => false,
+        }
+    }
+
+    /// A value of the same kind with new numeric values.
+    fn with_values(&self, values: &[f32]) -> Self {
+        match self {
+            Self::Color(_) => Self::Color(
+                Srgba::new(
+                    values[0].clamp(0.0, 1.0),
+                    values[1].clamp(0.0, 1.0),
+                    values[2].clamp(0.0, 1.0),
+                    values[3].clamp(0.0, 1.0),
+                )
+                .into(),
+            ),
+            Self::Number(_) => Self::Number(values[0]),
+            Self::OptionalNumber(_) => Self::OptionalNumber(Some(values[0])),
+            Self::Units(units) => Self::Units(
+                units
+                    .iter()
+                    .zip(values)
+                    .map(|(units, value)| match units {
+                        Units::Pixels(_) => Units::Pixels(*value),
+                        Units::Percentage(_) => Units::Percentage(*value),
+                        Units::Auto => Units::Auto,
+                    })
+                    .collect(),
+            ),
+        }
+    }
+}
+
+/// Describes which spring drives a single property.
+#[derive(Debug, Copy, Reflect, Clone, PartialEq)]
+pub struct PropertySpring {
+    /// The property to animate.
+    pub property: TransitionProperty,
+    /// The spring driving it.
+    pub spring: Spring,
+}
+
+impl From<(TransitionProperty, Spring)> for PropertySpring {
+    fn from((property, spring): (TransitionProperty, Spring)) -> Self {
+        Self { property, spring }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct ActiveSpring {
+    property: TransitionProperty,
+    from: Field,
+    position: Vec<f32>,
+    velocity: Vec<f32>,
+}
+
+/// Spring driven implicit animations, an alternative to [`StyleTransitions`](super::StyleTransitions).
+///
+/// Whenever one of the listed properties of the widget's [`WoodpeckerStyle`] changes
+/// it springs from the value currently on screen to the new value. Retargeting a
+/// moving property keeps its velocity.
+///
+/// Only colors, numbers and matching pixel or percentage units are animated,
+/// anything else jumps to the new value.
+///
+/// ```ignore
+/// StyleSprings::new([
+///     (TransitionProperty::Width, Spring::WOBBLY),
+///     (TransitionProperty::Opacity, Spring::STIFF),
+/// ])
+/// ```
+#[derive(Component, Debug, Default, Reflect, Clone, PartialEq)]
+pub struct StyleSprings {
+    /// The animated properties, later entries override earlier ones.
+    pub properties: Vec<PropertySpring>,
+}
+
+fn spring_for(properties: &[PropertySpring], property: TransitionProperty) -> Option<Spring> {
+    properties
+        .iter()
+        .rev()
+        .find(|p| p.property == property || p.property == TransitionProperty::All)
+        .map(|p| p.spring)
+}
+
+impl StyleSprings {
+    /// Creates a new set of spring animations.
+    pub fn new(properties: impl IntoIterator<Item = impl Into<PropertySpring>>) -> Self {
+        Self {
+            properties: properties.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// The moving springs of [`StyleSprings`], with their positions and velocities.
+///
+/// Kept apart from the [`StyleSprings`] props so a parent re-rendering and re-inserting
+/// them doesn't stop the springs.
+#[derive(Component, Debug, Clone, PartialEq)]
+pub(crate) struct StyleSpringState {
+    target: WoodpeckerStyle,
+    displayed: WoodpeckerStyle,
+    active: Vec<ActiveSpring>,
+}
+
+impl StyleSpringState {
+    /// Starts at rest on the current style.
+    fn new(style: WoodpeckerStyle) -> Self {
+        Self {
+            target: style,
+            displayed: style,
+            active: vec![],
+        }
+    }
+
+    /// Is anything currently animating?
+    fn is_animating(&self) -> bool {
+        !self.active.is_empty()
+    }
+
+    /// Takes the style written by the widget and returns the style to display,
+    /// moving springs forward by `delta` seconds.
+    fn update(
+        &mut self,
+        properties: &[PropertySpring],
+        style: WoodpeckerStyle,
+        delta: f32,
+        reduced_motion: bool,
+    ) -> WoodpeckerStyle {
+        if reduced_motion {
+            self.active.clear();
+            self.target = style;
+            self.displayed = style;
+            return style;
+        }
+
+        if style != self.displayed {
+            let (target, displayed) = (self.target, self.displayed);
+            for property in TransitionProperty::PROPERTIES {
+                if spring_for(properties, property).is_none()
+                    || !property.differs(&target, &style)
+                    // Already moving, the spring picks up the new target with its velocity.
+                    || self.active.iter().any(|active| active.property == property)
+                {
+                    continue;
+                }
+                let Some(from) = Field::get(property, &displayed) else {
+                    continue;
+                };
+                let Some(position) = from.values() else {
+                    continue;
+                };
+                self.active.push(ActiveSpring {
+                    property,
+                    from,
+                    velocity: vec![0.0; position.len()],
+                    position,
+                });
+            }
+            self.target = style;
+        }
+
+        let target = self.target;
+        let mut displayed = target;
+        self.active.retain_mut(|active| {
+            let Some(spring) = spring_for(properties, active.property) else {
+                return false;
+            };
+            let Some(goal) = Field::get(active.property, &target) else {
+                return false;
+            };
+            let Some(goal_values) = goal.values().filter(|_| active.from.compatible(&goal)) else {
+                return false;
+            };
+            let mut at_rest = true;
+            for ((position, velocity), goal) in active
+                .position
+                .iter_mut()
+                .zip(active.velocity.iter_mut())
+                .zip(goal_values)
+            {
+                (*position, *velocity) = spring.step(*position, *velocity, goal, delta);
+                at_rest &= Spring::is_at_rest(*position, *velocity, goal);
+            }
+            if at_rest {
+                return false;
+            }
+            goal.with_values(&active.position)
+                .set(active.property, &mut displayed);
+            true
+        });
+        self.displayed = displayed;
+        displayed
+    }
+}
+
+// IMPORTANT: When modifying widget entities we need to verify we aren't modifying previous widget values.
+pub(crate) fn update_style_springs(
+    mut commands: Commands,
+    time: Res<Time>,
+    reduced_motion: Res<ReducedMotion>,
+    mut query: Query<
+        (
+            Entity,
+            &mut WoodpeckerStyle,
+            &StyleSprings,
+            Option<&mut StyleSpringState>,
+        ),
+        Without<PreviousWidget>,
+    >,
+) {
+    let delta = time.delta_secs();
+    for (entity, mut style, springs, state) in query.iter_mut() {
+        let Some(mut state) = state else {
+            commands
+                .entity(entity)
+                .insert(StyleSpringState::new(*style));
+            continue;
+        };
+        if !style.is_changed() && !state.is_animating() {
+            continue;
+        }
+        let displayed = state.update(&springs.properties, *style, delta, reduced_motion.0);
+        style.set_if_neq(displayed);
+    }
+}
+
+// IMPORTANT: When modifying widget entities we need to verify we aren't modifying previous widget values.
+pub(crate) fn update_scroll_springs(
+    time: Res<Time>,
+    reduced_motion: Res<ReducedMotion>,
+    mut query: Query<&mut ScrollContext, Without<PreviousWidget>>,
+) {
+    let delta = time.delta_secs();
+    for mut context in query.iter_mut() {
+        if context.is_kinetic_scrolling() {
+            context.update_kinetic(delta, reduced_motion.0);
+        }
+        if context.is_spring_scrolling() {
+            context.update_spring(delta, reduced_motion.0);
+        }
+    }
+}
+
+#[test]
+fn test_springs() {
+    let spring = Spring::DEFAULT;
+    let (mut position, mut velocity) = (0.0, 0.0);
+    for _ in 0..120 {
+        (position, velocity) = spring.step(position, velocity, 100.0, 1.0 / 60.0);
+    }
+    assert!(Spring::is_at_rest(position, velocity, 100.0));
+
+    let style = |width: f32| WoodpeckerStyle {
+        width: width.into(),
+        height: width.into(),
+        ..Default::default()
+    };
+    let springs = StyleSprings::new([(TransitionProperty::Width, Spring::DEFAULT)]);
+    let properties = &springs.properties;
+    let mut state = StyleSpringState::new(style(0.0));
+    assert_eq!(
+        state.update(properties, style(0.0), 0.016, false),
+        style(0.0)
+    );
+
+    // Width springs, height jumps.
+    let displayed = state.update(properties, style(100.0), 0.1, false);
+    let Units::Pixels(width) = displayed.width else {
+        panic!("Expected pixels");
+    };
+    assert!(width > 0.0 && width < 100.0);
+    assert_eq!(displayed.height, Units::Pixels(100.0));
+
+    // Retargeted mid flight, the velocity carries it further before it turns around.
+    let displayed = state.update(properties, style(0.0), 0.01, false);
+    let Units::Pixels(next_width) = displayed.width else {
+        panic!("Expected pixels");
+    };
+    assert!(next_width > width);
+
+    let mut displayed = displayed;
+    for _ in 0..300 {
+        displayed = state.update(properties, displayed, 1.0 / 60.0, false);
+    }
+    assert_eq!(displayed, style(0.0));
+    assert!(!state.is_animating());
+
+    // Reduced motion jumps straight to the target.
+    assert_eq!(
+        state.update(properties, style(50.0), 0.016, true),
+        style(50.0)
+    );
+    assert!(!state.is_animating());
+}
@@ -1,52 +1,255 @@
+use std::sync::Mutex;
+
 use crate::prelude::{ColorText, Highlighted};
-use autumnus::{themes, FormatterOption, Options};
+use autumnus::{constants::HIGHLIGHT_NAMES, languages::Language, themes};
+use bevy::prelude::{Color, Srgba};
+use tree_sitter_highlight::{HighlightEvent, Highlighter};
 
 /// A function to generate syntax highlighting for parley.
+///
+/// The spans are built from the tree-sitter highlight events of the language,
+/// colored with the foreground colors of the theme.
 pub fn highlight(language: &str, code: &str, theme: &str) -> Highlighted {
-    let ansi = autumnus::highlight(
-        code,
-        Options {
-            lang_or_file: Some(language),
-            formatter: FormatterOption::Terminal {
-                theme: themes::get(theme).ok(),
-            },
-        },
-    );
-    use ansi_parser::{AnsiParser, AnsiSequence, Output};
-    let parsed: Vec<Output> = ansi.ansi_parse().collect();
-
-    let mut highlighted = Highlighted::default();
-    let mut current_color_text = ColorText::default();
-    let mut start = 0;
-    let mut end = 0;
-    for item in parsed {
-        match item {
-            Output::TextBlock(text) => {
-                end += text.len();
-                current_color_text.range = start..end;
-                highlighted.color_text.push(current_color_text);
-                current_color_text = ColorText::default();
-                start = end;
+    let Ok(theme) = themes::get(theme) else {
+        return Highlighted::default();
+    };
+    let mut highlighter = Highlighter::new();
+    let Ok(events) = highlighter.highlight(
+        Language::guess(language, code).config(),
+        code.as_bytes(),
+        None,
+        |injected| Some(Language::guess(injected, "").config()),
+    ) else {
+        return Highlighted::default();
+    };
+
+    let mut color_text: Vec<ColorText> = vec![];
+    // Highlights nest, the innermost one with a color wins.
+    let mut colors: Vec<Option<Color>> = vec![];
+    for event in events {
+        let Ok(event) = event else {
+            break;
+        };
+        match event {
+            HighlightEvent::HighlightStart(highlight) => {
+                let color = HIGHLIGHT_NAMES
+                    .get(highlight.0)
+                    .and_then(|scope| theme.get_style(scope))
+                    .and_then(|style| style.fg.as_deref())
+                    .and_then(|fg| Srgba::hex(fg).ok())
+                    .map(Color::from)
+                    .or_else(|| colors.last().copied().flatten());
+                colors.push(color);
             }
-            Output::Escape(ansi_sequence) => {
-                if let AnsiSequence::SetGraphicsMode(items) = ansi_sequence {
-                    let code = items[0];
-                    if code == 38 {
-                        let code2 = items[1];
-                        if code2 == 2 {
-                            let red = items[2];
-                            let green = items[3];
-                            let blue = items[4];
-                            current_color_text.color =
-                                bevy::prelude::Srgba::rgb_u8(red, green, blue).into();
-                        }
+            HighlightEvent::HighlightEnd => {
+                colors.pop();
+            }
+            HighlightEvent::Source { start, end } => {
+                let Some(color) = colors.last().copied().flatten() else {
+                    continue;
+                };
+                match color_text.last_mut() {
+                    Some(last) if last.color == color && last.range.end == start => {
+                        last.range.end = end;
                     }
+                    _ => color_text.push(ColorText {
+                        color,
+                        range: start..end,
+                    }),
                 }
             }
         }
     }
 
+    Highlighted { color_text }
+}
+
+/// Returns the edited region between two texts as
+/// `(start, old_end, new_end)` byte indices.
+fn changed_region(old: &str, new: &str) -> (usize, usize, usize) {
+    let mut prefix = old
+        .bytes()
+        .zip(new.bytes())
+        .take_while(|(a, b)| a == b)
+        .count();
+    while !old.is_char_boundary(prefix) || !new.is_char_boundary(prefix) {
+        prefix -= 1;
+    }
+
+    let max_suffix = old.len().min(new.len()) - prefix;
+    let mut suffix = old
+        .bytes()
+        .rev()
+        .zip(new.bytes().rev())
+        .take(max_suffix)
+        .take_while(|(a, b)| a == b)
+        .count();
+    while !old.is_char_boundary(old.len() - suffix) || !new.is_char_boundary(new.len() - suffix) {
+        suffix -= 1;
+    }
+
+    (prefix, old.len() - suffix, new.len() - suffix)
+}
+
+/// Keeps the spans before `start`, moves the spans after `old_end` to `new_end`
+/// and inserts `middle` in between.
+fn splice(
+    highlighted: &Highlighted,
+    start: usize,
+    old_end: usize,
+    new_end: usize,
+    middle: Vec<ColorText>,
+) -> Highlighted {
+    let before = highlighted
+        .color_text
+        .iter()
+        .filter(|span| span.range.start < start)
+        .map(|span| ColorText {
+            color: span.color,
+            range: span.range.start..span.range.end.min(start),
+        });
+    let after = highlighted
+        .color_text
+        .iter()
+        .filter(|span| span.range.end > old_end)
+        .map(|span| ColorText {
+            color: span.color,
+            range: span.range.start.max(old_end) - old_end + new_end
+                ..span.range.end - old_end + new_end,
+        });
+
+    Highlighted {
+        color_text: before
+            .chain(middle)
+            .chain(after)
+            .filter(|span| !span.range.is_empty())
+            .collect(),
+    }
+}
+
+/// Moves highlighting computed for `old` onto `new`.
+///
+/// Spans inside the edited region are dropped. Used to display stale
+/// highlighting while new highlighting is computed.
+pub(crate) fn shift_highlighted(highlighted: &Highlighted, old: &str, new: &str) -> Highlighted {
+    let (start, old_end, new_end) = changed_region(old, new);
+    splice(highlighted, start, old_end, new_end, vec![])
+}
+
+/// A block starts after a blank line with an unindented line.
+fn is_block_start(text: &str, index: usize) -> bool {
+    index == 0
+        || index == text.len()
+        || (text[..index].ends_with("\n\n")
+            && text[index..]
+                .chars()
+                .next()
+                .is_some_and(|c| !c.is_whitespace()))
+}
+
+fn block_start(text: &str, index: usize) -> usize {
+    text[..index]
+        .rmatch_indices("\n\n")
+        .map(|(i, _)| i + 2)
+        .find(|i| *i <= index && is_block_start(text, *i))
+        .unwrap_or(0)
+}
+
+fn block_end(text: &str, index: usize) -> usize {
+    text[index..]
+        .match_indices("\n\n")
+        .map(|(i, _)| index + i + 2)
+        .find(|i| is_block_start(text, *i))
+        .unwrap_or(text.len())
+}
+
+/// Does a span continue across `index`?
+fn crosses(highlighted: &Highlighted, index: usize) -> bool {
     highlighted
+        .color_text
+        .iter()
+        .any(|span| span.range.start < index && index < span.range.end)
+}
+
+#[derive(Default)]
+struct HighlightCache {
+    text: String,
+    highlighted: Highlighted,
+}
+
+/// An incremental syntax highlighter.
+///
+/// Caches the last result and only re-highlights the blocks of code that changed.
+/// A block is a run of lines that starts after a blank line with an unindented line,
+/// which covers top level items in most languages.
+///
+/// ```ignore
+/// let highlighter = SyntaxHighlighter::new("rust", "dracula");
+/// let text_highlighting = ApplyHighlighting::background(move |text| Some(highlighter.highlight(text)));
+/// ```
+pub struct SyntaxHighlighter {
+    language: String,
+    theme: String,
+    cache: Mutex<HighlightCache>,
+}
+
+impl SyntaxHighlighter {
+    /// Creates a new syntax highlighter for a language and theme.
+    pub fn new(language: impl Into<String>, theme: impl Into<String>) -> Self {
+        Self {
+            language: language.into(),
+            theme: theme.into(),
+            cache: Mutex::new(HighlightCache::default()),
+        }
+    }
+
+    /// Highlights the code, re-using the cached highlighting for unchanged blocks.
+    ///
+    /// Falls back to highlighting all of the code when the edit is inside a token that
+    /// spans several blocks, like a block comment or a raw string, or leaves one unterminated.
+    pub fn highlight(&self, code: &str) -> Highlighted {
+        let mut cache = self.cache.lock().unwrap();
+        if cache.text == code && !code.is_empty() {
+            return cache.highlighted.clone();
+        }
+
+        let (start, old_end, new_end) = changed_region(&cache.text, code);
+        let start = block_start(code, start);
+        let end = block_end(code, new_end);
+        let old_block_end = old_end + (end - new_end);
+        let middle = (!cache.text.is_empty()
+            && end - start <= code.len() / 2
+            && !crosses(&cache.highlighted, start)
+            && !crosses(&cache.highlighted, old_block_end))
+        .then(|| highlight(&self.language, &code[start..end], &self.theme))
+        // A token running into the blank line that ends the block is unterminated.
+        .filter(|middle| {
+            end == code.len()
+                || middle
+                    .color_text
+                    .iter()
+                    .all(|span| span.range.end < end - start)
+        });
+
+        let highlighted = match middle {
+            Some(middle) => {
+                let middle = middle
+                    .color_text
+                    .into_iter()
+                    .map(|span| ColorText {
+                        color: span.color,
+                        range: span.range.start + start..span.range.end + start,
+                    })
+                    .collect();
+                splice(&cache.highlighted, start, old_block_end, end, middle)
+            }
+            None => highlight(&self.language, code, &self.theme),
+        };
+
+        cache.text = code.to_string();
+        cache.highlighted = highlighted.clone();
+        highlighted
+    }
 }
 
 #[test]
@@ -70,5 +273,52 @@ pub fn highlight(language: &str, code: &str) {
 }
 "#;
 
-    highlight("rust", code_to_highlight, "dracula");
+    let highlighted = highlight("rust", code_to_highlight, "dracula");
+    assert!(!highlighted.color_text.is_empty());
+    assert!(highlighted
+        .color_text
+        .windows(2)
+        .all(|spans| spans[0].range.end <= spans[1].range.start));
+
+    let red: Color = Srgba::rgb_u8(255, 0, 0).into();
+    let spans = Highlighted {
+        color_text: vec![
+            ColorText {
+                color: red,
+                range: 0..2,
+            },
+            ColorText {
+                color: red,
+                range: 7..9,
+            },
+        ],
+    };
+    let shifted = shift_highlighted(&spans, "fn main()", "fn my_main()");
+    assert_eq!(shifted.color_text[0].range, 0..2);
+    assert_eq!(shifted.color_text[1].range, 10..12);
+
+    let highlighter = SyntaxHighlighter::new("rust", "dracula");
+    let edited = code_to_highlight.replace("dbg!(ansi);", "dbg!(&ansi);");
+    highlighter.highlight(code_to_highlight);
+    assert_eq!(
+        highlighter
+            .highlight(&edited)
+            .color_text
+            .last()
+            .map(|span| span.range.end),
+        highlight("rust", &edited, "dracula")
+            .color_text
+            .last()
+            .map(|span| span.range.end)
+    );
+
+    // The edited block is inside a block comment that starts in the block before it.
+    let commented = "fn a() {}\n\n/* start\n\nfn b() {}\nend */\n\nfn c() {}\n\nfn d() {}\n";
+    let highlighter = SyntaxHighlighter::new("rust", "dracula");
+    highlighter.highlight(commented);
+    let edited = commented.replace("fn b()", "fn bb()");
+    assert_eq!(
+        highlighter.highlight(&edited),
+        highlight("rust", &edited, "dracula")
+    );
 }
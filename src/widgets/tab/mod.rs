@@ -1,27 +1,201 @@
+mod tab_bar;
 mod tab_button;
 mod tab_content;
 
 use crate::prelude::*;
 use bevy::prelude::*;
 
+pub use tab_bar::*;
 pub use tab_button::*;
 pub use tab_content::*;
 
+/// A tab change event, triggered on the [`TabContextProvider`] whenever the current tab changes.
+#[derive(Debug, Clone, Reflect)]
+pub struct TabChanged {
+    /// The index of the current tab.
+    pub index: usize,
+    /// The index of the tab that was current before.
+    pub previous: usize,
+}
+
+/// Triggered when a tab is closed with its close button.
+#[derive(Debug, Clone, Reflect)]
+pub struct TabClosed {
+    /// The index of the closed tab.
+    pub index: usize,
+}
+
+/// Triggered when a tab of a [`TabBar`] is dragged to a new position.
+#[derive(Debug, Clone, Reflect)]
+pub struct TabsReordered {
+    /// The indices of the open tabs in the order they are shown.
+    pub tabs: Vec<usize>,
+}
+
+/// Changes the current tab of a [`TabContextProvider`] from outside of it.
+///
+/// Closed tabs and indices without a [`TabButton`] or [`TabContent`] are ignored.
+#[derive(Clone, PartialEq, Debug, Reflect, EntityEvent)]
+pub struct SelectTab {
+    /// The tab context provider
+    #[event_target]
+    pub target: Entity,
+    /// The index of the tab to select
+    pub index: usize,
+}
+
 /// Tab context
 #[derive(Component, Default, Clone, PartialEq, Reflect)]
 pub struct TabContext {
     /// The current tab selected
     current_index: usize,
+    /// The open tabs in the order a [`TabBar`] shows them.
+    tabs: Vec<usize>,
+    /// The tabs that have been closed.
+    closed: Vec<usize>,
+    /// The indices of the tab buttons and contents, sorted.
+    indices: Vec<usize>,
+    /// The current tab the last [`TabChanged`] was sent for.
+    reported_index: usize,
+}
+
+impl TabContext {
+    /// Creates a new tab context with a tab selected.
+    pub fn new(current_index: usize) -> Self {
+        Self {
+            current_index,
+            reported_index: current_index,
+            ..Default::default()
+        }
+    }
+
+    /// The index of the current tab.
+    pub fn current_index(&self) -> usize {
+        self.current_index
+    }
+
+    /// Selects a tab, closed tabs and indices without a tab are ignored.
+    pub fn set_current_index(&mut self, index: usize) {
+        if self.is_open(index) {
+            self.current_index = index;
+        }
+    }
+
+    /// The open tabs in the order a [`TabBar`] shows them, empty without a tab bar.
+    pub fn tabs(&self) -> &[usize] {
+        &self.tabs
+    }
+
+    /// Has the tab been closed?
+    pub fn is_closed(&self, index: usize) -> bool {
+        self.closed.contains(&index)
+    }
+
+    /// Is there a tab with this index that hasn't been closed?
+    pub fn is_open(&self, index: usize) -> bool {
+        !self.is_closed(index)
+            && (self.tabs.contains(&index) || self.indices.binary_search(&index).is_ok())
+    }
+
+    /// Closes a tab, if it's the current tab the next open tab is selected.
+    ///
+    /// Tabs follow the order of the [`TabBar`], or of their indices without one. When the last
+    /// open tab is closed no tab is shown until one is reopened.
+    pub fn close(&mut self, index: usize) {
+        if self.is_closed(index) {
+            return;
+        }
+        let order = if self.tabs.contains(&index) {
+            self.tabs.clone()
+        } else {
+            self.indices.clone()
+        };
+        self.closed.push(index);
+        self.tabs.retain(|tab| *tab != index);
+        if self.current_index != index {
+            return;
+        }
+        let Some(position) = order.iter().position(|tab| *tab == index) else {
+            return;
+        };
+        let next = order[position + 1..]
+            .iter()
+            .chain(order[..position].iter().rev())
+            .find(|tab| self.is_open(**tab));
+        if let Some(next) = next {
+            self.current_index = *next;
+        }
+    }
+
+    /// Opens a closed tab again at the end of the tab bar.
+    pub fn reopen(&mut self, index: usize) {
+        self.closed.retain(|closed| *closed != index);
+    }
+
+    /// Moves a tab to a position in the tab bar, returns true if it moved.
+    pub fn move_tab(&mut self, index: usize, position: usize) -> bool {
+        let Some(from) = self.tabs.iter().position(|tab| *tab == index) else {
+            return false;
+        };
+        let to = position.min(self.tabs.len() - 1);
+        if from == to {
+            return false;
+        }
+        self.tabs.remove(from);
+        self.tabs.insert(to, index);
+        true
+    }
+
+    /// Selects the next or previous tab of the tab bar, wrapping around at the ends.
+    pub fn select_next(&mut self, forward: bool) {
+        let count = self.tabs.len();
+        if count == 0 {
+            return;
+        }
+        let next = match self.tabs.iter().position(|tab| *tab == self.current_index) {
+            Some(position) if forward => (position + 1) % count,
+            Some(position) => (position + count - 1) % count,
+            None => 0,
+        };
+        self.current_index = self.tabs[next];
+    }
+
+    /// Remembers the index of a tab button or content.
+    fn register(&mut self, index: usize) {
+        if let Err(position) = self.indices.binary_search(&index) {
+            self.indices.insert(position, index);
+        }
+    }
+
+    /// The open tabs of a tab bar with `indices` in order, tabs keep their position and
+    /// new tabs are added at the end.
+    fn synced_tabs(&self, indices: &[usize]) -> Vec<usize> {
+        let mut tabs = self
+            .tabs
+            .iter()
+            .copied()
+            .filter(|tab| indices.contains(tab))
+            .collect::<Vec<_>>();
+        for index in indices {
+            if !self.is_closed(*index) && !tabs.contains(index) {
+                tabs.push(*index);
+            }
+        }
+        tabs
+    }
 }
 
 /// Tab context provider
 /// Provides the context for the tab widgets.
+///
+/// Trigger [`SelectTab`] on the provider to change the current tab and observe
+/// [`Change<TabChanged>`] to find out when it changes.
 #[derive(Widget, Component, Default, Clone, PartialEq, Reflect)]
 #[auto_update(render)]
 #[props(TabContextProvider)]
 pub struct TabContextProvider {
     /// The initial tab that is selected.
-    initial_tab_index: usize,
+    pub initial_tab_index: usize,
 }
 
 /// A tab context provider bundle
@@ -47,14 +221,20 @@ fn render(
         return;
     };
 
-    let _context_entity = hooks.use_context(
+    let context_entity = hooks.use_context(
         &mut commands,
         *current_widget,
-        TabContext {
-            current_index: provider.initial_tab_index,
-        },
+        TabContext::new(provider.initial_tab_index),
     );
 
+    *children = WidgetChildren::default().with_observe(
+        *current_widget,
+        move |trigger: On<SelectTab>, mut context_query: Query<&mut TabContext>| {
+            if let Ok(mut context) = context_query.get_mut(context_entity) {
+                context.set_current_index(trigger.index);
+            }
+        },
+    );
     children.add::<Element>((
         Element,
         WoodpeckerStyle {
@@ -80,3 +260,121 @@ fn render(
 
     children.apply(current_widget.as_parent());
 }
+
+/// Sends [`TabChanged`] when the current tab of a context changes, however it was changed.
+pub(crate) fn report_tab_changes(
+    mut commands: Commands,
+    mut query: Query<(&ChildOf, &mut TabContext), Changed<TabContext>>,
+) {
+    for (child_of, mut context) in query.iter_mut() {
+        if context.current_index == context.reported_index {
+            continue;
+        }
+        let previous = context.reported_index;
+        context.bypass_change_detection().reported_index = context.current_index;
+        commands.trigger(Change {
+            target: child_of.parent(),
+            data: TabChanged {
+                index: context.current_index,
+                previous,
+            },
+        });
+    }
+}
+
+/// Ctrl+Tab selects the next tab and Ctrl+Shift+Tab the previous one, in the tabs that contain
+/// the focused widget or in the only [`TabBar`] when nothing is focused.
+pub(crate) fn switch_tabs_with_keyboard(
+    mut commands: Commands,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    current_focus: Res<CurrentFocus>,
+    hooks: Res<HookHelper>,
+    tab_bar_query: Query<Entity, With<TabBar>>,
+    tab_button_query: Query<(Entity, &TabButton)>,
+    parent_query: Query<&ChildOf>,
+    mut context_query: Query<&mut TabContext>,
+) {
+    if !keyboard_input.just_pressed(KeyCode::Tab)
+        || !keyboard_input.any_pressed([
+            KeyCode::ControlLeft,
+            KeyCode::ControlRight,
+            KeyCode::SuperLeft,
+            KeyCode::SuperRight,
+        ])
+    {
+        return;
+    }
+    let context_entity = if current_focus.get() != Entity::PLACEHOLDER {
+        hooks.get_context::<TabContext>(CurrentWidget(current_focus.get()))
+    } else {
+        tab_bar_query
+            .single()
+            .ok()
+            .and_then(|tab_bar| hooks.get_context::<TabContext>(CurrentWidget(tab_bar)))
+    };
+    let Some(context_entity) = context_entity else {
+        return;
+    };
+    let Ok(mut context) = context_query.get_mut(context_entity) else {
+        return;
+    };
+    let forward = !keyboard_input.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+    context.select_next(forward);
+
+    let tab_bar = tab_bar_query.iter().find(|tab_bar| {
+        hooks.get_context::<TabContext>(CurrentWidget(*tab_bar)) == Some(context_entity)
+    });
+    if let Some(tab_bar) = tab_bar {
+        tab_bar::reveal_tab(
+            &mut commands,
+            tab_bar,
+            context.current_index,
+            &tab_button_query,
+            &parent_query,
+        );
+    }
+}
+
+#[test]
+fn test_tab_context() {
+    let mut context = TabContext::new(1);
+    context.tabs = context.synced_tabs(&[0, 1, 2, 3]);
+    assert_eq!(context.tabs(), &[0, 1, 2, 3]);
+
+    assert!(context.move_tab(0, 2));
+    assert_eq!(context.tabs(), &[1, 2, 0, 3]);
+    assert!(!context.move_tab(3, 10));
+
+    context.select_next(false);
+    assert_eq!(context.current_index(), 3);
+    context.select_next(true);
+    assert_eq!(context.current_index(), 1);
+
+    // Closing the current tab selects the one after it, or before it at the end.
+    context.close(1);
+    assert_eq!(context.current_index(), 2);
+    context.set_current_index(3);
+    context.close(3);
+    assert_eq!(context.current_index(), 0);
+    assert_eq!(context.tabs(), &[2, 0]);
+
+    // Closed tabs stay closed and new tabs go at the end.
+    assert_eq!(context.synced_tabs(&[0, 1, 2, 3, 4]), vec![2, 0, 4]);
+    context.reopen(1);
+    assert_eq!(context.synced_tabs(&[0, 1, 2]), vec![2, 0, 1]);
+
+    // Without a tab bar the next open tab by index is selected.
+    let mut context = TabContext::new(2);
+    for index in [0, 1, 2, 3] {
+        context.register(index);
+    }
+    context.close(3);
+    context.close(2);
+    assert_eq!(context.current_index(), 1);
+    context.set_current_index(3);
+    context.set_current_index(7);
+    assert_eq!(context.current_index(), 1);
+    context.close(0);
+    context.close(1);
+    assert!(context.is_closed(context.current_index()));
+}
@@ -0,0 +1,307 @@
+use super::{TabContext, TabsReordered};
+use crate::prelude::*;
+use bevy::prelude::*;
+
+/// Tab bar styles
+#[derive(Component, Clone, PartialEq, Reflect)]
+pub struct TabBarStyles {
+    /// Styles of the bar
+    pub background: WoodpeckerStyle,
+    /// Styles of the buttons that scroll the tabs when they don't fit
+    pub scroll_button: ButtonStyles,
+    /// Styles of the arrows in the scroll buttons
+    pub scroll_icon: WoodpeckerStyle,
+}
+
+impl Default for TabBarStyles {
+    fn default() -> Self {
+        let scroll_button = WoodpeckerStyle {
+            height: Units::Percentage(100.0),
+            padding: Edge::all(0.0).left(4.0).right(4.0),
+            align_items: Some(WidgetAlignItems::Center),
+            background_color: colors::BACKGROUND_LIGHT,
+            ..Default::default()
+        };
+        Self {
+            background: WoodpeckerStyle {
+                width: Units::Percentage(100.0),
+                height: 52.0.into(),
+                background_color: colors::BACKGROUND_LIGHT,
+                ..Default::default()
+            },
+            scroll_button: ButtonStyles {
+                normal: scroll_button,
+                hovered: WoodpeckerStyle {
+                    background_color: colors::BACKGROUND_MID,
+                    ..scroll_button
+                },
+            },
+            scroll_icon: WoodpeckerStyle {
+                width: 20.0.into(),
+                height: 20.0.into(),
+                color: Color::WHITE,
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// A row of [`TabButton`]s for the tabs of a [`super::TabContextProvider`].
+///
+/// Tabs can be dragged to reorder them and closed if they are closable. When the tabs don't
+/// fit they scroll, with arrow buttons at the ends. While focused Left/Right select the
+/// previous/next tab and Home/End the first/last tab, Ctrl+Tab and Ctrl+Shift+Tab work anywhere
+/// within the tabs.
+#[derive(Widget, Component, Default, Clone, PartialEq, Reflect)]
+#[auto_update(render)]
+#[props(TabBar, TabBarStyles)]
+#[context(TabContext, ScrollContext)]
+#[require(WoodpeckerStyle, TabBarStyles, WidgetRender = WidgetRender::Quad, WidgetChildren, Pickable, Focusable)]
+pub struct TabBar {
+    /// The tabs, new tabs are added at the end of the bar
+    pub tabs: Vec<TabButton>,
+}
+
+/// Scrolls the tab bar so the button of a tab is visible.
+pub(super) fn reveal_tab(
+    commands: &mut Commands,
+    tab_bar_entity: Entity,
+    index: usize,
+    tab_button_query: &Query<(Entity, &TabButton)>,
+    parent_query: &Query<&ChildOf>,
+) {
+    let tab_button = tab_button_query.iter().find(|(entity, tab_button)| {
+        tab_button.index == index
+            && parent_query
+                .iter_ancestors(*entity)
+                .any(|ancestor| ancestor == tab_bar_entity)
+    });
+    if let Some((entity, _)) = tab_button {
+        commands.trigger(ScrollTo {
+            target: entity,
+            align: ScrollAlign::Nearest,
+            animated: true,
+        });
+    }
+}
+
+/// The position a dragged tab is dropped at, counting the other tabs left of the pointer.
+///
+/// `centers` are the horizontal centers of the tabs.
+fn drop_position(centers: &[(usize, f32)], dragged: usize, x: f32) -> usize {
+    centers
+        .iter()
+        .filter(|(index, center)| *index != dragged && *center < x)
+        .count()
+}
+
+fn render(
+    mut commands: Commands,
+    mut hooks: ResMut<HookHelper>,
+    current_widget: Res<CurrentWidget>,
+    asset_server: Res<AssetServer>,
+    mut query: Query<(
+        &TabBar,
+        &TabBarStyles,
+        &mut WoodpeckerStyle,
+        &mut WidgetChildren,
+    )>,
+    mut tab_context_query: Query<&mut TabContext>,
+    scroll_context_query: Query<&ScrollContext>,
+) {
+    let Ok((tab_bar, tab_bar_styles, mut styles, mut children)) = query.get_mut(**current_widget)
+    else {
+        return;
+    };
+
+    let tab_context_entity =
+        hooks.use_context(&mut commands, *current_widget, TabContext::default());
+    // The bar provides the context of its scroll box so it knows when the tabs overflow.
+    let scroll_context_entity =
+        hooks.provide_context(&mut commands, *current_widget, ScrollContext::default());
+    let (Ok(mut tab_context), Ok(scroll_context)) = (
+        tab_context_query.get_mut(tab_context_entity),
+        scroll_context_query.get(scroll_context_entity),
+    ) else {
+        return;
+    };
+
+    let indices = tab_bar.tabs.iter().map(|tab| tab.index).collect::<Vec<_>>();
+    let tabs = tab_context.synced_tabs(&indices);
+    if tab_context.tabs != tabs {
+        tab_context.tabs = tabs;
+    }
+
+    *styles = tab_bar_styles.background;
+
+    let tab_bar_entity = **current_widget;
+    *children = WidgetChildren::default().with_observe(
+        *current_widget,
+        move |trigger: On<WidgetKeyboardButtonEvent>,
+              mut commands: Commands,
+              tab_button_query: Query<(Entity, &TabButton)>,
+              parent_query: Query<&ChildOf>,
+              mut context_query: Query<&mut TabContext>| {
+            let Ok(mut context) = context_query.get_mut(tab_context_entity) else {
+                return;
+            };
+            match trigger.code {
+                KeyCode::ArrowLeft => context.select_next(false),
+                KeyCode::ArrowRight => context.select_next(true),
+                KeyCode::Home => {
+                    if let Some(first) = context.tabs.first().copied() {
+                        context.set_current_index(first);
+                    }
+                }
+                KeyCode::End => {
+                    if let Some(last) = context.tabs.last().copied() {
+                        context.set_current_index(last);
+                    }
+                }
+                _ => return,
+            }
+            reveal_tab(
+                &mut commands,
+                tab_bar_entity,
+                context.current_index,
+                &tab_button_query,
+                &parent_query,
+            );
+        },
+    );
+
+    let overflowing = scroll_context.scrollable_width() > 0.0;
+    let scroll_button = |children: &mut WidgetChildren, icon: &str, direction: f32| {
+        children
+            .add::<WButton>((
+                WButton,
+                tab_bar_styles.scroll_button,
+                WidgetChildren::default().with_child::<Element>((
+                    Element,
+                    tab_bar_styles.scroll_icon,
+                    WidgetRender::Svg {
+                        handle: asset_server.load(format!(
+                            "embedded://woodpecker_ui/embedded_assets/icons/{icon}"
+                        )),
+                        color: Some(tab_bar_styles.scroll_icon.color),
+                    },
+                )),
+            ))
+            .observe(
+                *current_widget,
+                move |_trigger: On<Pointer<Click>>,
+                      mut context_query: Query<&mut ScrollContext>| {
+                    let Ok(mut context) = context_query.get_mut(scroll_context_entity) else {
+                        return;
+                    };
+                    // Scroll by half of the visible tabs.
+                    let step = (context.scrollbox_width / 2.0).max(1.0);
+                    let x = context.scroll_target_x() - direction * step;
+                    context.spring_scroll_x(x, Spring::DEFAULT);
+                },
+            );
+    };
+
+    if overflowing {
+        scroll_button(&mut *children, "arrow-left.svg", -1.0);
+    }
+
+    let mut tab_children = WidgetChildren::default();
+    for index in tab_context.tabs.iter() {
+        let Some(tab) = tab_bar.tabs.iter().find(|tab| tab.index == *index) else {
+            continue;
+        };
+        let index = *index;
+        tab_children
+            .add::<TabButton>(TabButtonBundle {
+                tab_button: tab.clone(),
+                ..Default::default()
+            })
+            .observe(
+                *current_widget,
+                move |trigger: On<Pointer<Drag>>,
+                      mut commands: Commands,
+                      tab_button_query: Query<(Entity, &TabButton, &WidgetLayout)>,
+                      parent_query: Query<&ChildOf>,
+                      mut context_query: Query<&mut TabContext>| {
+                    let Ok(mut context) = context_query.get_mut(tab_context_entity) else {
+                        return;
+                    };
+                    let centers = tab_button_query
+                        .iter()
+                        .filter(|(entity, ..)| {
+                            parent_query
+                                .iter_ancestors(*entity)
+                                .any(|ancestor| ancestor == tab_bar_entity)
+                        })
+                        .map(|(_, tab_button, layout)| {
+                            (tab_button.index, layout.location.x + layout.size.x / 2.0)
+                        })
+                        .collect::<Vec<_>>();
+                    let position =
+                        drop_position(&centers, index, trigger.pointer_location.position.x);
+                    if context.move_tab(index, position) {
+                        commands.trigger(Change {
+                            target: tab_bar_entity,
+                            data: TabsReordered {
+                                tabs: context.tabs.clone(),
+                            },
+                        });
+                    }
+                },
+            )
+            // Tab buttons aren't focusable, focus the bar again so the arrow keys work.
+            .observe(
+                *current_widget,
+                move |_trigger: On<Pointer<Click>>,
+                      mut commands: Commands,
+                      mut current_focus: ResMut<CurrentFocus>| {
+                    current_focus.refocus(&mut commands, tab_bar_entity);
+                },
+            );
+        // Keyed by tab so the buttons move instead of being rebuilt when reordering.
+        tab_children.add_key(index.to_string());
+    }
+
+    children.add::<Element>((
+        Element,
+        WoodpeckerStyle {
+            flex_grow: 1.0,
+            height: Units::Percentage(100.0),
+            ..Default::default()
+        },
+        WidgetChildren::default().with_child::<ScrollBox>((
+            ScrollBox {
+                disable_vertical: true,
+                hide_horizontal: true,
+                hide_vertical: true,
+                scrollbar_thickness: Some(0.0),
+                spring: Some(Spring::DEFAULT),
+                ..Default::default()
+            },
+            PassedChildren(WidgetChildren::default().with_child::<Element>((
+                Element,
+                WoodpeckerStyle {
+                    height: tab_bar_styles.background.height,
+                    ..Default::default()
+                },
+                tab_children,
+            ))),
+        )),
+    ));
+
+    if overflowing {
+        scroll_button(&mut *children, "arrow-right.svg", 1.0);
+    }
+
+    children.apply(current_widget.as_parent());
+}
+
+#[test]
+fn test_drop_position() {
+    let centers = [(3, 50.0), (0, 150.0), (1, 250.0)];
+    assert_eq!(drop_position(&centers, 3, 10.0), 0);
+    assert_eq!(drop_position(&centers, 3, 160.0), 1);
+    assert_eq!(drop_position(&centers, 3, 400.0), 2);
+    assert_eq!(drop_position(&centers, 1, 100.0), 1);
+}
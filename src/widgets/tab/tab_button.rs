@@ -1,4 +1,4 @@
-use super::TabContext;
+use super::{TabClosed, TabContext};
 use crate::prelude::*;
 use bevy::prelude::*;
 
@@ -12,10 +12,14 @@ pub struct TabButton {
     pub index: usize,
     /// The title of the tab.
     pub title: String,
+    /// Shows a button that closes the tab.
+    pub closable: bool,
     /// Inactive styles
     pub inactive_styles: ButtonStyles,
     /// Active styles
     pub active_styles: ButtonStyles,
+    /// Close button styles
+    pub close_styles: ButtonStyles,
 }
 
 impl Default for TabButton {
@@ -28,9 +32,19 @@ impl Default for TabButton {
             padding: Edge::all(0.0).left(16.0).right(16.0),
             ..Default::default()
         };
+        let close_styles = WoodpeckerStyle {
+            margin: Edge::all(0.0).left(8.0),
+            padding: Edge::all(0.0).left(4.0).right(4.0),
+            border_radius: Corner::all(4.0),
+            font_size: 18.0,
+            color: Color::WHITE,
+            background_color: Srgba::new(0.0, 0.0, 0.0, 0.0).into(),
+            ..Default::default()
+        };
         Self {
             index: Default::default(),
             title: Default::default(),
+            closable: false,
             inactive_styles: ButtonStyles {
                 normal: WoodpeckerStyle {
                     background_color: colors::BACKGROUND_LIGHT,
@@ -51,6 +65,13 @@ impl Default for TabButton {
                     ..base_styles
                 },
             },
+            close_styles: ButtonStyles {
+                normal: close_styles,
+                hovered: WoodpeckerStyle {
+                    background_color: colors::BACKGROUND_MID,
+                    ..close_styles
+                },
+            },
         }
     }
 }
@@ -71,7 +92,7 @@ fn render(
     current_widget: Res<CurrentWidget>,
     mut hooks: ResMut<HookHelper>,
     mut query: Query<(&TabButton, &mut WidgetChildren)>,
-    context_query: Query<&TabContext>,
+    mut context_query: Query<&mut TabContext>,
 ) {
     let Ok((tab_button, mut children)) = query.get_mut(**current_widget) else {
         return;
@@ -79,14 +100,79 @@ fn render(
 
     let context_entity = hooks.use_context(&mut commands, *current_widget, TabContext::default());
 
-    let Ok(context) = context_query.get(context_entity) else {
+    let Ok(mut context) = context_query.get_mut(context_entity) else {
         return;
     };
+    // Only closing and selecting tabs look at the known tabs, so there's nothing to re-render.
+    context.bypass_change_detection().register(tab_button.index);
+
+    if context.is_closed(tab_button.index) {
+        *children = WidgetChildren::default();
+        children.apply(current_widget.as_parent());
+        return;
+    }
 
     let is_active = context.current_index == tab_button.index;
 
     // Actual button.
     let index = tab_button.index;
+    let tab_button_entity = **current_widget;
+    let mut button_children = WidgetChildren::default().with_child::<Element>((
+        Element,
+        WoodpeckerStyle {
+            font_size: if is_active {
+                tab_button.active_styles.normal.font_size
+            } else {
+                tab_button.inactive_styles.normal.font_size
+            },
+            color: if is_active {
+                tab_button.active_styles.normal.color
+            } else {
+                tab_button.inactive_styles.normal.color
+            },
+            text_wrap: TextWrap::None,
+            ..Default::default()
+        },
+        WidgetRender::Text {
+            content: tab_button.title.clone(),
+        },
+    ));
+    if tab_button.closable {
+        button_children
+            .add::<WButton>((
+                WButton,
+                tab_button.close_styles,
+                WidgetChildren::default().with_child::<Element>((
+                    Element,
+                    WoodpeckerStyle {
+                        font_size: tab_button.close_styles.normal.font_size,
+                        color: tab_button.close_styles.normal.color,
+                        ..Default::default()
+                    },
+                    WidgetRender::Text {
+                        content: "×".into(),
+                    },
+                )),
+            ))
+            .observe(
+                *current_widget,
+                move |mut trigger: On<Pointer<Click>>,
+                      mut commands: Commands,
+                      mut context_query: Query<&mut TabContext>| {
+                    // Closing a tab shouldn't select it.
+                    trigger.propagate(false);
+                    let Ok(mut context) = context_query.get_mut(context_entity) else {
+                        return;
+                    };
+                    context.close(index);
+                    commands.trigger(Change {
+                        target: tab_button_entity,
+                        data: TabClosed { index },
+                    });
+                },
+            );
+    }
+
     children
         .add::<WButton>((
             WButton,
@@ -95,26 +181,7 @@ fn render(
             } else {
                 tab_button.inactive_styles
             },
-            WidgetChildren::default().with_child::<Element>((
-                Element,
-                WoodpeckerStyle {
-                    font_size: if is_active {
-                        tab_button.active_styles.normal.font_size
-                    } else {
-                        tab_button.inactive_styles.normal.font_size
-                    },
-                    color: if is_active {
-                        tab_button.active_styles.normal.color
-                    } else {
-                        tab_button.inactive_styles.normal.color
-                    },
-                    text_wrap: TextWrap::None,
-                    ..Default::default()
-                },
-                WidgetRender::Text {
-                    content: tab_button.title.clone(),
-                },
-            )),
+            button_children,
         ))
         .observe(
             *current_widget,
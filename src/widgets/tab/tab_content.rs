@@ -36,7 +36,7 @@ fn render(
         &mut WoodpeckerStyle,
         &PassedChildren,
     )>,
-    context_query: Query<&TabContext>,
+    mut context_query: Query<&mut TabContext>,
 ) {
     let Ok((tab, mut children, mut styles, passed_children)) = query.get_mut(**current_widget)
     else {
@@ -45,12 +45,14 @@ fn render(
 
     let context_entity = hooks.use_context(&mut commands, *current_widget, TabContext::default());
 
-    let Ok(context) = context_query.get(context_entity) else {
+    let Ok(mut context) = context_query.get_mut(context_entity) else {
         return;
     };
+    // Lets tabs without a tab bar be selected and picked when another tab closes.
+    context.bypass_change_detection().register(tab.index);
 
     *styles = WoodpeckerStyle {
-        display: if context.current_index == tab.index {
+        display: if context.current_index == tab.index && !context.is_closed(tab.index) {
             WidgetDisplay::Flex
         } else {
             WidgetDisplay::None
@@ -0,0 +1,750 @@
+use std::cmp::Ordering;
+
+use crate::prelude::*;
+use bevy::prelude::*;
+
+use super::colors;
+use super::virtual_list::{view_size, Rows};
+
+/// The direction a [`Table`] column is sorted in.
+#[derive(Default, Debug, Reflect, Copy, Clone, PartialEq, Eq)]
+pub enum SortDirection {
+    /// Smallest first
+    #[default]
+    Ascending,
+    /// Largest first
+    Descending,
+}
+
+impl SortDirection {
+    /// The other direction.
+    pub fn reversed(self) -> Self {
+        match self {
+            Self::Ascending => Self::Descending,
+            Self::Descending => Self::Ascending,
+        }
+    }
+}
+
+/// A table sort change event.
+#[derive(Debug, Clone, Reflect)]
+pub struct TableSortChanged {
+    /// The index of the column the rows are sorted by.
+    pub column: usize,
+    /// The direction the rows are sorted in.
+    pub direction: SortDirection,
+}
+
+/// A table selection change event.
+#[derive(Debug, Clone, Reflect)]
+pub struct TableSelectionChanged {
+    /// The indices of the selected rows in [`Table::rows`], in row order.
+    pub selected: Vec<usize>,
+}
+
+/// How many rows of a [`Table`] can be selected.
+#[derive(Default, Debug, Reflect, Copy, Clone, PartialEq, Eq)]
+pub enum TableSelection {
+    /// Rows can't be selected
+    None,
+    /// Clicking a row selects only that row
+    #[default]
+    Single,
+    /// Ctrl+Click toggles a row and Shift+Click selects a range of rows
+    Multiple,
+}
+
+/// A column of a [`Table`].
+#[derive(Debug, Clone, Reflect, PartialEq)]
+pub struct TableColumn {
+    /// The header text
+    pub header: String,
+    /// The initial width in pixels
+    pub width: f32,
+    /// The smallest width the column can be resized to
+    pub min_width: f32,
+    /// The largest width the column can be resized to
+    pub max_width: f32,
+    /// The alignment of the text in the cells of this column
+    pub align: TextAlign,
+    /// Can the rows be sorted by this column?
+    pub sortable: bool,
+}
+
+impl Default for TableColumn {
+    fn default() -> Self {
+        Self {
+            header: String::new(),
+            width: 120.0,
+            min_width: 40.0,
+            max_width: f32::MAX,
+            align: TextAlign::Left,
+            sortable: true,
+        }
+    }
+}
+
+impl TableColumn {
+    /// Creates a new sortable column.
+    pub fn new(header: impl Into<String>, width: f32) -> Self {
+        Self {
+            header: header.into(),
+            width,
+            ..Default::default()
+        }
+    }
+
+    /// Sets the range the column can be resized in.
+    pub fn with_limits(mut self, min_width: f32, max_width: f32) -> Self {
+        self.min_width = min_width;
+        self.max_width = max_width;
+        self
+    }
+
+    /// Sets the alignment of the text in the cells.
+    pub fn with_align(mut self, align: TextAlign) -> Self {
+        self.align = align;
+        self
+    }
+
+    /// Stops the rows from being sorted by this column.
+    pub fn unsortable(mut self) -> Self {
+        self.sortable = false;
+        self
+    }
+
+    fn clamp(&self, width: f32) -> f32 {
+        width.clamp(self.min_width, self.max_width.max(self.min_width))
+    }
+}
+
+/// Table styles
+#[derive(Component, Clone, PartialEq, Reflect)]
+pub struct TableStyles {
+    /// Styles of the table
+    pub background: WoodpeckerStyle,
+    /// Styles of the header row
+    pub header: WoodpeckerStyle,
+    /// Styles of the header cells, the width is set by the column
+    pub header_cell: WoodpeckerStyle,
+    /// Header text styles
+    pub header_text: WoodpeckerStyle,
+    /// Styles of the arrow on the column the rows are sorted by
+    pub sort_icon: WoodpeckerStyle,
+    /// The handle on the right side of each header cell that resizes the column
+    pub resize_handle: ButtonStyles,
+    /// Row styles, the height is the row height of the table
+    pub row: WoodpeckerStyle,
+    /// Styles of every other row
+    pub row_alternate: WoodpeckerStyle,
+    /// Styles of selected rows
+    pub row_selected: WoodpeckerStyle,
+    /// Styles of the cells, the width is set by the column
+    pub cell: WoodpeckerStyle,
+}
+
+impl Default for TableStyles {
+    fn default() -> Self {
+        let row = WoodpeckerStyle {
+            height: 28.0.into(),
+            align_items: Some(WidgetAlignItems::Center),
+            background_color: colors::BACKGROUND,
+            ..Default::default()
+        };
+        let resize_handle = WoodpeckerStyle {
+            position: WidgetPosition::Absolute,
+            right: 0.0.into(),
+            width: 4.0.into(),
+            height: Units::Percentage(100.0),
+            background_color: colors::BACKGROUND_LIGHT,
+            ..Default::default()
+        };
+        Self {
+            background: WoodpeckerStyle {
+                width: Units::Percentage(100.0),
+                height: Units::Percentage(100.0),
+                flex_direction: WidgetFlexDirection::Column,
+                background_color: colors::BACKGROUND,
+                ..Default::default()
+            },
+            header: WoodpeckerStyle {
+                width: Units::Percentage(100.0),
+                height: 32.0.into(),
+                background_color: colors::DARK_BACKGROUND,
+                ..Default::default()
+            },
+            header_cell: WoodpeckerStyle {
+                height: Units::Percentage(100.0),
+                padding: Edge::all(0.0).left(8.0).right(8.0),
+                align_items: Some(WidgetAlignItems::Center),
+                ..Default::default()
+            },
+            header_text: WoodpeckerStyle {
+                width: Units::Percentage(100.0),
+                color: Color::WHITE,
+                font_size: 14.0,
+                text_wrap: TextWrap::None,
+                ..Default::default()
+            },
+            sort_icon: WoodpeckerStyle {
+                width: 14.0.into(),
+                height: 14.0.into(),
+                margin: Edge::all(0.0).left(4.0),
+                color: Color::WHITE,
+                ..Default::default()
+            },
+            resize_handle: ButtonStyles {
+                normal: resize_handle,
+                hovered: WoodpeckerStyle {
+                    background_color: colors::PRIMARY,
+                    ..resize_handle
+                },
+            },
+            row,
+            row_alternate: WoodpeckerStyle {
+                background_color: colors::BACKGROUND_MID,
+                ..row
+            },
+            row_selected: WoodpeckerStyle {
+                background_color: colors::PRIMARY,
+                ..row
+            },
+            cell: WoodpeckerStyle {
+                padding: Edge::all(0.0).left(8.0).right(8.0),
+                color: Color::WHITE,
+                font_size: 14.0,
+                text_wrap: TextWrap::None,
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Table state
+#[derive(Component, Debug, Default, Clone, PartialEq, Reflect)]
+pub struct TableState {
+    /// The column the rows are sorted by.
+    pub sort_column: Option<usize>,
+    /// The direction the rows are sorted in.
+    pub sort_direction: SortDirection,
+    /// The current width of each column.
+    pub widths: Vec<f32>,
+    /// The indices of the selected rows in [`Table::rows`], in row order.
+    pub selected: Vec<usize>,
+    /// The row Shift+Click selects from.
+    anchor: Option<usize>,
+    /// The width of the column being resized when the drag started.
+    resize_start: Option<f32>,
+    /// The order rows are shown in.
+    order: Vec<usize>,
+    /// The sort, row count and [`Table::revision`] `order` was computed for.
+    order_key: Option<(Option<usize>, SortDirection, usize, u64)>,
+}
+
+impl TableState {
+    /// Is the row selected?
+    pub fn is_selected(&self, row: usize) -> bool {
+        self.selected.binary_search(&row).is_ok()
+    }
+
+    fn width(&self, table: &Table, column: usize) -> f32 {
+        self.widths
+            .get(column)
+            .copied()
+            .or_else(|| table.columns.get(column).map(|column| column.width))
+            .unwrap_or_default()
+    }
+
+    /// Sorts the rows again if the sort, the number of rows or the revision changed since they
+    /// were last sorted.
+    fn update_order(&mut self, table: &Table) {
+        let key = (
+            self.sort_column,
+            self.sort_direction,
+            table.rows.len(),
+            table.revision,
+        );
+        if self.order_key != Some(key) {
+            self.order = row_order(&table.rows, self.sort_column, self.sort_direction);
+            self.order_key = Some(key);
+        }
+    }
+
+    /// Selects a clicked row, Shift+Click selects the rows between in the order they are shown.
+    ///
+    /// Returns true if the selection changed.
+    fn click(&mut self, mode: TableSelection, row: usize, shift: bool, ctrl: bool) -> bool {
+        let order = &self.order;
+        let previous = self.selected.clone();
+        match mode {
+            TableSelection::None => return false,
+            TableSelection::Single => {
+                self.selected = vec![row];
+                self.anchor = Some(row);
+            }
+            TableSelection::Multiple => {
+                let position = |row: usize| order.iter().position(|r| *r == row);
+                let range = self
+                    .anchor
+                    .and_then(position)
+                    .zip(position(row))
+                    .filter(|_| shift);
+                if let Some((from, to)) = range {
+                    let range = &order[from.min(to)..=from.max(to)];
+                    if !ctrl {
+                        self.selected.clear();
+                    }
+                    self.selected.extend_from_slice(range);
+                } else if ctrl {
+                    match self.selected.binary_search(&row) {
+                        Ok(index) => {
+                            self.selected.remove(index);
+                        }
+                        Err(index) => self.selected.insert(index, row),
+                    }
+                    self.anchor = Some(row);
+                } else {
+                    self.selected = vec![row];
+                    self.anchor = Some(row);
+                }
+                self.selected.sort_unstable();
+                self.selected.dedup();
+            }
+        }
+        self.selected != previous
+    }
+}
+
+/// Compares cells as numbers when both are numbers and as text when neither is,
+/// numbers come before text.
+fn compare_cells(a: &str, b: &str) -> Ordering {
+    match (a.trim().parse::<f64>(), b.trim().parse::<f64>()) {
+        (Ok(a), Ok(b)) => a.total_cmp(&b),
+        (Ok(_), Err(_)) => Ordering::Less,
+        (Err(_), Ok(_)) => Ordering::Greater,
+        (Err(_), Err(_)) => a.to_lowercase().cmp(&b.to_lowercase()),
+    }
+}
+
+/// The order rows are shown in, sorting is stable so equal rows keep their order.
+fn row_order(
+    rows: &[Vec<String>],
+    sort_column: Option<usize>,
+    direction: SortDirection,
+) -> Vec<usize> {
+    let mut order = (0..rows.len()).collect::<Vec<_>>();
+    if let Some(column) = sort_column {
+        let cell = |row: usize| rows[row].get(column).map(String::as_str).unwrap_or("");
+        order.sort_by(|a, b| {
+            let ordering = compare_cells(cell(*a), cell(*b));
+            match direction {
+                SortDirection::Ascending => ordering,
+                SortDirection::Descending => ordering.reverse(),
+            }
+        });
+    }
+    order
+}
+
+/// A table of text with a header that stays in place while the rows scroll.
+///
+/// Clicking a header sorts the rows by that column, clicking it again reverses the order.
+/// Dragging the handle on the right of a header resizes the column. Only the rows in view
+/// are mounted, so tables can hold thousands of rows.
+#[derive(Widget, Component, Clone, PartialEq, Reflect)]
+#[auto_update(render)]
+#[props(Table, TableStyles)]
+#[state(TableState)]
+#[context(ScrollContext)]
+#[require(WoodpeckerStyle, TableStyles, WidgetRender = WidgetRender::Quad, WidgetChildren)]
+pub struct Table {
+    /// The columns
+    pub columns: Vec<TableColumn>,
+    /// The rows, each with a cell for every column
+    pub rows: Vec<Vec<String>>,
+    /// Change this when cells change so sorted rows are sorted again, adding or removing rows
+    /// is noticed without it. Checking the cells themselves on every scroll would be too slow.
+    pub revision: u64,
+    /// How many rows can be selected
+    pub selection: TableSelection,
+    /// How many rows outside of the visible area are mounted above and below it
+    pub overscan: usize,
+    /// The scroll box settings, its children are ignored
+    pub scroll_box: ScrollBox,
+}
+
+impl Default for Table {
+    fn default() -> Self {
+        Self {
+            columns: vec![],
+            rows: vec![],
+            revision: 0,
+            selection: TableSelection::default(),
+            overscan: 3,
+            scroll_box: ScrollBox::default(),
+        }
+    }
+}
+
+fn render(
+    mut commands: Commands,
+    mut hooks: ResMut<HookHelper>,
+    current_widget: Res<CurrentWidget>,
+    asset_server: Res<AssetServer>,
+    mut query: Query<(
+        &Table,
+        &TableStyles,
+        &mut WoodpeckerStyle,
+        &mut WidgetChildren,
+    )>,
+    context_query: Query<&ScrollContext>,
+    mut state_query: Query<&mut TableState>,
+) {
+    let Ok((table, table_styles, mut styles, mut children)) = query.get_mut(**current_widget)
+    else {
+        return;
+    };
+
+    // The table provides the context so the header can follow the horizontal scroll.
+    let context_entity =
+        hooks.provide_context(&mut commands, *current_widget, ScrollContext::default());
+    let state_entity = hooks.use_state(
+        &mut commands,
+        *current_widget,
+        TableState {
+            widths: table.columns.iter().map(|column| column.width).collect(),
+            ..Default::default()
+        },
+    );
+    let (Ok(context), Ok(state)) = (
+        context_query.get(context_entity),
+        state_query.get_mut(state_entity),
+    ) else {
+        return;
+    };
+    // The order is derived from the props, updating it doesn't need another render.
+    let state = state.bypass_change_detection();
+    state.update_order(table);
+
+    *styles = table_styles.background;
+
+    let widths = (0..table.columns.len())
+        .map(|column| state.width(table, column))
+        .collect::<Vec<_>>();
+    let total_width = widths.iter().sum::<f32>();
+    let table_entity = **current_widget;
+
+    let mut header_children = WidgetChildren::default();
+    for (i, column) in table.columns.iter().enumerate() {
+        let mut cell_children = WidgetChildren::default().with_child::<Element>((
+            Element,
+            WoodpeckerStyle {
+                text_alignment: Some(column.align),
+                ..table_styles.header_text
+            },
+            WidgetRender::Text {
+                content: column.header.clone(),
+            },
+        ));
+        if state.sort_column == Some(i) {
+            let icon = match state.sort_direction {
+                SortDirection::Ascending => "arrow-up.svg",
+                SortDirection::Descending => "arrow-down.svg",
+            };
+            cell_children.add::<Element>((
+                Element,
+                table_styles.sort_icon,
+                WidgetRender::Svg {
+                    handle: asset_server.load(format!(
+                        "embedded://woodpecker_ui/embedded_assets/icons/{icon}"
+                    )),
+                    color: Some(table_styles.sort_icon.color),
+                },
+            ));
+        }
+        cell_children
+            .add::<WButton>((WButton, table_styles.resize_handle))
+            .observe(
+                *current_widget,
+                move |mut trigger: On<Pointer<DragStart>>,
+                      table_query: Query<&Table>,
+                      mut state_query: Query<&mut TableState>| {
+                    trigger.propagate(false);
+                    let (Ok(table), Ok(mut state)) = (
+                        table_query.get(table_entity),
+                        state_query.get_mut(state_entity),
+                    ) else {
+                        return;
+                    };
+                    state.resize_start = Some(state.width(table, i));
+                },
+            )
+            .observe(
+                *current_widget,
+                move |mut trigger: On<Pointer<Drag>>,
+                      table_query: Query<&Table>,
+                      mut state_query: Query<&mut TableState>| {
+                    trigger.propagate(false);
+                    let (Ok(table), Ok(mut state)) = (
+                        table_query.get(table_entity),
+                        state_query.get_mut(state_entity),
+                    ) else {
+                        return;
+                    };
+                    let Some(resize_start) = state.resize_start else {
+                        return;
+                    };
+                    let Some(column) = table.columns.get(i) else {
+                        return;
+                    };
+                    let width = column.clamp(resize_start + trigger.distance.x);
+                    let mut widths = (0..table.columns.len())
+                        .map(|column| state.width(table, column))
+                        .collect::<Vec<_>>();
+                    widths[i] = width;
+                    state.widths = widths;
+                },
+            )
+            .observe(
+                *current_widget,
+                move |mut trigger: On<Pointer<DragEnd>>,
+                      mut state_query: Query<&mut TableState>| {
+                    trigger.propagate(false);
+                    if let Ok(mut state) = state_query.get_mut(state_entity) {
+                        state.resize_start = None;
+                    }
+                },
+            )
+            // Releasing the handle shouldn't sort the column.
+            .observe(*current_widget, move |mut trigger: On<Pointer<Click>>| {
+                trigger.propagate(false);
+            });
+
+        header_children
+            .add::<Element>((
+                Element,
+                WoodpeckerStyle {
+                    width: widths[i].into(),
+                    ..table_styles.header_cell
+                },
+                cell_children,
+                Pickable::default(),
+            ))
+            .observe(
+                *current_widget,
+                move |_trigger: On<Pointer<Click>>,
+                      mut commands: Commands,
+                      table_query: Query<&Table>,
+                      mut state_query: Query<&mut TableState>| {
+                    let (Ok(table), Ok(mut state)) = (
+                        table_query.get(table_entity),
+                        state_query.get_mut(state_entity),
+                    ) else {
+                        return;
+                    };
+                    if !table.columns.get(i).is_some_and(|column| column.sortable) {
+                        return;
+                    }
+                    state.sort_direction = if state.sort_column == Some(i) {
+                        state.sort_direction.reversed()
+                    } else {
+                        SortDirection::Ascending
+                    };
+                    state.sort_column = Some(i);
+                    commands.trigger(Change {
+                        target: table_entity,
+                        data: TableSortChanged {
+                            column: i,
+                            direction: state.sort_direction,
+                        },
+                    });
+                },
+            );
+    }
+
+    let rows = Rows::Fixed {
+        height: table_styles.row.height.value_or(28.0),
+        count: state.order.len(),
+    };
+    let top = -context.scroll_y();
+    let visible = rows.visible(top, top + view_size(context).y, table.overscan);
+
+    let mut content_children = WidgetChildren::default();
+    for position in visible {
+        let row = state.order[position];
+        let mut row_children = WidgetChildren::default();
+        for (i, column) in table.columns.iter().enumerate() {
+            row_children.add::<Element>((
+                Element,
+                WoodpeckerStyle {
+                    width: widths[i].into(),
+                    text_alignment: Some(column.align),
+                    ..table_styles.cell
+                },
+                WidgetRender::Text {
+                    content: table.rows[row].get(i).cloned().unwrap_or_default(),
+                },
+            ));
+        }
+        let row_styles = if state.is_selected(row) {
+            table_styles.row_selected
+        } else if position % 2 == 1 {
+            table_styles.row_alternate
+        } else {
+            table_styles.row
+        };
+        content_children
+            .add::<Element>((
+                Element,
+                WoodpeckerStyle {
+                    position: WidgetPosition::Absolute,
+                    top: rows.top(position).into(),
+                    width: total_width.into(),
+                    ..row_styles
+                },
+                WidgetRender::Quad,
+                row_children,
+                Pickable::default(),
+            ))
+            .observe(
+                *current_widget,
+                move |_trigger: On<Pointer<Click>>,
+                      mut commands: Commands,
+                      keyboard_input: Res<ButtonInput<KeyCode>>,
+                      table_query: Query<&Table>,
+                      mut state_query: Query<&mut TableState>| {
+                    let (Ok(table), Ok(mut state)) = (
+                        table_query.get(table_entity),
+                        state_query.get_mut(state_entity),
+                    ) else {
+                        return;
+                    };
+                    let shift =
+                        keyboard_input.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+                    let ctrl = keyboard_input.any_pressed([
+                        KeyCode::ControlLeft,
+                        KeyCode::ControlRight,
+                        KeyCode::SuperLeft,
+                        KeyCode::SuperRight,
+                    ]);
+                    state.bypass_change_detection().update_order(table);
+                    if state.click(table.selection, row, shift, ctrl) {
+                        commands.trigger(Change {
+                            target: table_entity,
+                            data: TableSelectionChanged {
+                                selected: state.selected.clone(),
+                            },
+                        });
+                    }
+                },
+            );
+        // Keyed by row so moving rows around when sorting keeps their widgets.
+        content_children.add_key(row.to_string());
+    }
+
+    *children = WidgetChildren::default()
+        .with_child::<Element>((
+            Element,
+            table_styles.header,
+            WidgetRender::Quad,
+            WidgetChildren::default().with_child::<Clip>((
+                Clip,
+                WidgetChildren::default().with_child::<Element>((
+                    Element,
+                    WoodpeckerStyle {
+                        position: WidgetPosition::Absolute,
+                        left: context.scroll_x().into(),
+                        width: total_width.into(),
+                        height: Units::Percentage(100.0),
+                        ..Default::default()
+                    },
+                    header_children,
+                )),
+            )),
+        ))
+        .with_child::<Element>((
+            Element,
+            WoodpeckerStyle {
+                width: Units::Percentage(100.0),
+                flex_grow: 1.0,
+                ..Default::default()
+            },
+            WidgetChildren::default().with_child::<ScrollBox>((
+                table.scroll_box.clone(),
+                PassedChildren(WidgetChildren::default().with_child::<Element>((
+                    Element,
+                    WoodpeckerStyle {
+                        width: total_width.into(),
+                        height: rows.total().into(),
+                        ..Default::default()
+                    },
+                    content_children,
+                ))),
+            )),
+        ));
+
+    children.apply(current_widget.as_parent());
+}
+
+#[test]
+fn test_table() {
+    let rows = vec![
+        vec!["Bob".to_string(), "10".to_string()],
+        vec!["alice".to_string(), "9".to_string()],
+        vec!["Carol".to_string(), "10".to_string()],
+    ];
+    // Numbers sort as numbers and ties keep their order.
+    assert_eq!(
+        row_order(&rows, Some(1), SortDirection::Ascending),
+        vec![1, 0, 2]
+    );
+    assert_eq!(
+        row_order(&rows, Some(1), SortDirection::Descending),
+        vec![0, 2, 1]
+    );
+    assert_eq!(
+        row_order(&rows, Some(0), SortDirection::Ascending),
+        vec![1, 0, 2]
+    );
+
+    // Numbers come before text so mixed columns still have a consistent order.
+    let mixed = ["9", "1a", "10", "b", "1"]
+        .map(|cell| vec![cell.to_string()])
+        .to_vec();
+    assert_eq!(
+        row_order(&mixed, Some(0), SortDirection::Ascending),
+        vec![4, 0, 2, 1, 3]
+    );
+
+    // The order is sorted again when the rows are revised.
+    let mut table = Table {
+        rows: mixed,
+        ..Default::default()
+    };
+    let mut state = TableState {
+        sort_column: Some(0),
+        ..Default::default()
+    };
+    state.update_order(&table);
+    assert_eq!(state.order, vec![4, 0, 2, 1, 3]);
+    table.rows[0][0] = "c".to_string();
+    state.update_order(&table);
+    assert_eq!(state.order, vec![4, 0, 2, 1, 3]);
+    table.revision += 1;
+    state.update_order(&table);
+    assert_eq!(state.order, vec![4, 2, 1, 3, 0]);
+
+    let mut state = TableState {
+        order: vec![3, 1, 0, 2],
+        ..Default::default()
+    };
+    assert!(!state.click(TableSelection::None, 1, false, false));
+    assert!(state.click(TableSelection::Multiple, 1, false, false));
+    // Shift selects the rows between in the order they are shown.
+    assert!(state.click(TableSelection::Multiple, 2, true, false));
+    assert_eq!(state.selected, vec![0, 1, 2]);
+    assert!(state.click(TableSelection::Multiple, 0, false, true));
+    assert_eq!(state.selected, vec![1, 2]);
+    assert!(state.click(TableSelection::Single, 3, true, true));
+    assert_eq!(state.selected, vec![3]);
+}
@@ -0,0 +1,304 @@
+use crate::prelude::*;
+use bevy::prelude::*;
+
+use super::colors;
+
+/// A tag input change event.
+#[derive(Debug, Clone, Reflect)]
+pub struct TagsChanged {
+    /// All of the tags, in the order they were added.
+    pub tags: Vec<String>,
+}
+
+/// Tag input styles
+#[derive(Component, Clone, PartialEq, Reflect)]
+pub struct TagInputStyles {
+    /// Styles of the box holding the tags and the textbox
+    pub background: WoodpeckerStyle,
+    /// Tag styles
+    pub tag: WoodpeckerStyle,
+    /// Tag remove button styles
+    pub remove: ButtonStyles,
+    /// The styles of the wrapper around the textbox
+    pub input: WoodpeckerStyle,
+}
+
+impl Default for TagInputStyles {
+    fn default() -> Self {
+        let remove = WoodpeckerStyle {
+            margin: Edge::all(0.0).left(4.0),
+            padding: Edge::all(0.0).left(3.0).right(3.0),
+            border_radius: Corner::all(2.0),
+            font_size: 14.0,
+            color: Color::WHITE,
+            background_color: Srgba::new(0.0, 0.0, 0.0, 0.0).into(),
+            ..Default::default()
+        };
+        Self {
+            background: WoodpeckerStyle {
+                width: Units::Percentage(100.0),
+                flex_wrap: WidgetFlexWrap::Wrap,
+                align_items: Some(WidgetAlignItems::Center),
+                gap: (4.0.into(), 4.0.into()),
+                padding: Edge::all(4.0),
+                background_color: colors::DARK_BACKGROUND,
+                border_radius: Corner::all(4.0),
+                ..Default::default()
+            },
+            tag: WoodpeckerStyle {
+                align_items: Some(WidgetAlignItems::Center),
+                height: 22.0.into(),
+                padding: Edge::all(0.0).left(6.0).right(2.0),
+                border_radius: Corner::all(11.0),
+                background_color: colors::PRIMARY,
+                color: Color::WHITE,
+                font_size: 14.0,
+                text_wrap: TextWrap::None,
+                ..Default::default()
+            },
+            remove: ButtonStyles {
+                normal: remove,
+                hovered: WoodpeckerStyle {
+                    background_color: colors::PRIMARY_LIGHT,
+                    ..remove
+                },
+            },
+            input: WoodpeckerStyle {
+                flex_grow: 1.0,
+                min_width: 80.0.into(),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// Tag input state
+#[derive(Component, Debug, Default, Clone, PartialEq, Reflect)]
+pub struct TagInputState {
+    /// The tags, in the order they were added.
+    pub tags: Vec<String>,
+    /// The text that hasn't been turned into a tag yet.
+    pub text: String,
+}
+
+/// A textbox that turns typed text into removable tags.
+///
+/// Pressing Enter or typing a comma adds the text as a tag, Backspace in an empty
+/// textbox removes the last tag. Tags that are already present(ignoring case) aren't added.
+#[derive(Widget, Component, Clone, PartialEq, Reflect, Default)]
+#[auto_update(render)]
+#[props(TagInput, TagInputStyles)]
+#[state(TagInputState)]
+#[require(WoodpeckerStyle, TagInputStyles, WidgetRender = WidgetRender::Quad, WidgetChildren)]
+pub struct TagInput {
+    /// The initial tags
+    pub initial_tags: Vec<String>,
+}
+
+/// Adds a tag unless it's empty or already present, ignoring case.
+///
+/// Returns true if the tag was added.
+pub(crate) fn add_tag(tags: &mut Vec<String>, tag: &str) -> bool {
+    let tag = tag.trim();
+    if tag.is_empty() || tags.iter().any(|t| t.to_lowercase() == tag.to_lowercase()) {
+        return false;
+    }
+    tags.push(tag.to_string());
+    true
+}
+
+/// Adds every comma terminated part of the text as a tag and returns the text after the last comma.
+///
+/// The second value is true if any tags were added.
+pub(crate) fn take_tags(tags: &mut Vec<String>, text: &str) -> (String, bool) {
+    let Some((complete, rest)) = text.rsplit_once(',') else {
+        return (text.to_string(), false);
+    };
+    let mut changed = false;
+    for tag in complete.split(',') {
+        changed |= add_tag(tags, tag);
+    }
+    (rest.trim_start().to_string(), changed)
+}
+
+fn tags_changed(commands: &mut Commands, tag_input_entity: Entity, state: &TagInputState) {
+    commands.trigger(Change {
+        target: tag_input_entity,
+        data: TagsChanged {
+            tags: state.tags.clone(),
+        },
+    });
+}
+
+fn render(
+    mut commands: Commands,
+    mut hooks: ResMut<HookHelper>,
+    current_widget: Res<CurrentWidget>,
+    mut query: Query<(
+        &TagInput,
+        &TagInputStyles,
+        &mut WoodpeckerStyle,
+        &mut WidgetChildren,
+    )>,
+    state_query: Query<&TagInputState>,
+) {
+    let Ok((tag_input, tag_input_styles, mut styles, mut children)) =
+        query.get_mut(**current_widget)
+    else {
+        return;
+    };
+
+    let mut initial_tags = vec![];
+    for tag in tag_input.initial_tags.iter() {
+        add_tag(&mut initial_tags, tag);
+    }
+    let state_entity = hooks.use_state(
+        &mut commands,
+        *current_widget,
+        TagInputState {
+            tags: initial_tags,
+            text: String::new(),
+        },
+    );
+
+    let Ok(state) = state_query.get(state_entity) else {
+        return;
+    };
+
+    *styles = tag_input_styles.background;
+
+    let tag_input_entity = **current_widget;
+    *children = WidgetChildren::default();
+
+    for (i, tag) in state.tags.iter().enumerate() {
+        children.add::<Element>((
+            Element,
+            tag_input_styles.tag,
+            WidgetRender::Quad,
+            WidgetChildren::default()
+                .with_child::<Element>((
+                    Element,
+                    WoodpeckerStyle {
+                        font_size: tag_input_styles.tag.font_size,
+                        color: tag_input_styles.tag.color,
+                        text_wrap: TextWrap::None,
+                        ..Default::default()
+                    },
+                    WidgetRender::Text {
+                        content: tag.clone(),
+                    },
+                ))
+                .with_child::<WButton>((
+                    WButton,
+                    tag_input_styles.remove,
+                    WidgetChildren::default().with_child::<Element>((
+                        Element,
+                        WoodpeckerStyle {
+                            font_size: tag_input_styles.remove.normal.font_size,
+                            color: tag_input_styles.remove.normal.color,
+                            ..Default::default()
+                        },
+                        WidgetRender::Text {
+                            content: "×".into(),
+                        },
+                    )),
+                ))
+                .with_observe(
+                    *current_widget,
+                    move |mut trigger: On<Pointer<Click>>,
+                          mut commands: Commands,
+                          mut state_query: Query<&mut TagInputState>| {
+                        trigger.propagate(false);
+                        let Ok(mut state) = state_query.get_mut(state_entity) else {
+                            return;
+                        };
+                        if i < state.tags.len() {
+                            state.tags.remove(i);
+                            tags_changed(&mut commands, tag_input_entity, &state);
+                        }
+                    },
+                ),
+        ));
+        children.add_key(tag.to_lowercase());
+    }
+
+    children.add::<Element>((
+        Element,
+        tag_input_styles.input,
+        WidgetChildren::default()
+            // The typed text isn't fed back, that would reset the textbox and its undo history.
+            .with_child::<TextBox>(TextBox::default())
+            .with_observe(
+                *current_widget,
+                move |mut trigger: On<Change<TextChanged>>,
+                      mut commands: Commands,
+                      mut state_query: Query<&mut TagInputState>| {
+                    // Consumers listen for TagsChanged instead.
+                    trigger.propagate(false);
+                    let Ok(mut state) = state_query.get_mut(state_entity) else {
+                        return;
+                    };
+                    let (text, changed) = take_tags(&mut state.tags, &trigger.data.value);
+                    if text != trigger.data.value {
+                        commands.trigger(SetTextBoxValue {
+                            target: trigger.target,
+                            value: text.clone(),
+                        });
+                    }
+                    state.text = text;
+                    if changed {
+                        tags_changed(&mut commands, tag_input_entity, &state);
+                    }
+                },
+            )
+            .with_observe(
+                *current_widget,
+                move |trigger: On<WidgetKeyboardButtonEvent>,
+                      mut commands: Commands,
+                      mut state_query: Query<&mut TagInputState>| {
+                    let Ok(mut state) = state_query.get_mut(state_entity) else {
+                        return;
+                    };
+                    match trigger.code {
+                        KeyCode::Enter => {
+                            let text = state.text.clone();
+                            if add_tag(&mut state.tags, &text) {
+                                state.text.clear();
+                                commands.trigger(SetTextBoxValue {
+                                    target: trigger.target,
+                                    value: String::new(),
+                                });
+                                tags_changed(&mut commands, tag_input_entity, &state);
+                            }
+                        }
+                        // The text is updated after this key press, so empty means it was already empty.
+                        KeyCode::Backspace if state.text.is_empty() => {
+                            if state.tags.pop().is_some() {
+                                tags_changed(&mut commands, tag_input_entity, &state);
+                            }
+                        }
+                        _ => {}
+                    }
+                },
+            ),
+    ));
+
+    children.apply(current_widget.as_parent());
+}
+
+#[test]
+fn test_tags() {
+    let mut tags = vec![];
+    assert!(add_tag(&mut tags, " rust "));
+    assert!(!add_tag(&mut tags, "Rust"));
+    assert!(!add_tag(&mut tags, "  "));
+    assert_eq!(tags, vec!["rust".to_string()]);
+
+    assert_eq!(take_tags(&mut tags, "bevy"), ("bevy".to_string(), false));
+    assert_eq!(
+        take_tags(&mut tags, "bevy, ui,RUST, wood"),
+        ("wood".to_string(), true)
+    );
+    assert_eq!(tags, vec!["rust", "bevy", "ui"]);
+    assert_eq!(take_tags(&mut tags, "bevy,"), (String::new(), false));
+}
@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
 use bevy_vello::vello::{
     kurbo::{Affine, Vec2},
@@ -15,11 +15,28 @@ use crate::{
 };
 use bevy::{
     prelude::*,
+    tasks::AsyncComputeTaskPool,
     window::CursorIcon,
     window::{PrimaryWindow, SystemCursorIcon},
 };
 
-use super::{colors, Clip, Element};
+use super::{
+    autocomplete::{AutocompleteState, SuggestionProvider},
+    code_editor::{
+        auto_indent, char_geometry, find_matching_bracket, gutter_text, indent_lines,
+        outdent_lines, toggle_line_comment, CodeEditorSettings,
+    },
+    colors,
+    diagnostics::{
+        diagnostic_at, diagnostics_geometry, gutter_icon, gutter_markers, squiggle, Diagnostic,
+    },
+    find_replace::{
+        find_matches, range_geometry, replace_all, replace_match, FindOptions, FindState,
+    },
+    syntax_highlighting::shift_highlighted,
+    undo::{EditKind, TextSnapshot, UndoStack},
+    ButtonStyles, Clip, Element, WButton,
+};
 
 /// A textbox change event.
 #[derive(Debug, Clone, Reflect)]
@@ -28,6 +45,40 @@ pub struct TextChanged {
     pub value: String,
 }
 
+/// Replaces the text of a [`TextBox`], ex: a parent widget resetting the text the user typed.
+///
+/// Unlike changing [`TextBox::initial_value`] this keeps the undo history, so the replaced
+/// text can be restored with undo. No [`TextChanged`] is triggered.
+#[derive(Clone, PartialEq, Debug, Reflect, EntityEvent)]
+pub struct SetTextBoxValue {
+    /// The textbox
+    #[event_target]
+    pub target: Entity,
+    /// The new text
+    pub value: String,
+}
+
+/// Shows text owned by a parent widget in its [`TextBox`], ex: after committing typed text.
+///
+/// `synced` is what the parent passes as [`TextBox::initial_value`] and only changes here, so
+/// typing doesn't reset the textbox. When it already is `text` the textbox may still show typed
+/// text, which is replaced with [`SetTextBoxValue`].
+pub(crate) fn sync_text_box(
+    commands: &mut Commands,
+    synced: &mut String,
+    text: &str,
+    text_box: Option<Entity>,
+) {
+    if synced != text {
+        *synced = text.to_string();
+    } else if let Some(text_box) = text_box {
+        commands.trigger(SetTextBoxValue {
+            target: text_box,
+            value: text.to_string(),
+        });
+    }
+}
+
 /// A collection of textbox styles.
 #[derive(Component, Clone, PartialEq)]
 pub struct TextboxStyles {
@@ -39,6 +90,16 @@ pub struct TextboxStyles {
     pub focused: WoodpeckerStyle,
     /// Cursor styles
     pub cursor: WoodpeckerStyle,
+    /// Find match highlight styles, the background color is used.
+    pub find_match: WoodpeckerStyle,
+    /// Autocomplete popup styles
+    pub suggestions: WoodpeckerStyle,
+    /// Autocomplete suggestion styles
+    pub suggestion: WoodpeckerStyle,
+    /// Highlighted autocomplete suggestion styles, also used on hover.
+    pub suggestion_selected: WoodpeckerStyle,
+    /// Diagnostic tooltip styles, the border color is replaced by the severity color.
+    pub diagnostic_tooltip: WoodpeckerStyle,
 }
 
 impl Default for TextboxStyles {
@@ -69,6 +130,49 @@ impl Default for TextboxStyles {
                 height: (shared.height.value_or(26.0) - 10.0).into(),
                 ..Default::default()
             },
+            find_match: WoodpeckerStyle {
+                background_color: Srgba::new(0.871, 0.592, 0.592, 0.35).into(),
+                ..Default::default()
+            },
+            suggestions: WoodpeckerStyle {
+                position: WidgetPosition::Absolute,
+                flex_direction: WidgetFlexDirection::Column,
+                min_width: 150.0.into(),
+                padding: Edge::all(2.0),
+                border_radius: Corner::all(4.0),
+                background_color: colors::BACKGROUND_MID,
+                ..Default::default()
+            },
+            suggestion: WoodpeckerStyle {
+                width: Units::Percentage(100.0),
+                padding: Edge::all(0.0).left(6.0).right(6.0),
+                height: 22.0.into(),
+                font_size: 14.0,
+                align_items: Some(WidgetAlignItems::Center),
+                border_radius: Corner::all(3.0),
+                background_color: colors::BACKGROUND_MID,
+                ..Default::default()
+            },
+            suggestion_selected: WoodpeckerStyle {
+                width: Units::Percentage(100.0),
+                padding: Edge::all(0.0).left(6.0).right(6.0),
+                height: 22.0.into(),
+                font_size: 14.0,
+                align_items: Some(WidgetAlignItems::Center),
+                border_radius: Corner::all(3.0),
+                background_color: colors::PRIMARY,
+                ..Default::default()
+            },
+            diagnostic_tooltip: WoodpeckerStyle {
+                position: WidgetPosition::Absolute,
+                max_width: 400.0.into(),
+                padding: Edge::all(6.0),
+                border: Edge::all(0.0).left(3.0),
+                border_radius: Corner::all(3.0),
+                background_color: colors::BACKGROUND_MID,
+                font_size: 12.0,
+                ..Default::default()
+            },
         }
     }
 }
@@ -100,6 +204,7 @@ pub struct TextBox {
     /// An initial value
     pub initial_value: String,
     /// Indicates this is a multi-line text editor.
+    /// Multi-line textboxes support find(ctrl+f) and replace(ctrl+h).
     pub multi_line: bool,
     /// Optional text highlighting used for syntax highlighting or other
     /// text coloring.
@@ -107,18 +212,43 @@ pub struct TextBox {
     pub text_highlighting: ApplyHighlighting,
     /// The tab behavior. Defaults to 4 spaces.
     pub tab_mode: TabMode,
+    /// Turns a multi-line textbox into a code editor with line numbers,
+    /// bracket matching, auto-indent and more.
+    pub code_editor: Option<CodeEditorSettings>,
+    /// Optional autocomplete suggestions for the word being typed.
+    /// Arrows navigate the popup, Enter/Tab accept and Escape dismisses.
+    #[reflect(ignore)]
+    pub suggestions: Option<SuggestionProvider>,
+    /// Diagnostics(ex: compiler errors) shown as squiggly underlines,
+    /// with the message shown on hover and an icon in the code editor gutter.
+    pub diagnostics: Vec<Diagnostic>,
 }
 
 /// Applies color highlighting to the text.
 #[derive(Clone)]
 pub struct ApplyHighlighting {
     inner: Arc<dyn Fn(&str) -> Option<Highlighted> + Send + Sync + 'static>,
+    background: bool,
 }
 
 impl ApplyHighlighting {
     /// Creates a new color highlighting applier.
     pub fn new(f: impl Fn(&str) -> Option<Highlighted> + Send + Sync + 'static) -> Self {
-        Self { inner: Arc::new(f) }
+        Self {
+            inner: Arc::new(f),
+            background: false,
+        }
+    }
+
+    /// Creates a new color highlighting applier that runs on a background task.
+    ///
+    /// Until the new highlighting is ready the previous highlighting is shown,
+    /// shifted to match the edited text.
+    pub fn background(f: impl Fn(&str) -> Option<Highlighted> + Send + Sync + 'static) -> Self {
+        Self {
+            inner: Arc::new(f),
+            background: true,
+        }
     }
 }
 
@@ -132,6 +262,7 @@ impl Default for ApplyHighlighting {
     fn default() -> Self {
         Self {
             inner: Arc::new(|_| None),
+            background: false,
         }
     }
 }
@@ -161,6 +292,21 @@ pub struct TextBoxState {
     pub engine: parley::PlainEditor<Brush>,
     /// Indicates this is a multi-line text editor.
     pub multi_line: bool,
+    /// Undo/redo history.
+    pub history: UndoStack,
+    /// Find/replace state, multi-line only.
+    pub find: FindState,
+    /// Autocomplete popup state.
+    pub autocomplete: AutocompleteState,
+    /// The index of the diagnostic under the pointer.
+    pub hovered_diagnostic: Option<usize>,
+    diagnostic_geometry: Vec<Vec<parley::BoundingBox>>,
+    /// The latest background highlighting and the text it was computed for.
+    pub highlighted: Option<(String, Highlighted)>,
+    highlight_requested: Option<String>,
+    highlight_generation: u64,
+    highlight_applied: u64,
+    highlight_results: Arc<Mutex<Option<(u64, String, Option<Highlighted>)>>>,
 }
 
 // TODO: Remove once Parley is updated.
@@ -175,6 +321,10 @@ impl PartialEq for TextBoxState {
             && self.selections == other.selections
             && self.cursor_visible == other.cursor_visible
             && self.current_value == other.current_value
+            && self.find == other.find
+            && self.highlighted == other.highlighted
+            && self.autocomplete == other.autocomplete
+            && self.hovered_diagnostic == other.hovered_diagnostic
     }
 }
 
@@ -191,6 +341,16 @@ impl Default for TextBoxState {
             initial_value: String::new(),
             engine: parley::PlainEditor::new(0.0),
             multi_line: false,
+            history: UndoStack::default(),
+            find: FindState::default(),
+            autocomplete: AutocompleteState::default(),
+            hovered_diagnostic: None,
+            diagnostic_geometry: vec![],
+            highlighted: None,
+            highlight_requested: None,
+            highlight_generation: 0,
+            highlight_applied: 0,
+            highlight_results: Default::default(),
         }
     }
 }
@@ -199,8 +359,9 @@ pub fn render(
     mut commands: Commands,
     current_widget: Res<CurrentWidget>,
     mut hook_helper: ResMut<HookHelper>,
-    font_manager: Res<FontManager>,
+    mut font_manager: ResMut<FontManager>,
     default_font: Res<DefaultFont>,
+    asset_server: Res<AssetServer>,
     mut query: Query<(
         Ref<TextBox>,
         &mut WoodpeckerStyle,
@@ -215,6 +376,7 @@ pub fn render(
     };
 
     let tab_mode = text_box.tab_mode;
+    let code_editor = text_box.code_editor.clone().filter(|_| text_box.multi_line);
 
     let mut default_engine = parley::PlainEditor::new(styles.normal.font_size);
     default_engine.set_text(&text_box.initial_value);
@@ -253,13 +415,19 @@ pub fn render(
     };
 
     if let Ok(layout) = widget_layout.get(current_widget.entity()) {
-        state.engine.set_width(Some(layout.size.x));
+        let gutter_width = code_editor
+            .as_ref()
+            .filter(|settings| settings.line_numbers)
+            .map(|settings| settings.gutter_width)
+            .unwrap_or(0.0);
+        state.engine.set_width(Some(layout.size.x - gutter_width));
     }
 
     if text_box.initial_value != state.initial_value {
         state.initial_value = text_box.initial_value.clone();
         state.current_value.clone_from(&text_box.initial_value);
         state.engine.set_text(&text_box.initial_value);
+        state.history.clear();
 
         state.selections = state.engine.selection_geometry();
         state.cursor = state
@@ -300,6 +468,68 @@ pub fn render(
         };
     }
 
+    if let Some(settings) = code_editor
+        .as_ref()
+        .filter(|settings| settings.line_numbers)
+    {
+        style.padding.left = (style.padding.left.value_or(0.0) + settings.gutter_width).into();
+    }
+
+    // Code editor visuals(line numbers, current line and bracket matching), find matches
+    // and diagnostics.
+    let show_find = state.find.open && text_box.multi_line;
+    let mut gutter = None;
+    let mut bracket_matches = vec![];
+    let mut find_highlights = vec![];
+    let mut find_match_count = 0;
+    let mut diagnostic_markers = vec![];
+    if code_editor.is_some() || show_find || !text_box.diagnostics.is_empty() {
+        let state = &mut *state;
+        let text = state.current_value.clone();
+        let cursor_index = state.engine.raw_selection().focus().index();
+        let font_manager = &mut *font_manager;
+        let layout = state
+            .engine
+            .layout(&mut font_manager.font_cx, &mut font_manager.layout_cx);
+        if let Some(settings) = code_editor.as_ref() {
+            if settings.line_numbers {
+                gutter = Some(gutter_text(layout, &text));
+            }
+            if let Some((a, b)) = find_matching_bracket(&text, cursor_index, &settings.brackets) {
+                bracket_matches.extend(
+                    [a, b]
+                        .into_iter()
+                        .filter_map(|index| char_geometry(layout, &text, index)),
+                );
+            }
+        }
+        if show_find {
+            let matches = find_matches(&text, &state.find.query, state.find.options);
+            find_match_count = matches.len();
+            find_highlights = matches
+                .into_iter()
+                .flat_map(|range| range_geometry(layout, range))
+                .collect::<Vec<_>>();
+        }
+        state.diagnostic_geometry = diagnostics_geometry(layout, &text, &text_box.diagnostics);
+        diagnostic_markers = gutter_markers(&state.diagnostic_geometry, &text_box.diagnostics);
+    } else if !state.diagnostic_geometry.is_empty() {
+        state.diagnostic_geometry.clear();
+    }
+    if state
+        .hovered_diagnostic
+        .is_some_and(|i| i >= text_box.diagnostics.len())
+    {
+        state.hovered_diagnostic = None;
+    }
+
+    // Single line text is centered vertically.
+    let text_offset_y = if text_box.multi_line {
+        0.0
+    } else {
+        (styles.normal.height.value_or(styles.normal.font_size) - styles.normal.font_size) / 2.0
+    };
+
     let cursor_styles = WoodpeckerStyle {
         top: (state.cursor.y0.min(state.cursor.y1) as f32
             + if text_box.multi_line {
@@ -314,6 +544,9 @@ pub fn render(
     };
 
     let current_widget = *current_widget;
+    let keyboard_code_editor = code_editor.clone();
+    let char_suggestions = text_box.suggestions.clone();
+    let keyboard_suggestions = text_box.suggestions.clone();
     *children = WidgetChildren::default()
         .with_observe(
             current_widget,
@@ -337,6 +570,7 @@ pub fn render(
                     return;
                 }
 
+                state.record_undo(EditKind::Insert);
                 let mut driver = font_manager.driver(&mut state.engine);
                 driver.insert_or_replace_selection(&trigger.c);
 
@@ -347,6 +581,14 @@ pub fn render(
                 state.selections = state.engine.selection_geometry();
                 state.current_value = state.engine.text().to_string();
 
+                if let Some(provider) = char_suggestions.as_ref() {
+                    let state = &mut *state;
+                    let cursor = state.engine.raw_selection().focus().index();
+                    state
+                        .autocomplete
+                        .update(&state.current_value, cursor, provider);
+                }
+
                 commands.trigger(
                     Change {
                         target: *current_widget,
@@ -386,6 +628,8 @@ pub fn render(
                 if !mouse_input.just_pressed(MouseButton::Left) {
                     return;
                 }
+                state.history.break_group();
+                state.autocomplete.close();
 
                 let mut driver = font_manager.driver(&mut state.engine);
 
@@ -534,6 +778,63 @@ pub fn render(
                 state.selections = state.engine.selection_geometry();
             },
         )
+        .with_observe(
+            current_widget,
+            move |trigger: On<Pointer<Move>>,
+                  text_box_query: Query<&TextBox>,
+                  widget_layout: Query<&WidgetLayout>,
+                  window: Single<(Entity, &Window), With<PrimaryWindow>>,
+                  camera: Query<&Camera, With<WoodpeckerView>>,
+                  mut state_query: Query<&mut TextBoxState>| {
+                let Ok(text_box) = text_box_query.get(*current_widget) else {
+                    return;
+                };
+                let Ok(widget_layout) = widget_layout.get(*current_widget) else {
+                    return;
+                };
+                let Ok(state) = state_query.get(state_entity) else {
+                    return;
+                };
+                if state.diagnostic_geometry.is_empty() && state.hovered_diagnostic.is_none() {
+                    return;
+                }
+                let Some(camera) = camera.iter().next() else {
+                    return;
+                };
+
+                let (offset, size, _scale) = compute_letterboxed_transform(
+                    window.1.size(),
+                    camera.logical_target_size().unwrap(),
+                );
+
+                let cursor_pos_world = ((trigger.pointer_location.position - offset) / size)
+                    * camera.logical_target_size().unwrap();
+                let point = bevy::prelude::Vec2::new(
+                    cursor_pos_world.x
+                        - widget_layout.location.x
+                        - widget_layout.padding.left.value_or(0.0),
+                    cursor_pos_world.y
+                        - widget_layout.location.y
+                        - widget_layout.padding.top.value_or(0.0),
+                );
+                let has_gutter = text_box.multi_line
+                    && text_box
+                        .code_editor
+                        .as_ref()
+                        .is_some_and(|settings| settings.line_numbers);
+
+                let hovered = if point.x < 0.0 && !has_gutter {
+                    None
+                } else {
+                    diagnostic_at(&state.diagnostic_geometry, point, !text_box.multi_line)
+                };
+                if hovered != state.hovered_diagnostic {
+                    if let Ok(mut state) = state_query.get_mut(state_entity) {
+                        state.hovered_diagnostic = hovered;
+                    }
+                }
+            },
+        )
         .with_observe(
             current_widget,
             move |_trigger: On<Pointer<Over>>,
@@ -564,12 +865,34 @@ pub fn render(
                 if !state.focused {
                     state.hovering = false;
                 }
+                state.hovered_diagnostic = None;
 
                 commands
                     .entity(camera_query.single().unwrap())
                     .insert(CursorIcon::from(SystemCursorIcon::Default));
             },
         )
+        .with_observe(
+            current_widget,
+            move |trigger: On<SetTextBoxValue>,
+                  style_query: Query<&WoodpeckerStyle>,
+                  mut font_manager: ResMut<FontManager>,
+                  mut state_query: Query<&mut TextBoxState>| {
+                let Ok(styles) = style_query.get(trigger.target) else {
+                    return;
+                };
+                let Ok(mut state) = state_query.get_mut(state_entity) else {
+                    return;
+                };
+                if state.current_value == trigger.value {
+                    return;
+                }
+                state.record_undo(EditKind::Other);
+                let end = trigger.value.len();
+                replace_text(&mut state, &mut font_manager, &trigger.value, end..end);
+                sync_from_engine(&mut state, styles.font_size);
+            },
+        )
         .with_observe(
             current_widget,
             move |_trigger: On<WidgetFocus>, mut state_query: Query<&mut TextBoxState>| {
@@ -595,6 +918,10 @@ pub fn render(
 
                 state.hovering = false;
                 state.focused = false;
+                // Clicking a suggestion blurs the textbox, keep the popup open for the click.
+                if !state.autocomplete.hovering {
+                    state.autocomplete.close();
+                }
 
                 let mut driver = font_manager.driver(&mut state.engine);
                 driver.move_to_text_start();
@@ -619,6 +946,7 @@ pub fn render(
                     return;
                 };
 
+                state.record_undo(EditKind::Other);
                 let mut driver = font_manager.driver(&mut state.engine);
                 driver.insert_or_replace_selection(&trigger.paste.to_string());
 
@@ -657,12 +985,257 @@ pub fn render(
                     keyboard_input,
                     state_entity,
                     tab_mode,
+                    keyboard_code_editor.as_ref(),
+                    keyboard_suggestions.as_ref(),
                 );
             },
         );
 
+    if let (Some(settings), Some(gutter)) = (code_editor.as_ref(), gutter) {
+        children.add::<Element>((
+            Element,
+            WoodpeckerStyle {
+                position: WidgetPosition::Absolute,
+                left: 0.0.into(),
+                top: 0.0.into(),
+                width: settings.gutter_width.into(),
+                height: Units::Percentage(100.0),
+                background_color: settings.gutter_background,
+                padding: Edge::new(style.padding.top, 8.0, 0.0, 0.0),
+                ..Default::default()
+            },
+            WidgetRender::Quad,
+            WidgetChildren::default()
+                .with_child::<Element>((
+                    Element,
+                    WoodpeckerStyle {
+                        width: Units::Percentage(100.0),
+                        font: style.font,
+                        font_size: style.font_size,
+                        color: settings.gutter_color,
+                        text_alignment: Some(TextAlign::Right),
+                        text_wrap: TextWrap::None,
+                        ..Default::default()
+                    },
+                    WidgetRender::Text { content: gutter },
+                ))
+                .with_child::<Element>((
+                    Element,
+                    WoodpeckerStyle {
+                        position: WidgetPosition::Absolute,
+                        left: 0.0.into(),
+                        top: style.padding.top,
+                        width: settings.gutter_width.into(),
+                        height: Units::Percentage(100.0),
+                        ..Default::default()
+                    },
+                    WidgetRender::Custom {
+                        render: WidgetRenderCustom::new(
+                            move |scene, widget_layout, _styles, scale| {
+                                let transform = Affine::default()
+                                    .with_translation(Vec2::new(
+                                        (widget_layout.location.x * scale) as f64,
+                                        (widget_layout.location.y * scale) as f64,
+                                    ))
+                                    .pre_scale(scale as f64);
+                                for (line, severity) in diagnostic_markers.iter() {
+                                    let color = severity.color().to_srgba();
+                                    scene.fill(
+                                        vello::peniko::Fill::NonZero,
+                                        transform,
+                                        &Brush::Solid(vello::peniko::Color::new([
+                                            color.red,
+                                            color.green,
+                                            color.blue,
+                                            color.alpha,
+                                        ])),
+                                        None,
+                                        &gutter_icon(
+                                            *severity,
+                                            bevy_vello::prelude::kurbo::Point::new(
+                                                8.0,
+                                                (line.y0 + line.y1) / 2.0,
+                                            ),
+                                        ),
+                                    );
+                                }
+                            },
+                        ),
+                    },
+                )),
+        ));
+    }
+
+    let text_highlight = if text_box.text_highlighting.background {
+        request_background_highlighting(&mut state, &text_box.text_highlighting);
+        state.highlighted.as_ref().map(|(text, highlighted)| {
+            if *text == state.current_value {
+                highlighted.clone()
+            } else {
+                shift_highlighted(highlighted, text, &state.current_value)
+            }
+        })
+    } else {
+        (text_box.text_highlighting.inner)(&state.current_value)
+    };
+
     let mut clip_children = WidgetChildren::default();
 
+    if let Some(settings) = code_editor.as_ref() {
+        let current_line = state.focused.then_some(state.cursor);
+        let current_line_color = settings.current_line_color.to_srgba();
+        let bracket_match_color = settings.bracket_match_color.to_srgba();
+        clip_children.add::<Element>((
+            Element,
+            WoodpeckerStyle {
+                position: WidgetPosition::Absolute,
+                left: 0.0.into(),
+                top: 0.0.into(),
+                width: Units::Percentage(100.0),
+                height: Units::Percentage(100.0),
+                ..Default::default()
+            },
+            WidgetRender::Custom {
+                render: WidgetRenderCustom::new(move |scene, widget_layout, _styles, scale| {
+                    let transform = Affine::default()
+                        .with_translation(Vec2::new(
+                            (widget_layout.location.x * scale) as f64,
+                            (widget_layout.location.y * scale) as f64,
+                        ))
+                        .pre_scale(scale as f64);
+                    let mut fill = |color: Srgba, rect: bevy_vello::prelude::kurbo::Rect| {
+                        scene.fill(
+                            vello::peniko::Fill::NonZero,
+                            transform,
+                            &Brush::Solid(vello::peniko::Color::new([
+                                color.red,
+                                color.green,
+                                color.blue,
+                                color.alpha,
+                            ])),
+                            None,
+                            &rect,
+                        );
+                    };
+                    if let Some(cursor) = current_line {
+                        fill(
+                            current_line_color,
+                            bevy_vello::prelude::kurbo::Rect {
+                                x0: 0.0,
+                                y0: cursor.y0.min(cursor.y1),
+                                x1: widget_layout.size.x as f64,
+                                y1: cursor.y0.max(cursor.y1),
+                            },
+                        );
+                    }
+                    for bracket in bracket_matches.iter() {
+                        fill(
+                            bracket_match_color,
+                            bevy_vello::prelude::kurbo::Rect {
+                                x0: bracket.x0,
+                                y0: bracket.y0,
+                                x1: bracket.x1,
+                                y1: bracket.y1,
+                            },
+                        );
+                    }
+                }),
+            },
+        ));
+    }
+
+    if !find_highlights.is_empty() {
+        clip_children.add::<Element>((
+            Element,
+            WoodpeckerStyle {
+                position: WidgetPosition::Absolute,
+                left: 0.0.into(),
+                top: 0.0.into(),
+                width: Units::Percentage(100.0),
+                height: Units::Percentage(100.0),
+                background_color: styles.find_match.background_color,
+                ..Default::default()
+            },
+            WidgetRender::Custom {
+                render: WidgetRenderCustom::new(move |scene, widget_layout, styles, scale| {
+                    let transform = Affine::default()
+                        .with_translation(Vec2::new(
+                            (widget_layout.location.x * scale) as f64,
+                            (widget_layout.location.y * scale) as f64,
+                        ))
+                        .pre_scale(scale as f64);
+                    let color = styles.background_color.to_srgba();
+                    for highlight in find_highlights.iter() {
+                        scene.fill(
+                            vello::peniko::Fill::NonZero,
+                            transform,
+                            &Brush::Solid(vello::peniko::Color::new([
+                                color.red,
+                                color.green,
+                                color.blue,
+                                color.alpha,
+                            ])),
+                            None,
+                            &bevy_vello::prelude::kurbo::Rect {
+                                x0: highlight.x0,
+                                y0: highlight.y0,
+                                x1: highlight.x1,
+                                y1: highlight.y1,
+                            },
+                        );
+                    }
+                }),
+            },
+        ));
+    }
+
+    if !state.diagnostic_geometry.is_empty() {
+        let squiggles = state
+            .diagnostic_geometry
+            .iter()
+            .zip(text_box.diagnostics.iter())
+            .flat_map(|(boxes, diagnostic)| {
+                let color = diagnostic.severity.color().to_srgba();
+                boxes.iter().map(move |bounds| (*bounds, color))
+            })
+            .collect::<Vec<_>>();
+        clip_children.add::<Element>((
+            Element,
+            WoodpeckerStyle {
+                position: WidgetPosition::Absolute,
+                left: 0.0.into(),
+                top: text_offset_y.into(),
+                width: Units::Percentage(100.0),
+                height: Units::Percentage(100.0),
+                ..Default::default()
+            },
+            WidgetRender::Custom {
+                render: WidgetRenderCustom::new(move |scene, widget_layout, _styles, scale| {
+                    let transform = Affine::default()
+                        .with_translation(Vec2::new(
+                            (widget_layout.location.x * scale) as f64,
+                            (widget_layout.location.y * scale) as f64,
+                        ))
+                        .pre_scale(scale as f64);
+                    for (bounds, color) in squiggles.iter() {
+                        scene.stroke(
+                            &bevy_vello::prelude::kurbo::Stroke::new(1.0),
+                            transform,
+                            &Brush::Solid(vello::peniko::Color::new([
+                                color.red,
+                                color.green,
+                                color.blue,
+                                color.alpha,
+                            ])),
+                            None,
+                            &squiggle(bounds.x0, bounds.x1, bounds.y1 - 1.5),
+                        );
+                    }
+                }),
+            },
+        ));
+    }
+
     clip_children.add::<Element>((
         Element,
         WoodpeckerStyle {
@@ -683,7 +1256,7 @@ pub fn render(
             z_index: Some(WidgetZ::Relative(2)),
             ..Default::default()
         },
-        if let Some(text_highlight) = (text_box.text_highlighting.inner)(&state.current_value) {
+        if let Some(text_highlight) = text_highlight {
             WidgetRender::RichText {
                 content: RichText::from_hightlighted(&state.current_value, text_highlight),
             }
@@ -699,7 +1272,11 @@ pub fn render(
         let Ok(layout) = widget_layout.get(current_widget.entity()) else {
             return;
         };
-        let pos = layout.location;
+        let pos = layout.location
+            + bevy::prelude::Vec2::new(
+                style.padding.left.value_or(0.0),
+                style.padding.top.value_or(0.0),
+            );
         clip_children.add::<Element>((
             Element,
             WoodpeckerStyle {
@@ -752,9 +1329,513 @@ pub fn render(
 
     children.add::<Clip>((Clip, clip_styles, clip_children));
 
+    if show_find {
+        children
+            .add::<Element>((
+                Element,
+                WoodpeckerStyle {
+                    position: WidgetPosition::Absolute,
+                    top: 4.0.into(),
+                    right: 4.0.into(),
+                    flex_direction: WidgetFlexDirection::Column,
+                    padding: Edge::all(4.0),
+                    border_radius: Corner::all(4.0),
+                    background_color: colors::BACKGROUND_MID,
+                    ..Default::default()
+                },
+                WidgetRender::Quad,
+                find_bar(
+                    current_widget,
+                    state_entity,
+                    &state.find,
+                    find_match_count,
+                    &asset_server,
+                ),
+            ))
+            // Keep clicks in the find bar from moving the textbox cursor.
+            .observe(current_widget, |mut trigger: On<Pointer<Press>>| {
+                trigger.propagate(false);
+            })
+            .observe(current_widget, |mut trigger: On<Pointer<DragStart>>| {
+                trigger.propagate(false);
+            })
+            .observe(current_widget, |mut trigger: On<Pointer<Drag>>| {
+                trigger.propagate(false);
+            });
+    }
+
+    let hovered_diagnostic = state.hovered_diagnostic.and_then(|i| {
+        let bounds = state.diagnostic_geometry.get(i)?.first()?;
+        Some((text_box.diagnostics.get(i)?, *bounds))
+    });
+    if let Some((diagnostic, bounds)) = hovered_diagnostic {
+        // Show the message just below the underline.
+        children.add::<Element>((
+            Element,
+            WoodpeckerStyle {
+                left: (style.padding.left.value_or(0.0) + bounds.x0 as f32).into(),
+                top: (style.padding.top.value_or(0.0) + text_offset_y + bounds.y1 as f32 + 2.0)
+                    .into(),
+                border_color: diagnostic.severity.color(),
+                z_index: Some(WidgetZ::Global(1000)),
+                ..styles.diagnostic_tooltip
+            },
+            WidgetRender::Quad,
+            WidgetChildren::default().with_child::<Element>((
+                Element,
+                WoodpeckerStyle {
+                    font_size: styles.diagnostic_tooltip.font_size,
+                    color: styles.diagnostic_tooltip.color,
+                    text_wrap: TextWrap::WordOrGlyph,
+                    ..Default::default()
+                },
+                WidgetRender::Text {
+                    content: diagnostic.message.clone(),
+                },
+            )),
+        ));
+    }
+
+    if state.autocomplete.open {
+        // Anchor the popup just below the cursor.
+        let cursor_top = cursor_styles.top.value_or(0.0);
+        let cursor_height = (state.cursor.y1 - state.cursor.y0).abs() as f32;
+        children
+            .add::<Element>((
+                Element,
+                WoodpeckerStyle {
+                    left: (style.padding.left.value_or(0.0)
+                        + state.cursor.x0.min(state.cursor.x1) as f32)
+                        .into(),
+                    top: (style.padding.top.value_or(0.0) + cursor_top + cursor_height).into(),
+                    z_index: Some(WidgetZ::Global(1000)),
+                    ..styles.suggestions
+                },
+                WidgetRender::Quad,
+                autocomplete_popup(current_widget, state_entity, &state.autocomplete, styles),
+            ))
+            .observe(
+                current_widget,
+                move |_trigger: On<Pointer<Over>>, mut state_query: Query<&mut TextBoxState>| {
+                    if let Ok(mut state) = state_query.get_mut(state_entity) {
+                        state.autocomplete.hovering = true;
+                    }
+                },
+            )
+            .observe(
+                current_widget,
+                move |_trigger: On<Pointer<Out>>, mut state_query: Query<&mut TextBoxState>| {
+                    if let Ok(mut state) = state_query.get_mut(state_entity) {
+                        state.autocomplete.hovering = false;
+                    }
+                },
+            )
+            // Keep clicks in the popup from moving the textbox cursor.
+            .observe(current_widget, |mut trigger: On<Pointer<Press>>| {
+                trigger.propagate(false);
+            })
+            .observe(current_widget, |mut trigger: On<Pointer<DragStart>>| {
+                trigger.propagate(false);
+            })
+            .observe(current_widget, |mut trigger: On<Pointer<Drag>>| {
+                trigger.propagate(false);
+            });
+    }
+
     children.apply(current_widget.as_parent());
 }
 
+/// Builds the autocomplete popup children.
+fn autocomplete_popup(
+    current_widget: CurrentWidget,
+    state_entity: Entity,
+    autocomplete: &AutocompleteState,
+    styles: &TextboxStyles,
+) -> WidgetChildren {
+    let text_box_entity = *current_widget;
+    let mut children = WidgetChildren::default();
+    for i in autocomplete.visible_range() {
+        let normal = if i == autocomplete.selected {
+            styles.suggestion_selected
+        } else {
+            styles.suggestion
+        };
+        children
+            .add::<WButton>((
+                WButton,
+                ButtonStyles {
+                    normal,
+                    hovered: styles.suggestion_selected,
+                },
+                WidgetChildren::default().with_child::<Element>((
+                    Element,
+                    WoodpeckerStyle {
+                        font_size: normal.font_size,
+                        text_wrap: TextWrap::None,
+                        ..Default::default()
+                    },
+                    WidgetRender::Text {
+                        content: autocomplete.suggestions[i].clone(),
+                    },
+                )),
+            ))
+            .observe(
+                current_widget,
+                move |mut trigger: On<Pointer<Click>>,
+                      mut commands: Commands,
+                      mut current_focus: ResMut<CurrentFocus>,
+                      style_query: Query<&WoodpeckerStyle>,
+                      mut font_manager: ResMut<FontManager>,
+                      mut state_query: Query<&mut TextBoxState>| {
+                    trigger.propagate(false);
+                    let Ok(styles) = style_query.get(text_box_entity) else {
+                        return;
+                    };
+                    let Ok(mut state) = state_query.get_mut(state_entity) else {
+                        return;
+                    };
+                    state.autocomplete.hovering = false;
+                    if accept_suggestion(&mut state, &mut font_manager, styles.font_size, i) {
+                        commands.trigger(Change {
+                            target: text_box_entity,
+                            data: TextChanged {
+                                value: state.current_value.clone(),
+                            },
+                        });
+                    }
+                    // The press blurred the textbox, give it focus back.
+                    current_focus.refocus(&mut commands, text_box_entity);
+                },
+            );
+    }
+    children
+}
+
+fn find_bar_button_styles(active: bool) -> ButtonStyles {
+    let normal = WoodpeckerStyle {
+        background_color: if active {
+            colors::PRIMARY
+        } else {
+            colors::BACKGROUND_LIGHT
+        },
+        border_radius: Corner::all(4.0),
+        margin: Edge::all(0.0).left(4.0),
+        padding: Edge::all(0.0).left(6.0).right(6.0),
+        height: 26.0.into(),
+        font_size: 12.0,
+        justify_content: Some(WidgetAlignContent::Center),
+        align_items: Some(WidgetAlignItems::Center),
+        ..Default::default()
+    };
+    ButtonStyles {
+        normal,
+        hovered: WoodpeckerStyle {
+            background_color: if active {
+                colors::PRIMARY_LIGHT
+            } else {
+                colors::BACKGROUND
+            },
+            ..normal
+        },
+    }
+}
+
+fn find_bar_text(content: impl Into<String>) -> (Element, WoodpeckerStyle, WidgetRender) {
+    (
+        Element,
+        WoodpeckerStyle {
+            font_size: 12.0,
+            text_wrap: TextWrap::None,
+            ..Default::default()
+        },
+        WidgetRender::Text {
+            content: content.into(),
+        },
+    )
+}
+
+/// Builds the find/replace bar children.
+fn find_bar(
+    current_widget: CurrentWidget,
+    state_entity: Entity,
+    find: &FindState,
+    match_count: usize,
+    asset_server: &AssetServer,
+) -> WidgetChildren {
+    let text_box_entity = *current_widget;
+    let row = WoodpeckerStyle {
+        flex_direction: WidgetFlexDirection::Row,
+        align_items: Some(WidgetAlignItems::Center),
+        ..Default::default()
+    };
+    let input = WoodpeckerStyle {
+        width: 200.0.into(),
+        ..Default::default()
+    };
+
+    let mut find_row = WidgetChildren::default();
+    find_row.add::<Element>((
+        Element,
+        input,
+        WidgetChildren::default()
+            .with_child::<TextBox>(TextBox {
+                initial_value: find.synced_query.clone(),
+                ..Default::default()
+            })
+            .with_observe(
+                current_widget,
+                move |mut trigger: On<Change<TextChanged>>,
+                      style_query: Query<&WoodpeckerStyle>,
+                      mut font_manager: ResMut<FontManager>,
+                      mut state_query: Query<&mut TextBoxState>| {
+                    // The query is not a change of the textbox value.
+                    trigger.propagate(false);
+                    let Ok(styles) = style_query.get(text_box_entity) else {
+                        return;
+                    };
+                    let Ok(mut state) = state_query.get_mut(state_entity) else {
+                        return;
+                    };
+                    state.find.query = trigger.data.value.clone();
+                    state.find.query_box = Some(trigger.target);
+                    select_find_match(
+                        &mut state,
+                        &mut font_manager,
+                        styles.font_size,
+                        FindDirection::Current,
+                    );
+                },
+            )
+            .with_observe(
+                current_widget,
+                move |trigger: On<WidgetKeyboardButtonEvent>,
+                      keyboard_input: Res<ButtonInput<KeyCode>>,
+                      style_query: Query<&WoodpeckerStyle>,
+                      mut font_manager: ResMut<FontManager>,
+                      mut state_query: Query<&mut TextBoxState>| {
+                    let Ok(styles) = style_query.get(text_box_entity) else {
+                        return;
+                    };
+                    let Ok(mut state) = state_query.get_mut(state_entity) else {
+                        return;
+                    };
+                    match trigger.code {
+                        KeyCode::Enter => {
+                            let direction = if keyboard_input.pressed(KeyCode::ShiftLeft) {
+                                FindDirection::Previous
+                            } else {
+                                FindDirection::Next
+                            };
+                            select_find_match(
+                                &mut state,
+                                &mut font_manager,
+                                styles.font_size,
+                                direction,
+                            );
+                        }
+                        KeyCode::Escape => state.find.close(),
+                        _ => {}
+                    }
+                },
+            ),
+    ));
+
+    // Match count
+    find_row.add::<Element>((
+        Element,
+        WoodpeckerStyle {
+            margin: Edge::all(0.0).left(6.0).right(2.0),
+            ..Default::default()
+        },
+        WidgetChildren::default().with_child::<Element>(find_bar_text(
+            match (match_count, find.current) {
+                (0, _) => "No results".to_string(),
+                (count, Some(current)) => format!("{} of {count}", current + 1),
+                (count, None) => format!("{count} found"),
+            },
+        )),
+    ));
+
+    // Options
+    let options: [(&str, bool, fn(&mut FindOptions) -> &mut bool); 3] = [
+        ("Aa", find.options.case_sensitive, |options| {
+            &mut options.case_sensitive
+        }),
+        ("W", find.options.whole_word, |options| {
+            &mut options.whole_word
+        }),
+        (".*", find.options.regex, |options| &mut options.regex),
+    ];
+    for (label, active, option) in options {
+        find_row
+            .add::<WButton>((
+                WButton,
+                find_bar_button_styles(active),
+                WidgetChildren::default().with_child::<Element>(find_bar_text(label)),
+            ))
+            .observe(
+                current_widget,
+                move |mut trigger: On<Pointer<Click>>,
+                      style_query: Query<&WoodpeckerStyle>,
+                      mut font_manager: ResMut<FontManager>,
+                      mut state_query: Query<&mut TextBoxState>| {
+                    trigger.propagate(false);
+                    let Ok(styles) = style_query.get(text_box_entity) else {
+                        return;
+                    };
+                    let Ok(mut state) = state_query.get_mut(state_entity) else {
+                        return;
+                    };
+                    let value = option(&mut state.find.options);
+                    *value = !*value;
+                    select_find_match(
+                        &mut state,
+                        &mut font_manager,
+                        styles.font_size,
+                        FindDirection::Current,
+                    );
+                },
+            );
+    }
+
+    // Previous/Next
+    for (icon, direction) in [
+        ("arrow-up.svg", FindDirection::Previous),
+        ("arrow-down.svg", FindDirection::Next),
+    ] {
+        find_row
+            .add::<WButton>((
+                WButton,
+                find_bar_button_styles(false),
+                WidgetChildren::default().with_child::<Element>((
+                    Element,
+                    WoodpeckerStyle {
+                        width: 14.0.into(),
+                        height: 14.0.into(),
+                        ..Default::default()
+                    },
+                    WidgetRender::Svg {
+                        handle: asset_server.load(format!(
+                            "embedded://woodpecker_ui/embedded_assets/icons/{icon}"
+                        )),
+                        color: Some(Color::WHITE),
+                    },
+                )),
+            ))
+            .observe(
+                current_widget,
+                move |mut trigger: On<Pointer<Click>>,
+                      style_query: Query<&WoodpeckerStyle>,
+                      mut font_manager: ResMut<FontManager>,
+                      mut state_query: Query<&mut TextBoxState>| {
+                    trigger.propagate(false);
+                    let Ok(styles) = style_query.get(text_box_entity) else {
+                        return;
+                    };
+                    let Ok(mut state) = state_query.get_mut(state_entity) else {
+                        return;
+                    };
+                    select_find_match(&mut state, &mut font_manager, styles.font_size, direction);
+                },
+            );
+    }
+
+    // Close
+    find_row
+        .add::<WButton>((
+            WButton,
+            find_bar_button_styles(false),
+            WidgetChildren::default().with_child::<Element>(find_bar_text("x")),
+        ))
+        .observe(
+            current_widget,
+            move |mut trigger: On<Pointer<Click>>, mut state_query: Query<&mut TextBoxState>| {
+                trigger.propagate(false);
+                let Ok(mut state) = state_query.get_mut(state_entity) else {
+                    return;
+                };
+                state.find.close();
+            },
+        );
+
+    let mut children = WidgetChildren::default().with_child::<Element>((Element, row, find_row));
+
+    if !find.replace_open {
+        return children;
+    }
+
+    let mut replace_row = WidgetChildren::default();
+    replace_row.add::<Element>((
+        Element,
+        input,
+        WidgetChildren::default()
+            .with_child::<TextBox>(TextBox {
+                initial_value: find.synced_replacement.clone(),
+                ..Default::default()
+            })
+            .with_observe(
+                current_widget,
+                move |mut trigger: On<Change<TextChanged>>,
+                      mut state_query: Query<&mut TextBoxState>| {
+                    trigger.propagate(false);
+                    let Ok(mut state) = state_query.get_mut(state_entity) else {
+                        return;
+                    };
+                    state.find.replacement = trigger.data.value.clone();
+                },
+            ),
+    ));
+
+    for (label, all) in [("Replace", false), ("All", true)] {
+        replace_row
+            .add::<WButton>((
+                WButton,
+                find_bar_button_styles(false),
+                WidgetChildren::default().with_child::<Element>(find_bar_text(label)),
+            ))
+            .observe(
+                current_widget,
+                move |mut trigger: On<Pointer<Click>>,
+                      mut commands: Commands,
+                      style_query: Query<&WoodpeckerStyle>,
+                      mut font_manager: ResMut<FontManager>,
+                      mut state_query: Query<&mut TextBoxState>| {
+                    trigger.propagate(false);
+                    let Ok(styles) = style_query.get(text_box_entity) else {
+                        return;
+                    };
+                    let Ok(mut state) = state_query.get_mut(state_entity) else {
+                        return;
+                    };
+                    let changed = if all {
+                        replace_all_find_matches(&mut state, &mut font_manager, styles.font_size)
+                    } else {
+                        replace_find_match(&mut state, &mut font_manager, styles.font_size)
+                    };
+                    if changed {
+                        commands.trigger(Change {
+                            target: text_box_entity,
+                            data: TextChanged {
+                                value: state.current_value.clone(),
+                            },
+                        });
+                    }
+                },
+            );
+    }
+
+    children.add::<Element>((
+        Element,
+        WoodpeckerStyle {
+            margin: Edge::all(0.0).top(4.0),
+            ..row
+        },
+        replace_row,
+    ));
+
+    children
+}
+
 // IMPORTANT: When modifying widget entities we need to verify we aren't modifying previous widget values.
 pub fn cursor_animation_system(
     mut state_query: ParamSet<(
@@ -779,6 +1860,47 @@ pub fn cursor_animation_system(
     }
 }
 
+/// Spawns a background highlighting task if the text changed since the last request.
+fn request_background_highlighting(state: &mut TextBoxState, highlighting: &ApplyHighlighting) {
+    if state.highlight_requested.as_ref() == Some(&state.current_value) {
+        return;
+    }
+    state.highlight_requested = Some(state.current_value.clone());
+    state.highlight_generation += 1;
+
+    let generation = state.highlight_generation;
+    let text = state.current_value.clone();
+    let inner = highlighting.inner.clone();
+    let results = state.highlight_results.clone();
+    AsyncComputeTaskPool::get()
+        .spawn(async move {
+            let highlighted = inner(&text);
+            let mut results = results.lock().unwrap();
+            // Don't let an older task overwrite a newer result.
+            if !matches!(&*results, Some((newer, ..)) if *newer > generation) {
+                *results = Some((generation, text, highlighted));
+            }
+        })
+        .detach();
+}
+
+// IMPORTANT: When modifying widget entities we need to verify we aren't modifying previous widget values.
+pub fn apply_background_highlighting(
+    mut state_query: Query<&mut TextBoxState, Without<PreviousWidget>>,
+) {
+    for mut state in state_query.iter_mut() {
+        let Some((generation, text, highlighted)) = state.highlight_results.lock().unwrap().take()
+        else {
+            continue;
+        };
+        if generation <= state.highlight_applied {
+            continue;
+        }
+        state.highlight_applied = generation;
+        state.highlighted = highlighted.map(|highlighted| (text, highlighted));
+    }
+}
+
 pub fn textbox_handle_keyboard_events(
     trigger: On<WidgetKeyboardButtonEvent>,
     mut commands: Commands,
@@ -788,7 +1910,143 @@ pub fn textbox_handle_keyboard_events(
     keyboard_input: Res<ButtonInput<KeyCode>>,
     state_entity: Entity,
     tab_mode: TabMode,
+    code_editor: Option<&CodeEditorSettings>,
+    suggestions: Option<&SuggestionProvider>,
 ) {
+    let ctrl =
+        keyboard_input.pressed(KeyCode::SuperLeft) || keyboard_input.pressed(KeyCode::ControlLeft);
+
+    // The autocomplete popup takes the navigation keys while it's open.
+    if let Ok(mut state) = state_query.get_mut(state_entity) {
+        if state.autocomplete.open {
+            match trigger.code {
+                KeyCode::ArrowDown => {
+                    state.autocomplete.select_next();
+                    return;
+                }
+                KeyCode::ArrowUp => {
+                    state.autocomplete.select_previous();
+                    return;
+                }
+                KeyCode::Enter | KeyCode::Tab => {
+                    let Ok(styles) = style_query.get(trigger.target) else {
+                        return;
+                    };
+                    let selected = state.autocomplete.selected;
+                    if accept_suggestion(&mut state, &mut font_manager, styles.font_size, selected)
+                    {
+                        commands.trigger(Change {
+                            target: trigger.target,
+                            data: TextChanged {
+                                value: state.current_value.clone(),
+                            },
+                        });
+                    }
+                    return;
+                }
+                KeyCode::Escape => {
+                    state.autocomplete.close();
+                    return;
+                }
+                KeyCode::ArrowLeft
+                | KeyCode::ArrowRight
+                | KeyCode::Home
+                | KeyCode::End
+                | KeyCode::PageUp
+                | KeyCode::PageDown => {
+                    state.autocomplete.close();
+                }
+                _ => {}
+            }
+        }
+    }
+
+    if matches!(
+        trigger.code,
+        KeyCode::ArrowUp | KeyCode::ArrowDown | KeyCode::ArrowLeft | KeyCode::ArrowRight
+    ) {
+        if let Ok(mut state) = state_query.get_mut(state_entity) {
+            state.history.break_group();
+        }
+    }
+
+    if ctrl && matches!(trigger.code, KeyCode::KeyZ | KeyCode::KeyY) {
+        let Ok(styles) = style_query.get(trigger.target) else {
+            return;
+        };
+        let Ok(mut state) = state_query.get_mut(state_entity) else {
+            return;
+        };
+        let redo = trigger.code == KeyCode::KeyY || keyboard_input.pressed(KeyCode::ShiftLeft);
+        let current = state.snapshot();
+        let snapshot = if redo {
+            state.history.redo(current)
+        } else {
+            state.history.undo(current)
+        };
+        if let Some(snapshot) = snapshot {
+            replace_text(
+                &mut state,
+                &mut font_manager,
+                &snapshot.text,
+                snapshot.selection,
+            );
+            sync_from_engine(&mut state, styles.font_size);
+            commands.trigger(Change {
+                target: trigger.target,
+                data: TextChanged {
+                    value: state.current_value.clone(),
+                },
+            });
+        }
+    }
+
+    // Find(ctrl+f) and replace(ctrl+h).
+    if ctrl && matches!(trigger.code, KeyCode::KeyF | KeyCode::KeyH) {
+        let Ok(mut state) = state_query.get_mut(state_entity) else {
+            return;
+        };
+        if !state.multi_line {
+            return;
+        }
+        // Seed the query with the selected text.
+        let selected = state
+            .engine
+            .selected_text()
+            .filter(|selected| !selected.contains('\n'))
+            .map(str::to_string);
+        let replace_was_open = state.find.open && state.find.replace_open;
+        let find = &mut state.find;
+        if let Some(selected) = selected {
+            find.query = selected;
+        }
+        // An open bar keeps its textboxes, new ones start with the current text.
+        if find.open {
+            sync_text_box(
+                &mut commands,
+                &mut find.synced_query,
+                &find.query,
+                find.query_box,
+            );
+        } else {
+            find.synced_query.clone_from(&find.query);
+        }
+        if !replace_was_open {
+            find.synced_replacement.clone_from(&find.replacement);
+        }
+        find.open = true;
+        find.replace_open = trigger.code == KeyCode::KeyH;
+    }
+
+    if trigger.code == KeyCode::Escape {
+        let Ok(mut state) = state_query.get_mut(state_entity) else {
+            return;
+        };
+        if state.find.open {
+            state.find.close();
+        }
+    }
+
     if trigger.code == KeyCode::Tab {
         let Ok(styles) = style_query.get(trigger.target) else {
             return;
@@ -801,17 +2059,31 @@ pub fn textbox_handle_keyboard_events(
         if !state.multi_line {
             return;
         }
-        let mut driver = font_manager.driver(&mut state.engine);
-        match tab_mode {
-            TabMode::Tab => {
-                driver.insert_or_replace_selection("\t");
-            }
-            TabMode::Space(spaces) => {
-                driver.insert_or_replace_selection(
-                    &std::iter::repeat(' ')
-                        .take(spaces as usize)
-                        .collect::<String>(),
-                );
+        state.record_undo(EditKind::Other);
+        let selection = state.engine.raw_selection().text_range();
+        let shift = keyboard_input.pressed(KeyCode::ShiftLeft);
+        if code_editor.is_some() && (shift || state.current_value[selection.clone()].contains('\n'))
+        {
+            // Block indent/outdent every selected line.
+            let (text, selection) = if shift {
+                outdent_lines(&state.current_value, selection, tab_mode)
+            } else {
+                indent_lines(&state.current_value, selection, tab_mode)
+            };
+            replace_text(&mut state, &mut font_manager, &text, selection);
+        } else {
+            let mut driver = font_manager.driver(&mut state.engine);
+            match tab_mode {
+                TabMode::Tab => {
+                    driver.insert_or_replace_selection("\t");
+                }
+                TabMode::Space(spaces) => {
+                    driver.insert_or_replace_selection(
+                        &std::iter::repeat(' ')
+                            .take(spaces as usize)
+                            .collect::<String>(),
+                    );
+                }
             }
         }
         state.selections = state.engine.selection_geometry();
@@ -841,8 +2113,18 @@ pub fn textbox_handle_keyboard_events(
         if !state.multi_line {
             return;
         }
+        state.record_undo(EditKind::Other);
+        let new_line = match code_editor.filter(|settings| settings.auto_indent) {
+            Some(settings) => auto_indent(
+                &state.current_value,
+                state.engine.raw_selection().text_range().start,
+                tab_mode,
+                settings,
+            ),
+            None => "\n".to_string(),
+        };
         let mut driver = font_manager.driver(&mut state.engine);
-        driver.insert_or_replace_selection("\n");
+        driver.insert_or_replace_selection(&new_line);
         state.selections = state.engine.selection_geometry();
         state.cursor = state
             .engine
@@ -981,6 +2263,7 @@ pub fn textbox_handle_keyboard_events(
         let Ok(mut state) = state_query.get_mut(state_entity) else {
             return;
         };
+        state.record_undo(EditKind::Delete);
         let mut driver = font_manager.driver(&mut state.engine);
         driver.backdelete();
         state.cursor = state
@@ -989,6 +2272,7 @@ pub fn textbox_handle_keyboard_events(
             .unwrap_or_default();
         state.selections = state.engine.selection_geometry();
         state.current_value = state.engine.text().to_string();
+        refresh_suggestions(&mut state, suggestions);
         commands.trigger(
             Change {
                 target: trigger.target,
@@ -1039,6 +2323,33 @@ pub fn textbox_handle_keyboard_events(
             }
         }
     }
+    if let Some(settings) = code_editor {
+        if (keyboard_input.pressed(KeyCode::SuperLeft)
+            || keyboard_input.pressed(KeyCode::ControlLeft))
+            && trigger.code == KeyCode::Slash
+        {
+            let Ok(styles) = style_query.get(trigger.target) else {
+                return;
+            };
+            let Ok(mut state) = state_query.get_mut(state_entity) else {
+                return;
+            };
+            let (text, selection) = toggle_line_comment(
+                &state.current_value,
+                state.engine.raw_selection().text_range(),
+                &settings.line_comment,
+            );
+            state.record_undo(EditKind::Other);
+            replace_text(&mut state, &mut font_manager, &text, selection);
+            sync_from_engine(&mut state, styles.font_size);
+            commands.trigger(Change {
+                target: trigger.target,
+                data: TextChanged {
+                    value: state.current_value.clone(),
+                },
+            });
+        }
+    }
     if trigger.code == KeyCode::Delete {
         let Ok(styles) = style_query.get(trigger.target) else {
             return;
@@ -1048,6 +2359,7 @@ pub fn textbox_handle_keyboard_events(
         };
 
         if !state.current_value.is_empty() {
+            state.record_undo(EditKind::Delete);
             let mut driver = font_manager.driver(&mut state.engine);
             driver.delete();
             state.cursor = state
@@ -1056,6 +2368,7 @@ pub fn textbox_handle_keyboard_events(
                 .unwrap_or_default();
             state.selections = state.engine.selection_geometry();
             state.current_value = state.engine.text().to_string();
+            refresh_suggestions(&mut state, suggestions);
             commands.trigger(
                 Change {
                     target: trigger.target,
@@ -1068,3 +2381,171 @@ pub fn textbox_handle_keyboard_events(
         }
     }
 }
+
+impl TextBoxState {
+    /// A snapshot of the current text and selection.
+    pub fn snapshot(&self) -> TextSnapshot {
+        TextSnapshot {
+            text: self.current_value.clone(),
+            selection: self.engine.raw_selection().text_range(),
+        }
+    }
+
+    /// Records the current text in the undo stack, call this before editing.
+    pub fn record_undo(&mut self, kind: EditKind) {
+        let snapshot = self.snapshot();
+        self.history.record(snapshot, kind);
+    }
+}
+
+/// Updates the cursor, selections and value after the engine was edited.
+fn sync_from_engine(state: &mut TextBoxState, font_size: f32) {
+    state.selections = state.engine.selection_geometry();
+    state.cursor = state.engine.cursor_geometry(font_size).unwrap_or_default();
+    state.current_value = state.engine.text().to_string();
+}
+
+/// Updates an open autocomplete popup after deleting text.
+fn refresh_suggestions(state: &mut TextBoxState, suggestions: Option<&SuggestionProvider>) {
+    let Some(provider) = suggestions.filter(|_| state.autocomplete.open) else {
+        return;
+    };
+    let cursor = state.engine.raw_selection().focus().index();
+    state
+        .autocomplete
+        .update(&state.current_value, cursor, provider);
+}
+
+/// Replaces the autocomplete token with a suggestion and closes the popup.
+///
+/// Returns `true` if the text changed.
+fn accept_suggestion(
+    state: &mut TextBoxState,
+    font_manager: &mut FontManager,
+    font_size: f32,
+    index: usize,
+) -> bool {
+    let suggestion = state.autocomplete.suggestions.get(index).cloned();
+    let token = state.autocomplete.token.clone();
+    state.autocomplete.close();
+    let Some(suggestion) = suggestion else {
+        return false;
+    };
+    if token.end > state.current_value.len() || state.current_value[token.clone()] == suggestion {
+        return false;
+    }
+    state.record_undo(EditKind::Other);
+    let mut driver = font_manager.driver(&mut state.engine);
+    driver.select_byte_range(token.start, token.end);
+    driver.insert_or_replace_selection(&suggestion);
+    sync_from_engine(state, font_size);
+    true
+}
+
+/// Which find match to select.
+#[derive(Clone, Copy, PartialEq)]
+enum FindDirection {
+    /// The first match at or after the selection start.
+    Current,
+    /// The first match after the selection.
+    Next,
+    /// The last match before the selection.
+    Previous,
+}
+
+/// Selects a find match relative to the current selection, wrapping around the text.
+fn select_find_match(
+    state: &mut TextBoxState,
+    font_manager: &mut FontManager,
+    font_size: f32,
+    direction: FindDirection,
+) {
+    let matches = find_matches(&state.current_value, &state.find.query, state.find.options);
+    if matches.is_empty() {
+        state.find.current = None;
+        return;
+    }
+    let selection = state.engine.raw_selection().text_range();
+    let index = match direction {
+        FindDirection::Current => matches.iter().position(|m| m.start >= selection.start),
+        FindDirection::Next => matches.iter().position(|m| m.start >= selection.end),
+        FindDirection::Previous => matches.iter().rposition(|m| m.end <= selection.start),
+    }
+    .unwrap_or(if direction == FindDirection::Previous {
+        matches.len() - 1
+    } else {
+        0
+    });
+
+    let mut driver = font_manager.driver(&mut state.engine);
+    driver.select_byte_range(matches[index].start, matches[index].end);
+    state.history.break_group();
+    state.find.current = Some(index);
+    sync_from_engine(state, font_size);
+}
+
+/// Replaces the selected find match and selects the next one.
+///
+/// Returns true if the text changed.
+fn replace_find_match(
+    state: &mut TextBoxState,
+    font_manager: &mut FontManager,
+    font_size: f32,
+) -> bool {
+    let selection = state.engine.raw_selection().text_range();
+    let Some(replacement) = replace_match(
+        &state.current_value,
+        selection.clone(),
+        &state.find.query,
+        &state.find.replacement,
+        state.find.options,
+    ) else {
+        select_find_match(state, font_manager, font_size, FindDirection::Next);
+        return false;
+    };
+
+    state.record_undo(EditKind::Other);
+    let mut text = state.current_value.clone();
+    text.replace_range(selection.clone(), &replacement);
+    let end = selection.start + replacement.len();
+    replace_text(state, font_manager, &text, end..end);
+    sync_from_engine(state, font_size);
+    select_find_match(state, font_manager, font_size, FindDirection::Current);
+    true
+}
+
+/// Replaces every find match.
+///
+/// Returns true if the text changed.
+fn replace_all_find_matches(
+    state: &mut TextBoxState,
+    font_manager: &mut FontManager,
+    font_size: f32,
+) -> bool {
+    let Some(text) = replace_all(
+        &state.current_value,
+        &state.find.query,
+        &state.find.replacement,
+        state.find.options,
+    ) else {
+        return false;
+    };
+
+    state.record_undo(EditKind::Other);
+    replace_text(state, font_manager, &text, 0..0);
+    sync_from_engine(state, font_size);
+    state.find.current = None;
+    true
+}
+
+/// Replaces the text of the editor and selects the given byte range.
+fn replace_text(
+    state: &mut TextBoxState,
+    font_manager: &mut FontManager,
+    text: &str,
+    selection: std::ops::Range<usize>,
+) {
+    state.engine.set_text(text);
+    let mut driver = font_manager.driver(&mut state.engine);
+    driver.select_byte_range(selection.start, selection.end);
+}
@@ -108,6 +108,28 @@ impl TransitionEasing {
             _ => None,
         }
     }
+
+    /// Applies the easing to an interpolation factor between 0.0 and 1.0.
+    pub fn ease(&self, x: f32) -> f32 {
+        let x = x.clamp(0.0, 1.0);
+        if let Some(easing) = self.try_into_easing_function() {
+            Ease::calc(x, easing)
+        } else {
+            x
+        }
+    }
+}
+
+/// How a transition loops.
+#[derive(Default, Debug, Copy, Reflect, Clone, PartialEq)]
+pub enum TransitionLooping {
+    /// The transition plays once.
+    #[default]
+    None,
+    /// The transition plays forwards then backwards, forever.
+    PingPong,
+    /// The transition restarts from the beginning, forever.
+    Restart,
 }
 
 /// A transition bevy component that lets you animate Woodpecker UI widgets.
@@ -123,10 +145,8 @@ pub struct Transition {
     pub start: Instant,
     /// The time in milliseconds until the animation completed.
     pub timeout: f32,
-    /// Does the animation loop?
-    ///
-    /// TODO: Change from boolean to enum that allows disabling the reversing animation.
-    pub looping: bool,
+    /// How the animation loops.
+    pub looping: TransitionLooping,
     /// The starting styles of the widget.
     pub style_a: WoodpeckerStyle,
     /// The ending styles of the widget.
@@ -161,23 +181,24 @@ impl Default for Transition {
 }
 
 impl Transition {
-    pub(crate) fn update(&mut self) -> WoodpeckerStyle {
+    pub(crate) fn update(&mut self, parent_size: Option<Vec2>) -> WoodpeckerStyle {
         // as Milliseconds
         let elapsed_time = self.start.elapsed().as_secs_f32() * 1000.0;
         if (elapsed_time < self.timeout) && self.playing {
-            let mut x = if let Some(easing) = self.easing.try_into_easing_function() {
-                Ease::calc((elapsed_time / self.timeout).clamp(0.0, 1.0), easing)
-            } else {
-                (elapsed_time / self.timeout).clamp(0.0, 1.0)
-            };
+            let mut x = self.easing.ease(elapsed_time / self.timeout);
             if self.reversing {
                 x = 1.0 - x;
             }
-            self.style_a.lerp(&self.style_b, x)
-        } else if self.looping && self.playing {
+            match parent_size {
+                Some(parent_size) => self.style_a.lerp_resolved(&self.style_b, x, parent_size),
+                None => self.style_a.lerp(&self.style_b, x),
+            }
+        } else if self.looping != TransitionLooping::None && self.playing {
             // Restart animation
             self.start = Instant::now();
-            self.reversing = !self.reversing;
+            if self.looping == TransitionLooping::PingPong {
+                self.reversing = !self.reversing;
+            }
             if self.reversing {
                 self.style_b
             } else {
@@ -214,9 +235,345 @@ impl Transition {
     }
 }
 
-pub(crate) fn update_transitions(mut query: Query<(&mut Transition, &mut WoodpeckerStyle)>) {
-    for (mut transition, mut styles) in query.iter_mut() {
-        let new_styles = transition.update();
+pub(crate) fn update_transitions(
+    mut query: Query<(&mut Transition, &mut WoodpeckerStyle, Option<&ChildOf>)>,
+    layout_query: Query<&WidgetLayout>,
+) {
+    for (mut transition, mut styles, parent) in query.iter_mut() {
+        // Lets pixel to percentage transitions resolve against the parent.
+        let parent_size = parent
+            .and_then(|parent| layout_query.get(parent.parent()).ok())
+            .map(|layout| Vec2::new(layout.width(), layout.height()));
+        let new_styles = transition.update(parent_size);
         *styles = new_styles;
     }
 }
+
+/// A style property that can be transitioned by [`StyleTransitions`].
+#[derive(Debug, Copy, Reflect, Clone, PartialEq)]
+pub enum TransitionProperty {
+    /// Every property below.
+    All,
+    /// background_color
+    BackgroundColor,
+    /// border_color
+    BorderColor,
+    /// color
+    Color,
+    /// font_size
+    FontSize,
+    /// width
+    Width,
+    /// height
+    Height,
+    /// min_width
+    MinWidth,
+    /// min_height
+    MinHeight,
+    /// max_width
+    MaxWidth,
+    /// max_height
+    MaxHeight,
+    /// left
+    Left,
+    /// right
+    Right,
+    /// top
+    Top,
+    /// bottom
+    Bottom,
+    /// opacity
+    Opacity,
+    /// padding
+    Padding,
+    /// margin
+    Margin,
+    /// border
+    Border,
+    /// border_radius
+    BorderRadius,
+    /// gap
+    Gap,
+    /// flex_basis
+    FlexBasis,
+    /// flex_grow
+    FlexGrow,
+    /// flex_shrink
+    FlexShrink,
+    /// line_height
+    LineHeight,
+}
+
+impl TransitionProperty {
+    /// Every property except `All`.
+    pub(crate) const PROPERTIES: [TransitionProperty; 24] = [
+        Self::BackgroundColor,
+        Self::BorderColor,
+        Self::Color,
+        Self::FontSize,
+        Self::Width,
+        Self::Height,
+        Self::MinWidth,
+        Self::MinHeight,
+        Self::MaxWidth,
+        Self::MaxHeight,
+        Self::Left,
+        Self::Right,
+        Self::Top,
+        Self::Bottom,
+        Self::Opacity,
+        Self::Padding,
+        Self::Margin,
+        Self::Border,
+        Self::BorderRadius,
+        Self::Gap,
+        Self::FlexBasis,
+        Self::FlexGrow,
+        Self::FlexShrink,
+        Self::LineHeight,
+    ];
+
+    /// Copies this property from one style to another.
+    pub fn copy(&self, from: &WoodpeckerStyle, to: &mut WoodpeckerStyle) {
+        match self {
+            Self::All => {
+                for property in Self::PROPERTIES {
+                    property.copy(from, to);
+                }
+            }
+            Self::BackgroundColor => to.background_color = from.background_color,
+            Self::BorderColor => to.border_color = from.border_color,
+            Self::Color => to.color = from.color,
+            Self::FontSize => to.font_size = from.font_size,
+            Self::Width => to.width = from.width,
+            Self::Height => to.height = from.height,
+            Self::MinWidth => to.min_width = from.min_width,
+            Self::MinHeight => to.min_height = from.min_height,
+            Self::MaxWidth => to.max_width = from.max_width,
+            Self::MaxHeight => to.max_height = from.max_height,
+            Self::Left => to.left = from.left,
+            Self::Right => to.right = from.right,
+            Self::Top => to.top = from.top,
+            Self::Bottom => to.bottom = from.bottom,
+            Self::Opacity => to.opacity = from.opacity,
+            Self::Padding => to.padding = from.padding,
+            Self::Margin => to.margin = from.margin,
+            Self::Border => to.border = from.border,
+            Self::BorderRadius => to.border_radius = from.border_radius,
+            Self::Gap => to.gap = from.gap,
+            Self::FlexBasis => to.flex_basis = from.flex_basis,
+            Self::FlexGrow => to.flex_grow = from.flex_grow,
+            Self::FlexShrink => to.flex_shrink = from.flex_shrink,
+            Self::LineHeight => to.line_height = from.line_height,
+        }
+    }
+
+    /// Does this property differ between two styles?
+    pub fn differs(&self, a: &WoodpeckerStyle, b: &WoodpeckerStyle) -> bool {
+        let mut copy = *a;
+        self.copy(b, &mut copy);
+        copy != *a
+    }
+}
+
+/// Describes how a single property transitions.
+#[derive(Debug, Copy, Reflect, Clone, PartialEq)]
+pub struct PropertyTransition {
+    /// The property to transition.
+    pub property: TransitionProperty,
+    /// The time in milliseconds the transition takes.
+    pub duration: f32,
+    /// The easing function that dictates the interpolation factor.
+    pub easing: TransitionEasing,
+    /// The time in milliseconds before the transition starts.
+    pub delay: f32,
+}
+
+impl From<(TransitionProperty, f32, TransitionEasing)> for PropertyTransition {
+    fn from((property, duration, easing): (TransitionProperty, f32, TransitionEasing)) -> Self {
+        Self {
+            property,
+            duration,
+            easing,
+            delay: 0.0,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+struct ActiveTransition {
+    property: TransitionProperty,
+    from: WoodpeckerStyle,
+    elapsed: f32,
+    duration: f32,
+    delay: f32,
+    easing: TransitionEasing,
+}
+
+/// CSS-like implicit transitions.
+///
+/// Whenever one of the listed properties of the widget's [`WoodpeckerStyle`] changes,
+/// ex: a [`ButtonStyles`] hover swap, it animates from the value currently on screen
+/// to the new value instead of jumping. Changes made mid-animation start a new
+/// animation from wherever the previous one got to.
+///
+/// Later entries override earlier ones, so `All` can be combined with specific properties.
+///
+/// ```ignore
+/// StyleTransitions::new([
+///     (TransitionProperty::BackgroundColor, 150.0, TransitionEasing::QuadraticOut),
+///     (TransitionProperty::Width, 300.0, TransitionEasing::BackOut),
+/// ])
+/// ```
+#[derive(Component, Debug, Default, Reflect, Clone, PartialEq)]
+pub struct StyleTransitions {
+    /// The transitioned properties.
+    pub properties: Vec<PropertyTransition>,
+}
+
+impl StyleTransitions {
+    /// Creates a new set of implicit transitions.
+    pub fn new(properties: impl IntoIterator<Item = impl Into<PropertyTransition>>) -> Self {
+        Self {
+            properties: properties.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// The running animations of [`StyleTransitions`].
+///
+/// This lives outside of [`StyleTransitions`] because parents re-insert their
+/// children's components every time they render.
+#[derive(Component, Debug, Clone, PartialEq)]
+pub(crate) struct StyleTransitionState {
+    target: WoodpeckerStyle,
+    displayed: WoodpeckerStyle,
+    active: Vec<ActiveTransition>,
+}
+
+impl StyleTransitionState {
+    /// Starts with nothing to animate from.
+    fn new(style: WoodpeckerStyle) -> Self {
+        Self {
+            target: style,
+            displayed: style,
+            active: vec![],
+        }
+    }
+
+    /// Is anything currently animating?
+    fn is_animating(&self) -> bool {
+        !self.active.is_empty()
+    }
+
+    /// Takes the style written by the widget and returns the style to display,
+    /// moving animations forward by `delta` milliseconds.
+    fn update(
+        &mut self,
+        properties: &[PropertyTransition],
+        style: WoodpeckerStyle,
+        delta: f32,
+    ) -> WoodpeckerStyle {
+        // Anything other than what we displayed last is a new target.
+        if style != self.displayed {
+            for transition in properties.iter() {
+                if !transition.property.differs(&self.target, &style) {
+                    continue;
+                }
+                // Interrupt the running transition and start from what's on screen.
+                self.active
+                    .retain(|active| active.property != transition.property);
+                self.active.push(ActiveTransition {
+                    property: transition.property,
+                    from: self.displayed,
+                    elapsed: 0.0,
+                    duration: transition.duration,
+                    delay: transition.delay,
+                    easing: transition.easing,
+                });
+            }
+            self.target = style;
+        }
+
+        let target = self.target;
+        let mut displayed = target;
+        self.active.retain_mut(|active| {
+            active.elapsed += delta;
+            let x = if active.duration <= 0.0 {
+                1.0
+            } else {
+                ((active.elapsed - active.delay) / active.duration).clamp(0.0, 1.0)
+            };
+            if x >= 1.0 {
+                return false;
+            }
+            let blended = active.from.lerp(&target, active.easing.ease(x));
+            active.property.copy(&blended, &mut displayed);
+            true
+        });
+        self.displayed = displayed;
+        displayed
+    }
+}
+
+// IMPORTANT: When modifying widget entities we need to verify we aren't modifying previous widget values.
+pub(crate) fn update_style_transitions(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<
+        (
+            Entity,
+            &mut WoodpeckerStyle,
+            &StyleTransitions,
+            Option<&mut StyleTransitionState>,
+        ),
+        Without<PreviousWidget>,
+    >,
+) {
+    let delta = time.delta_secs() * 1000.0;
+    for (entity, mut style, transitions, state) in query.iter_mut() {
+        let Some(mut state) = state else {
+            // Nothing to animate from yet.
+            commands
+                .entity(entity)
+                .insert(StyleTransitionState::new(*style));
+            continue;
+        };
+        if !style.is_changed() && !state.is_animating() {
+            continue;
+        }
+        let displayed = state.update(&transitions.properties, *style, delta);
+        style.set_if_neq(displayed);
+    }
+}
+
+#[test]
+fn test_style_transitions() {
+    let style = |width: f32| WoodpeckerStyle {
+        width: width.into(),
+        height: width.into(),
+        ..Default::default()
+    };
+    let transitions =
+        StyleTransitions::new([(TransitionProperty::Width, 100.0, TransitionEasing::Linear)]);
+    let properties = &transitions.properties;
+    let mut state = StyleTransitionState::new(style(0.0));
+
+    assert_eq!(state.update(properties, style(0.0), 16.0), style(0.0));
+
+    // Width animates, height jumps.
+    let displayed = state.update(properties, style(100.0), 50.0);
+    assert_eq!(displayed.width, Units::Pixels(50.0));
+    assert_eq!(displayed.height, Units::Pixels(100.0));
+
+    // The widget writes the same target again.
+    let displayed = state.update(properties, style(100.0), 25.0);
+    assert_eq!(displayed.width, Units::Pixels(75.0));
+
+    // Interrupted, animates back from what's on screen.
+    let displayed = state.update(properties, style(0.0), 50.0);
+    assert_eq!(displayed.width, Units::Pixels(37.5));
+    let displayed = state.update(properties, displayed, 50.0);
+    assert_eq!(displayed, style(0.0));
+    assert!(!state.is_animating());
+}
@@ -0,0 +1,730 @@
+use crate::prelude::*;
+use bevy::{platform::collections::HashMap, prelude::*};
+
+use super::colors;
+
+/// A tree view selection change event.
+#[derive(Debug, Clone, Reflect)]
+pub struct TreeViewChanged {
+    /// The ids of the selected nodes, in the order they are shown.
+    pub selected: Vec<String>,
+    /// The entities of the selected nodes that have one.
+    pub entities: Vec<Entity>,
+}
+
+/// Triggered when a node of a [`TreeView`] is expanded or collapsed.
+#[derive(Debug, Clone, Reflect)]
+pub struct TreeNodeToggled {
+    /// The id of the node
+    pub id: String,
+    /// The entity of the node
+    pub entity: Option<Entity>,
+    /// Is the node expanded now?
+    pub expanded: bool,
+    /// True if the node is [`TreeNode::lazy`] and its children haven't been loaded yet,
+    /// add them to the node in [`TreeView::nodes`] to load them.
+    pub load_children: bool,
+}
+
+/// A node of a [`TreeView`].
+#[derive(Debug, Default, Clone, Reflect, PartialEq)]
+pub struct TreeNode {
+    /// The id of the node, unique within the tree
+    pub id: String,
+    /// The label shown for the node
+    pub label: String,
+    /// An entity the node stands for, like an entity in a scene outliner
+    pub entity: Option<Entity>,
+    /// The child nodes
+    pub children: Vec<TreeNode>,
+    /// The node has children that are loaded when it's first expanded, it shows an arrow
+    /// even if it doesn't have any children yet.
+    pub lazy: bool,
+}
+
+impl TreeNode {
+    /// Creates a new node without children.
+    pub fn new(id: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the entity the node stands for.
+    pub fn with_entity(mut self, entity: Entity) -> Self {
+        self.entity = Some(entity);
+        self
+    }
+
+    /// Adds a child node.
+    pub fn with_child(mut self, child: TreeNode) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    /// Marks the node as having children that are loaded when it's expanded.
+    pub fn lazy(mut self) -> Self {
+        self.lazy = true;
+        self
+    }
+
+    /// Can the node be expanded?
+    pub fn has_children(&self) -> bool {
+        self.lazy || !self.children.is_empty()
+    }
+
+    /// Finds a node by id in a list of nodes and their descendants.
+    pub fn find<'a>(nodes: &'a [TreeNode], id: &str) -> Option<&'a TreeNode> {
+        nodes.iter().find_map(|node| {
+            if node.id == id {
+                Some(node)
+            } else {
+                Self::find(&node.children, id)
+            }
+        })
+    }
+
+    /// Finds a node by id to change it, like adding the children of a lazy node.
+    pub fn find_mut<'a>(nodes: &'a mut [TreeNode], id: &str) -> Option<&'a mut TreeNode> {
+        nodes.iter_mut().find_map(|node| {
+            if node.id == id {
+                Some(node)
+            } else {
+                Self::find_mut(&mut node.children, id)
+            }
+        })
+    }
+}
+
+/// Tree view styles
+#[derive(Component, Clone, PartialEq, Reflect)]
+pub struct TreeViewStyles {
+    /// Styles of the tree
+    pub background: WoodpeckerStyle,
+    /// Row styles, the height is the height of every row
+    pub row: WoodpeckerStyle,
+    /// Styles of selected rows
+    pub row_selected: WoodpeckerStyle,
+    /// Styles of the row highlighted by the keyboard while the tree is focused
+    pub row_highlighted: WoodpeckerStyle,
+    /// The lines that connect children to their parent, positioned within each indent
+    pub guide: WoodpeckerStyle,
+    /// The expand/collapse arrow
+    pub arrow: WoodpeckerStyle,
+    /// Label styles
+    pub label: WoodpeckerStyle,
+    /// Styles of the label shown while the children of a lazy node are loading
+    pub loading: WoodpeckerStyle,
+}
+
+impl Default for TreeViewStyles {
+    fn default() -> Self {
+        let row = WoodpeckerStyle {
+            width: Units::Percentage(100.0),
+            height: 26.0.into(),
+            align_items: Some(WidgetAlignItems::Center),
+            background_color: colors::BACKGROUND,
+            ..Default::default()
+        };
+        let label = WoodpeckerStyle {
+            color: Color::WHITE,
+            font_size: 14.0,
+            text_wrap: TextWrap::None,
+            ..Default::default()
+        };
+        Self {
+            background: WoodpeckerStyle {
+                width: Units::Percentage(100.0),
+                flex_direction: WidgetFlexDirection::Column,
+                background_color: colors::BACKGROUND,
+                ..Default::default()
+            },
+            row,
+            row_selected: WoodpeckerStyle {
+                background_color: colors::PRIMARY,
+                ..row
+            },
+            row_highlighted: WoodpeckerStyle {
+                background_color: colors::BACKGROUND_LIGHT,
+                ..row
+            },
+            guide: WoodpeckerStyle {
+                position: WidgetPosition::Absolute,
+                left: 8.0.into(),
+                width: 1.0.into(),
+                height: Units::Percentage(100.0),
+                background_color: colors::BACKGROUND_LIGHT,
+                ..Default::default()
+            },
+            arrow: WoodpeckerStyle {
+                width: 16.0.into(),
+                height: 16.0.into(),
+                margin: Edge::all(0.0).right(4.0),
+                color: Color::WHITE,
+                ..Default::default()
+            },
+            label,
+            loading: WoodpeckerStyle {
+                color: colors::BACKGROUND_LIGHT,
+                ..label
+            },
+        }
+    }
+}
+
+/// Tree view state
+#[derive(Component, Debug, Default, Clone, PartialEq, Reflect)]
+pub struct TreeViewState {
+    /// The ids of the expanded nodes.
+    pub expanded: Vec<String>,
+    /// The ids of the selected nodes, in the order they are shown.
+    pub selected: Vec<String>,
+    /// The node highlighted by the keyboard.
+    pub highlighted: Option<String>,
+    /// Is the tree focused?
+    pub is_focused: bool,
+    /// The node Shift selects from.
+    anchor: Option<String>,
+}
+
+impl TreeViewState {
+    /// Is the node expanded?
+    pub fn is_expanded(&self, id: &str) -> bool {
+        self.expanded.iter().any(|expanded| expanded == id)
+    }
+
+    /// Is the node selected?
+    pub fn is_selected(&self, id: &str) -> bool {
+        self.selected.iter().any(|selected| selected == id)
+    }
+
+    /// Selects a node, `rows` are the visible rows in the order they are shown.
+    ///
+    /// Ctrl toggles the node and Shift selects the nodes from the anchor to it when
+    /// `multi_select` is on. Returns true if the selection changed.
+    fn select(
+        &mut self,
+        rows: &[TreeRow],
+        multi_select: bool,
+        id: &str,
+        shift: bool,
+        ctrl: bool,
+    ) -> bool {
+        let previous = self.selected.clone();
+        let positions = rows
+            .iter()
+            .enumerate()
+            .map(|(position, row)| (row.node.id.as_str(), position))
+            .collect::<HashMap<_, _>>();
+        let position = |id: &str| positions.get(id).copied();
+        let range = self
+            .anchor
+            .as_deref()
+            .and_then(position)
+            .zip(position(id))
+            .filter(|_| multi_select && shift);
+        if let Some((from, to)) = range {
+            if !ctrl {
+                self.selected.clear();
+            }
+            self.selected.extend(
+                rows[from.min(to)..=from.max(to)]
+                    .iter()
+                    .map(|row| row.node.id.clone()),
+            );
+        } else if multi_select && ctrl {
+            if self.is_selected(id) {
+                self.selected.retain(|selected| selected != id);
+            } else {
+                self.selected.push(id.to_string());
+            }
+            self.anchor = Some(id.to_string());
+        } else {
+            self.selected = vec![id.to_string()];
+            self.anchor = Some(id.to_string());
+        }
+        // Keep the selection in the order the rows are shown.
+        self.selected.sort_by_key(|id| position(id.as_str()));
+        self.selected.dedup();
+        self.selected != previous
+    }
+}
+
+/// A visible row of a tree.
+struct TreeRow<'a> {
+    node: &'a TreeNode,
+    depth: usize,
+    /// The row of the parent node
+    parent: Option<usize>,
+}
+
+/// The nodes that are shown, a node is shown if all of its ancestors are expanded.
+fn visible_rows<'a>(nodes: &'a [TreeNode], state: &TreeViewState) -> Vec<TreeRow<'a>> {
+    fn add<'a>(
+        rows: &mut Vec<TreeRow<'a>>,
+        nodes: &'a [TreeNode],
+        state: &TreeViewState,
+        depth: usize,
+        parent: Option<usize>,
+    ) {
+        for node in nodes {
+            let row = rows.len();
+            rows.push(TreeRow {
+                node,
+                depth,
+                parent,
+            });
+            if state.is_expanded(&node.id) {
+                add(rows, &node.children, state, depth + 1, Some(row));
+            }
+        }
+    }
+    let mut rows = vec![];
+    add(&mut rows, nodes, state, 0, None);
+    rows
+}
+
+/// What a navigation key does to a tree.
+#[derive(Debug, PartialEq, Eq)]
+enum TreeKey {
+    Highlight(usize),
+    Expand(usize),
+    Collapse(usize),
+}
+
+/// The action for a key press, `current` is the highlighted row.
+fn tree_key(
+    rows: &[TreeRow],
+    state: &TreeViewState,
+    current: Option<usize>,
+    code: KeyCode,
+) -> Option<TreeKey> {
+    let last = rows.len().checked_sub(1)?;
+    let Some(current) = current else {
+        return match code {
+            KeyCode::ArrowDown | KeyCode::ArrowUp | KeyCode::Home => Some(TreeKey::Highlight(0)),
+            KeyCode::End => Some(TreeKey::Highlight(last)),
+            _ => None,
+        };
+    };
+    let row = &rows[current];
+    match code {
+        KeyCode::ArrowDown => Some(TreeKey::Highlight((current + 1).min(last))),
+        KeyCode::ArrowUp => Some(TreeKey::Highlight(current.saturating_sub(1))),
+        KeyCode::Home => Some(TreeKey::Highlight(0)),
+        KeyCode::End => Some(TreeKey::Highlight(last)),
+        KeyCode::ArrowRight if row.node.has_children() => {
+            if !state.is_expanded(&row.node.id) {
+                Some(TreeKey::Expand(current))
+            } else if rows
+                .get(current + 1)
+                .is_some_and(|r| r.parent == Some(current))
+            {
+                Some(TreeKey::Highlight(current + 1))
+            } else {
+                None
+            }
+        }
+        KeyCode::ArrowLeft => {
+            if row.node.has_children() && state.is_expanded(&row.node.id) {
+                Some(TreeKey::Collapse(current))
+            } else {
+                row.parent.map(TreeKey::Highlight)
+            }
+        }
+        _ => None,
+    }
+}
+
+/// A hierarchical list of nodes that can be expanded and collapsed.
+///
+/// Clicking a node selects it, with `multi_select` Ctrl+Click toggles a node and Shift+Click
+/// selects a range. While focused Up/Down move the highlight, Right expands a node or moves
+/// to its first child, Left collapses a node or moves to its parent and Space or Enter selects
+/// the highlighted node.
+#[derive(Widget, Component, Clone, PartialEq, Reflect)]
+#[auto_update(render)]
+#[props(TreeView, TreeViewStyles)]
+#[state(TreeViewState)]
+#[require(WoodpeckerStyle, TreeViewStyles, WidgetRender = WidgetRender::Quad, WidgetChildren, Pickable, Focusable)]
+pub struct TreeView {
+    /// The top level nodes
+    pub nodes: Vec<TreeNode>,
+    /// The ids of the nodes that start expanded
+    pub initial_expanded: Vec<String>,
+    /// Can more than one node be selected?
+    pub multi_select: bool,
+    /// How far each level is indented in pixels
+    pub indent: f32,
+}
+
+impl Default for TreeView {
+    fn default() -> Self {
+        Self {
+            nodes: vec![],
+            initial_expanded: vec![],
+            multi_select: false,
+            indent: 16.0,
+        }
+    }
+}
+
+impl TreeView {
+    fn selection_changed(
+        &self,
+        commands: &mut Commands,
+        tree_view_entity: Entity,
+        state: &TreeViewState,
+    ) {
+        commands.trigger(Change {
+            target: tree_view_entity,
+            data: TreeViewChanged {
+                selected: state.selected.clone(),
+                entities: state
+                    .selected
+                    .iter()
+                    .filter_map(|id| TreeNode::find(&self.nodes, id)?.entity)
+                    .collect(),
+            },
+        });
+    }
+
+    fn toggle(
+        &self,
+        commands: &mut Commands,
+        tree_view_entity: Entity,
+        state: &mut TreeViewState,
+        id: &str,
+    ) {
+        let Some(node) = TreeNode::find(&self.nodes, id).filter(|node| node.has_children()) else {
+            return;
+        };
+        let expanded = !state.is_expanded(id);
+        if expanded {
+            state.expanded.push(id.to_string());
+        } else {
+            state.expanded.retain(|expanded| expanded != id);
+        }
+        commands.trigger(Change {
+            target: tree_view_entity,
+            data: TreeNodeToggled {
+                id: id.to_string(),
+                entity: node.entity,
+                expanded,
+                load_children: expanded && node.lazy && node.children.is_empty(),
+            },
+        });
+    }
+}
+
+fn modifiers(keyboard_input: &ButtonInput<KeyCode>) -> (bool, bool) {
+    (
+        keyboard_input.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]),
+        keyboard_input.any_pressed([
+            KeyCode::ControlLeft,
+            KeyCode::ControlRight,
+            KeyCode::SuperLeft,
+            KeyCode::SuperRight,
+        ]),
+    )
+}
+
+fn render(
+    mut commands: Commands,
+    mut hooks: ResMut<HookHelper>,
+    current_widget: Res<CurrentWidget>,
+    asset_server: Res<AssetServer>,
+    mut query: Query<(
+        &TreeView,
+        &TreeViewStyles,
+        &mut WoodpeckerStyle,
+        &mut WidgetChildren,
+    )>,
+    state_query: Query<&TreeViewState>,
+) {
+    let Ok((tree_view, tree_view_styles, mut styles, mut children)) =
+        query.get_mut(**current_widget)
+    else {
+        return;
+    };
+
+    let state_entity = hooks.use_state(
+        &mut commands,
+        *current_widget,
+        TreeViewState {
+            expanded: tree_view.initial_expanded.clone(),
+            ..Default::default()
+        },
+    );
+    let Ok(state) = state_query.get(state_entity) else {
+        return;
+    };
+
+    *styles = tree_view_styles.background;
+
+    let tree_view_entity = **current_widget;
+    *children = WidgetChildren::default()
+        .with_observe(
+            *current_widget,
+            move |trigger: On<WidgetKeyboardButtonEvent>,
+                  mut commands: Commands,
+                  keyboard_input: Res<ButtonInput<KeyCode>>,
+                  tree_view_query: Query<&TreeView>,
+                  mut state_query: Query<&mut TreeViewState>| {
+                let (Ok(tree_view), Ok(mut state)) = (
+                    tree_view_query.get(tree_view_entity),
+                    state_query.get_mut(state_entity),
+                ) else {
+                    return;
+                };
+                let rows = visible_rows(&tree_view.nodes, &state);
+                let current = state
+                    .highlighted
+                    .as_deref()
+                    .and_then(|id| rows.iter().position(|row| row.node.id == id));
+                let (shift, ctrl) = modifiers(&keyboard_input);
+                match trigger.code {
+                    KeyCode::Space | KeyCode::Enter => {
+                        let Some(current) = current else {
+                            return;
+                        };
+                        let id = rows[current].node.id.clone();
+                        // Space toggles the highlighted node like Ctrl+Click.
+                        let ctrl = ctrl || trigger.code == KeyCode::Space;
+                        if state.select(&rows, tree_view.multi_select, &id, shift, ctrl) {
+                            tree_view.selection_changed(&mut commands, tree_view_entity, &state);
+                        }
+                    }
+                    code => match tree_key(&rows, &state, current, code) {
+                        Some(TreeKey::Highlight(row)) => {
+                            let id = rows[row].node.id.clone();
+                            // Shift+Up/Down extends the selection.
+                            if shift
+                                && matches!(code, KeyCode::ArrowUp | KeyCode::ArrowDown)
+                                && state.select(&rows, tree_view.multi_select, &id, true, false)
+                            {
+                                tree_view.selection_changed(
+                                    &mut commands,
+                                    tree_view_entity,
+                                    &state,
+                                );
+                            }
+                            state.highlighted = Some(id);
+                        }
+                        Some(TreeKey::Expand(row) | TreeKey::Collapse(row)) => {
+                            let id = rows[row].node.id.clone();
+                            tree_view.toggle(&mut commands, tree_view_entity, &mut state, &id);
+                        }
+                        None => {}
+                    },
+                }
+            },
+        )
+        .with_observe(
+            *current_widget,
+            move |_trigger: On<WidgetFocus>, mut state_query: Query<&mut TreeViewState>| {
+                if let Ok(mut state) = state_query.get_mut(state_entity) {
+                    state.is_focused = true;
+                }
+            },
+        )
+        .with_observe(
+            *current_widget,
+            move |_trigger: On<WidgetBlur>, mut state_query: Query<&mut TreeViewState>| {
+                if let Ok(mut state) = state_query.get_mut(state_entity) {
+                    state.is_focused = false;
+                }
+            },
+        );
+
+    let indent = tree_view.indent;
+    // Indents the content of a row by `depth` levels, with a guide line in each level.
+    let indented = |depth: usize, row_children: &mut WidgetChildren| {
+        for _ in 0..depth {
+            row_children.add::<Element>((
+                Element,
+                WoodpeckerStyle {
+                    width: indent.into(),
+                    height: Units::Percentage(100.0),
+                    ..Default::default()
+                },
+                WidgetChildren::default().with_child::<Element>((
+                    Element,
+                    tree_view_styles.guide,
+                    WidgetRender::Quad,
+                )),
+            ));
+        }
+    };
+
+    for row in visible_rows(&tree_view.nodes, state) {
+        let node = row.node;
+        let is_expanded = state.is_expanded(&node.id);
+        let mut row_children = WidgetChildren::default();
+        indented(row.depth, &mut row_children);
+
+        if node.has_children() {
+            let icon = if is_expanded {
+                "arrow-down.svg"
+            } else {
+                "arrow-right.svg"
+            };
+            let id = node.id.clone();
+            row_children
+                .add::<Element>((
+                    Element,
+                    tree_view_styles.arrow,
+                    WidgetRender::Svg {
+                        handle: asset_server.load(format!(
+                            "embedded://woodpecker_ui/embedded_assets/icons/{icon}"
+                        )),
+                        color: Some(tree_view_styles.arrow.color),
+                    },
+                    Pickable::default(),
+                ))
+                .observe(
+                    *current_widget,
+                    move |mut trigger: On<Pointer<Click>>,
+                          mut commands: Commands,
+                          mut current_focus: ResMut<CurrentFocus>,
+                          tree_view_query: Query<&TreeView>,
+                          mut state_query: Query<&mut TreeViewState>| {
+                        // The arrow only expands or collapses, it doesn't select.
+                        trigger.propagate(false);
+                        let (Ok(tree_view), Ok(mut state)) = (
+                            tree_view_query.get(tree_view_entity),
+                            state_query.get_mut(state_entity),
+                        ) else {
+                            return;
+                        };
+                        tree_view.toggle(&mut commands, tree_view_entity, &mut state, &id);
+                        state.highlighted = Some(id.clone());
+                        current_focus.refocus(&mut commands, tree_view_entity);
+                    },
+                );
+        } else {
+            // Keeps the labels of leaves lined up with their siblings.
+            row_children.add::<Element>((Element, tree_view_styles.arrow));
+        }
+
+        row_children.add::<Element>((
+            Element,
+            tree_view_styles.label,
+            WidgetRender::Text {
+                content: node.label.clone(),
+            },
+        ));
+
+        let row_styles = if state.is_selected(&node.id) {
+            tree_view_styles.row_selected
+        } else if state.is_focused && state.highlighted.as_deref() == Some(node.id.as_str()) {
+            tree_view_styles.row_highlighted
+        } else {
+            tree_view_styles.row
+        };
+        let id = node.id.clone();
+        children
+            .add::<Element>((
+                Element,
+                row_styles,
+                WidgetRender::Quad,
+                row_children,
+                Pickable::default(),
+            ))
+            .observe(
+                *current_widget,
+                move |_trigger: On<Pointer<Click>>,
+                      mut commands: Commands,
+                      mut current_focus: ResMut<CurrentFocus>,
+                      keyboard_input: Res<ButtonInput<KeyCode>>,
+                      tree_view_query: Query<&TreeView>,
+                      mut state_query: Query<&mut TreeViewState>| {
+                    let (Ok(tree_view), Ok(mut state)) = (
+                        tree_view_query.get(tree_view_entity),
+                        state_query.get_mut(state_entity),
+                    ) else {
+                        return;
+                    };
+                    let (shift, ctrl) = modifiers(&keyboard_input);
+                    let rows = visible_rows(&tree_view.nodes, &state);
+                    if state.select(&rows, tree_view.multi_select, &id, shift, ctrl) {
+                        tree_view.selection_changed(&mut commands, tree_view_entity, &state);
+                    }
+                    state.highlighted = Some(id.clone());
+                    current_focus.refocus(&mut commands, tree_view_entity);
+                },
+            );
+        children.add_key(node.id.clone());
+
+        if is_expanded && node.lazy && node.children.is_empty() {
+            let mut loading_children = WidgetChildren::default();
+            indented(row.depth + 1, &mut loading_children);
+            loading_children.add::<Element>((
+                Element,
+                tree_view_styles.loading,
+                WidgetRender::Text {
+                    content: "Loading...".into(),
+                },
+            ));
+            children.add::<Element>((
+                Element,
+                tree_view_styles.row,
+                WidgetRender::Quad,
+                loading_children,
+            ));
+        }
+    }
+
+    children.apply(current_widget.as_parent());
+}
+
+#[test]
+fn test_tree_view() {
+    let nodes = vec![
+        TreeNode::new("a", "A")
+            .with_child(TreeNode::new("a1", "A1"))
+            .with_child(TreeNode::new("a2", "A2").lazy()),
+        TreeNode::new("b", "B"),
+    ];
+    let mut state = TreeViewState::default();
+    assert_eq!(visible_rows(&nodes, &state).len(), 2);
+    state.expanded.push("a".into());
+    let rows = visible_rows(&nodes, &state);
+    let ids = rows
+        .iter()
+        .map(|row| row.node.id.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(ids, vec!["a", "a1", "a2", "b"]);
+    assert_eq!(rows[2].depth, 1);
+    assert_eq!(rows[2].parent, Some(0));
+
+    // Right expands a collapsed node, Left on a child moves to its parent.
+    assert_eq!(
+        tree_key(&rows, &state, Some(2), KeyCode::ArrowRight),
+        Some(TreeKey::Expand(2))
+    );
+    assert_eq!(
+        tree_key(&rows, &state, Some(0), KeyCode::ArrowRight),
+        Some(TreeKey::Highlight(1))
+    );
+    assert_eq!(
+        tree_key(&rows, &state, Some(1), KeyCode::ArrowLeft),
+        Some(TreeKey::Highlight(0))
+    );
+    assert_eq!(
+        tree_key(&rows, &state, Some(0), KeyCode::ArrowLeft),
+        Some(TreeKey::Collapse(0))
+    );
+    assert_eq!(tree_key(&rows, &state, Some(3), KeyCode::ArrowRight), None);
+
+    assert!(state.select(&rows, true, "b", false, false));
+    assert!(state.select(&rows, true, "a1", true, false));
+    assert_eq!(state.selected, vec!["a1", "a2", "b"]);
+    assert!(state.select(&rows, true, "a2", false, true));
+    assert_eq!(state.selected, vec!["a1", "b"]);
+    // Without multi-select modifiers select a single node.
+    assert!(state.select(&rows, false, "a", true, true));
+    assert_eq!(state.selected, vec!["a"]);
+}
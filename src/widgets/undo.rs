@@ -0,0 +1,87 @@
+use std::ops::Range;
+
+/// The maximum number of snapshots kept in the undo stack.
+const MAX_HISTORY: usize = 200;
+
+/// A snapshot of the text and selection used by the undo stack.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct TextSnapshot {
+    /// The text value
+    pub text: String,
+    /// The selected byte range
+    pub selection: Range<usize>,
+}
+
+/// The kind of edit that is about to happen.
+///
+/// Consecutive edits of the same kind are grouped into a single undo step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EditKind {
+    /// Typing characters.
+    Insert,
+    /// Deleting characters with backspace/delete.
+    Delete,
+    /// Any other edit, never grouped.
+    Other,
+}
+
+/// Undo/redo history for text editing.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct UndoStack {
+    undo: Vec<TextSnapshot>,
+    redo: Vec<TextSnapshot>,
+    last_edit: Option<EditKind>,
+}
+
+impl UndoStack {
+    /// Records the state before an edit.
+    pub fn record(&mut self, snapshot: TextSnapshot, kind: EditKind) {
+        self.redo.clear();
+        if kind != EditKind::Other && self.last_edit == Some(kind) {
+            return;
+        }
+        self.last_edit = Some(kind);
+        self.undo.push(snapshot);
+        if self.undo.len() > MAX_HISTORY {
+            self.undo.remove(0);
+        }
+    }
+
+    /// Stops grouping edits, ex: when the cursor moves.
+    pub fn break_group(&mut self) {
+        self.last_edit = None;
+    }
+
+    /// Pops the last undo snapshot, storing `current` for redo.
+    pub fn undo(&mut self, current: TextSnapshot) -> Option<TextSnapshot> {
+        let snapshot = self.undo.pop()?;
+        self.redo.push(current);
+        self.last_edit = None;
+        Some(snapshot)
+    }
+
+    /// Pops the last redo snapshot, storing `current` for undo.
+    pub fn redo(&mut self, current: TextSnapshot) -> Option<TextSnapshot> {
+        let snapshot = self.redo.pop()?;
+        self.undo.push(current);
+        self.last_edit = None;
+        Some(snapshot)
+    }
+
+    /// Can we undo?
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    /// Can we redo?
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    /// Clears the history.
+    pub fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+        self.last_edit = None;
+    }
+}
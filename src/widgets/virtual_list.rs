@@ -0,0 +1,577 @@
+use std::ops::Range;
+use std::sync::Arc;
+
+use crate::prelude::*;
+use bevy::prelude::*;
+
+use super::scroll::aligned_offset;
+
+/// Builds the widgets of the items in a [`VirtualList`] or [`VirtualGrid`].
+///
+/// Only the items that are visible get built, so the builder is called with the index of the item
+/// every time it scrolls into view. Items are keyed by index unless a key function is given with
+/// [`VirtualItems::with_key`], use one when items can move so their state moves with them.
+///
+/// Builders are only equal to their clones. Create a new one when the data behind the items
+/// changes so the list re-renders, and keep the same one otherwise.
+#[derive(Clone)]
+pub struct VirtualItems {
+    build: Arc<dyn Fn(usize, &mut WidgetChildren) + Send + Sync>,
+    key: Option<Arc<dyn Fn(usize) -> String + Send + Sync>>,
+}
+
+impl VirtualItems {
+    /// Creates a new item builder, `build` adds the widget of the item at an index to the children.
+    pub fn new(build: impl Fn(usize, &mut WidgetChildren) + Send + Sync + 'static) -> Self {
+        Self {
+            build: Arc::new(build),
+            key: None,
+        }
+    }
+
+    /// Keys the items with a stable key instead of their index.
+    pub fn with_key(mut self, key: impl Fn(usize) -> String + Send + Sync + 'static) -> Self {
+        self.key = Some(Arc::new(key));
+        self
+    }
+
+    fn key(&self, index: usize) -> String {
+        match &self.key {
+            Some(key) => key(index),
+            None => index.to_string(),
+        }
+    }
+}
+
+impl PartialEq for VirtualItems {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.build, &other.build)
+            && match (&self.key, &other.key) {
+                (Some(key), Some(other_key)) => Arc::ptr_eq(key, other_key),
+                (None, None) => true,
+                _ => false,
+            }
+    }
+}
+
+impl Default for VirtualItems {
+    fn default() -> Self {
+        Self::new(|_, _| {})
+    }
+}
+
+/// How tall the rows of a [`VirtualList`] are.
+#[derive(Debug, Reflect, Clone, Copy, PartialEq)]
+pub enum RowHeight {
+    /// Every row has the same height in pixels.
+    Fixed(f32),
+    /// Rows are as tall as their content, rows that haven't been seen yet use the estimated height.
+    Measured(f32),
+}
+
+impl Default for RowHeight {
+    fn default() -> Self {
+        Self::Fixed(32.0)
+    }
+}
+
+/// Scrolls a [`VirtualList`] or [`VirtualGrid`] so that the item at `index` is visible,
+/// even if it isn't mounted yet.
+#[derive(Clone, PartialEq, Debug, Reflect, EntityEvent)]
+pub struct ScrollToIndex {
+    /// The virtual list or grid
+    #[event_target]
+    pub target: Entity,
+    /// The index of the item
+    pub index: usize,
+    /// Where the item ends up in the visible area
+    pub align: ScrollAlign,
+    /// Animates to the new offset with the scroll box spring, or the default spring
+    pub animated: bool,
+}
+
+/// Virtual list state
+#[derive(Component, Debug, Default, Clone, PartialEq, Reflect)]
+pub struct VirtualListState {
+    /// The measured heights of the rows that have been mounted, only used with [`RowHeight::Measured`].
+    measured: Vec<Option<f32>>,
+}
+
+/// Marks a row of a [`VirtualList`] that measures its height.
+#[derive(Component, Clone, Copy)]
+pub(crate) struct VirtualRow {
+    state: Entity,
+    index: usize,
+}
+
+/// Where the rows of a virtual list are.
+pub(super) enum Rows {
+    Fixed {
+        height: f32,
+        count: usize,
+    },
+    /// The top of each row followed by the total height.
+    Measured(Vec<f32>),
+}
+
+impl Rows {
+    pub(super) fn new(row_height: RowHeight, count: usize, measured: &[Option<f32>]) -> Self {
+        match row_height {
+            RowHeight::Fixed(height) => Self::Fixed { height, count },
+            RowHeight::Measured(estimate) => {
+                let mut offsets = Vec::with_capacity(count + 1);
+                let mut top = 0.0;
+                offsets.push(top);
+                for index in 0..count {
+                    top += measured.get(index).copied().flatten().unwrap_or(estimate);
+                    offsets.push(top);
+                }
+                Self::Measured(offsets)
+            }
+        }
+    }
+
+    pub(super) fn count(&self) -> usize {
+        match self {
+            Self::Fixed { count, .. } => *count,
+            Self::Measured(offsets) => offsets.len() - 1,
+        }
+    }
+
+    pub(super) fn top(&self, index: usize) -> f32 {
+        match self {
+            Self::Fixed { height, .. } => index as f32 * height,
+            Self::Measured(offsets) => offsets[index],
+        }
+    }
+
+    pub(super) fn height(&self, index: usize) -> f32 {
+        match self {
+            Self::Fixed { height, .. } => *height,
+            Self::Measured(offsets) => offsets[index + 1] - offsets[index],
+        }
+    }
+
+    pub(super) fn total(&self) -> f32 {
+        self.top(self.count())
+    }
+
+    /// The rows that overlap `top..bottom`, plus `overscan` rows on either side.
+    pub(super) fn visible(&self, top: f32, bottom: f32, overscan: usize) -> Range<usize> {
+        let count = self.count();
+        let (first, last) = match self {
+            Self::Fixed { height, .. } if *height > 0.0 => (
+                (top / height).floor().max(0.0) as usize,
+                (bottom / height).ceil().max(0.0) as usize,
+            ),
+            Self::Fixed { .. } => (0, count),
+            Self::Measured(offsets) => (
+                // The first row that ends below the top.
+                offsets[1..].partition_point(|end| *end <= top),
+                // The rows that start above the bottom.
+                offsets[..count].partition_point(|start| *start < bottom),
+            ),
+        };
+        let first = first.saturating_sub(overscan).min(count);
+        let last = last.saturating_add(overscan).min(count);
+        first..last.max(first)
+    }
+}
+
+/// A scrollable list that only mounts the rows in view, so it can hold thousands of items.
+///
+/// Rows are positioned absolutely inside the content of a [`ScrollBox`], the list provides the
+/// [`ScrollContext`] of the scroll box. Trigger [`ScrollToIndex`] on the list to scroll to an item.
+#[derive(Widget, Component, Clone, PartialEq, Reflect)]
+#[auto_update(render)]
+#[props(VirtualList)]
+#[state(VirtualListState)]
+#[context(ScrollContext)]
+#[require(WoodpeckerStyle, WidgetChildren)]
+pub struct VirtualList {
+    /// The number of items
+    pub item_count: usize,
+    /// How tall the rows are
+    pub row_height: RowHeight,
+    /// How many rows outside of the visible area are mounted above and below it
+    pub overscan: usize,
+    /// Builds the items
+    #[reflect(ignore)]
+    pub items: VirtualItems,
+    /// The scroll box settings, its children are ignored
+    pub scroll_box: ScrollBox,
+    /// The styles of the list
+    pub styles: WoodpeckerStyle,
+}
+
+impl Default for VirtualList {
+    fn default() -> Self {
+        Self {
+            item_count: 0,
+            row_height: RowHeight::default(),
+            overscan: 3,
+            items: VirtualItems::default(),
+            scroll_box: ScrollBox::default(),
+            styles: WoodpeckerStyle {
+                width: Units::Percentage(100.0),
+                height: Units::Percentage(100.0),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// A scrollable grid of equally sized cells that only mounts the rows in view.
+///
+/// Trigger [`ScrollToIndex`] on the grid to scroll to an item.
+#[derive(Widget, Component, Clone, PartialEq, Reflect)]
+#[auto_update(render_grid)]
+#[props(VirtualGrid)]
+#[context(ScrollContext)]
+#[require(WoodpeckerStyle, WidgetChildren)]
+pub struct VirtualGrid {
+    /// The number of items
+    pub item_count: usize,
+    /// The size of each cell in pixels
+    pub cell_size: Vec2,
+    /// The space between cells in pixels
+    pub gap: f32,
+    /// The number of columns, defaults to as many as fit in the visible width
+    pub columns: Option<usize>,
+    /// How many rows outside of the visible area are mounted above and below it
+    pub overscan: usize,
+    /// Builds the items
+    #[reflect(ignore)]
+    pub items: VirtualItems,
+    /// The scroll box settings, its children are ignored
+    pub scroll_box: ScrollBox,
+    /// The styles of the grid
+    pub styles: WoodpeckerStyle,
+}
+
+impl Default for VirtualGrid {
+    fn default() -> Self {
+        Self {
+            item_count: 0,
+            cell_size: Vec2::splat(64.0),
+            gap: 4.0,
+            columns: None,
+            overscan: 2,
+            items: VirtualItems::default(),
+            scroll_box: ScrollBox::default(),
+            styles: WoodpeckerStyle {
+                width: Units::Percentage(100.0),
+                height: Units::Percentage(100.0),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl VirtualGrid {
+    /// The number of columns that fit in a width.
+    fn column_count(&self, width: f32) -> usize {
+        self.columns
+            .unwrap_or(((width + self.gap) / (self.cell_size.x + self.gap)).floor() as usize)
+            .max(1)
+    }
+
+    fn rows(&self, columns: usize) -> Rows {
+        Rows::Fixed {
+            height: self.cell_size.y + self.gap,
+            count: self.item_count.div_ceil(columns),
+        }
+    }
+}
+
+/// The visible size of the content of a scroll box.
+pub(super) fn view_size(context: &ScrollContext) -> Vec2 {
+    Vec2::new(
+        context.scrollbox_width - context.pad_x,
+        context.scrollbox_height - context.pad_y,
+    )
+}
+
+/// Scrolls vertically so a row is visible.
+fn scroll_to_row(
+    context: &mut ScrollContext,
+    scroll_box: &ScrollBox,
+    trigger: &ScrollToIndex,
+    top: f32,
+    height: f32,
+) {
+    let y = aligned_offset(
+        trigger.align,
+        context.scroll_target_y(),
+        top,
+        height,
+        view_size(context).y,
+    );
+    if trigger.animated {
+        context.spring_scroll_y(y, scroll_box.spring.unwrap_or(Spring::DEFAULT));
+    } else {
+        context.set_scroll_y(y);
+    }
+}
+
+fn render(
+    mut commands: Commands,
+    mut hooks: ResMut<HookHelper>,
+    current_widget: Res<CurrentWidget>,
+    mut query: Query<(&VirtualList, &mut WoodpeckerStyle, &mut WidgetChildren)>,
+    context_query: Query<&ScrollContext>,
+    state_query: Query<&VirtualListState>,
+) {
+    let Ok((list, mut styles, mut children)) = query.get_mut(**current_widget) else {
+        return;
+    };
+
+    // The list provides the context so it knows where the scroll box is scrolled to.
+    let context_entity =
+        hooks.provide_context(&mut commands, *current_widget, ScrollContext::default());
+    let state_entity = hooks.use_state(&mut commands, *current_widget, VirtualListState::default());
+    let (Ok(context), Ok(state)) = (
+        context_query.get(context_entity),
+        state_query.get(state_entity),
+    ) else {
+        return;
+    };
+
+    *styles = list.styles;
+
+    let rows = Rows::new(list.row_height, list.item_count, &state.measured);
+    let top = -context.scroll_y();
+    let visible = rows.visible(top, top + view_size(context).y, list.overscan);
+
+    let mut content_children = WidgetChildren::default();
+    for index in visible {
+        let mut item_children = WidgetChildren::default();
+        (list.items.build)(index, &mut item_children);
+        let row_styles = WoodpeckerStyle {
+            position: WidgetPosition::Absolute,
+            top: rows.top(index).into(),
+            width: Units::Percentage(100.0),
+            flex_direction: WidgetFlexDirection::Column,
+            ..Default::default()
+        };
+        match list.row_height {
+            RowHeight::Fixed(height) => {
+                content_children.add::<Element>((
+                    Element,
+                    WoodpeckerStyle {
+                        height: height.into(),
+                        ..row_styles
+                    },
+                    item_children,
+                ));
+            }
+            RowHeight::Measured(_) => {
+                content_children.add::<Element>((
+                    Element,
+                    row_styles,
+                    item_children,
+                    VirtualRow {
+                        state: state_entity,
+                        index,
+                    },
+                ));
+            }
+        }
+        content_children.add_key(list.items.key(index));
+    }
+
+    let list_entity = **current_widget;
+    *children = WidgetChildren::default()
+        .with_observe(
+            *current_widget,
+            move |trigger: On<ScrollToIndex>,
+                  list_query: Query<&VirtualList>,
+                  state_query: Query<&VirtualListState>,
+                  mut context_query: Query<&mut ScrollContext>| {
+                let (Ok(list), Ok(state), Ok(mut context)) = (
+                    list_query.get(list_entity),
+                    state_query.get(state_entity),
+                    context_query.get_mut(context_entity),
+                ) else {
+                    return;
+                };
+                if trigger.index >= list.item_count {
+                    return;
+                }
+                let rows = Rows::new(list.row_height, list.item_count, &state.measured);
+                scroll_to_row(
+                    &mut context,
+                    &list.scroll_box,
+                    &trigger,
+                    rows.top(trigger.index),
+                    rows.height(trigger.index),
+                );
+            },
+        )
+        .with_child::<ScrollBox>((
+            ScrollBox {
+                disable_horizontal: true,
+                ..list.scroll_box.clone()
+            },
+            PassedChildren(WidgetChildren::default().with_child::<Element>((
+                Element,
+                WoodpeckerStyle {
+                    width: Units::Percentage(100.0),
+                    height: rows.total().into(),
+                    ..Default::default()
+                },
+                content_children,
+            ))),
+        ));
+
+    children.apply(current_widget.as_parent());
+}
+
+fn render_grid(
+    mut commands: Commands,
+    mut hooks: ResMut<HookHelper>,
+    current_widget: Res<CurrentWidget>,
+    mut query: Query<(&VirtualGrid, &mut WoodpeckerStyle, &mut WidgetChildren)>,
+    context_query: Query<&ScrollContext>,
+) {
+    let Ok((grid, mut styles, mut children)) = query.get_mut(**current_widget) else {
+        return;
+    };
+
+    let context_entity =
+        hooks.provide_context(&mut commands, *current_widget, ScrollContext::default());
+    let Ok(context) = context_query.get(context_entity) else {
+        return;
+    };
+
+    *styles = grid.styles;
+
+    let view = view_size(context);
+    let columns = grid.column_count(view.x);
+    let rows = grid.rows(columns);
+    let top = -context.scroll_y();
+    let visible = rows.visible(top, top + view.y, grid.overscan);
+
+    let mut content_children = WidgetChildren::default();
+    let items = visible.start * columns..(visible.end * columns).min(grid.item_count);
+    for index in items {
+        let mut item_children = WidgetChildren::default();
+        (grid.items.build)(index, &mut item_children);
+        content_children.add::<Element>((
+            Element,
+            WoodpeckerStyle {
+                position: WidgetPosition::Absolute,
+                left: ((index % columns) as f32 * (grid.cell_size.x + grid.gap)).into(),
+                top: rows.top(index / columns).into(),
+                width: grid.cell_size.x.into(),
+                height: grid.cell_size.y.into(),
+                flex_direction: WidgetFlexDirection::Column,
+                ..Default::default()
+            },
+            item_children,
+        ));
+        content_children.add_key(grid.items.key(index));
+    }
+
+    let grid_entity = **current_widget;
+    *children = WidgetChildren::default()
+        .with_observe(
+            *current_widget,
+            move |trigger: On<ScrollToIndex>,
+                  grid_query: Query<&VirtualGrid>,
+                  mut context_query: Query<&mut ScrollContext>| {
+                let (Ok(grid), Ok(mut context)) = (
+                    grid_query.get(grid_entity),
+                    context_query.get_mut(context_entity),
+                ) else {
+                    return;
+                };
+                if trigger.index >= grid.item_count {
+                    return;
+                }
+                let columns = grid.column_count(view_size(&context).x);
+                let row = trigger.index / columns;
+                scroll_to_row(
+                    &mut context,
+                    &grid.scroll_box,
+                    &trigger,
+                    grid.rows(columns).top(row),
+                    grid.cell_size.y,
+                );
+            },
+        )
+        .with_child::<ScrollBox>((
+            ScrollBox {
+                disable_horizontal: true,
+                ..grid.scroll_box.clone()
+            },
+            PassedChildren(WidgetChildren::default().with_child::<Element>((
+                Element,
+                WoodpeckerStyle {
+                    width: Units::Percentage(100.0),
+                    height: (rows.total() - grid.gap).max(0.0).into(),
+                    ..Default::default()
+                },
+                content_children,
+            ))),
+        ));
+
+    children.apply(current_widget.as_parent());
+}
+
+/// Records the heights of mounted rows with measured heights once they are laid out.
+pub(crate) fn measure_virtual_rows(
+    row_query: Query<(&VirtualRow, &WidgetLayout), Changed<WidgetLayout>>,
+    mut state_query: Query<&mut VirtualListState>,
+) {
+    for (row, layout) in row_query.iter() {
+        let Ok(mut state) = state_query.get_mut(row.state) else {
+            continue;
+        };
+        let height = layout.height();
+        if state.measured.get(row.index).copied().flatten() == Some(height) {
+            continue;
+        }
+        if state.measured.len() <= row.index {
+            state.measured.resize(row.index + 1, None);
+        }
+        state.measured[row.index] = Some(height);
+    }
+}
+
+#[test]
+fn test_virtual_rows() {
+    let fixed = Rows::new(RowHeight::Fixed(20.0), 100, &[]);
+    assert_eq!(fixed.visible(0.0, 100.0, 0), 0..5);
+    assert_eq!(fixed.visible(30.0, 130.0, 2), 0..9);
+    assert_eq!(fixed.visible(1990.0, 2090.0, 2), 97..100);
+    assert_eq!(fixed.total(), 2000.0);
+
+    let measured = Rows::new(
+        RowHeight::Measured(10.0),
+        5,
+        &[Some(30.0), None, Some(50.0)],
+    );
+    assert_eq!(measured.top(3), 90.0);
+    assert_eq!(measured.height(2), 50.0);
+    assert_eq!(measured.total(), 110.0);
+    // Rows 1 (30..40) and 2 (40..90) overlap 35..45.
+    assert_eq!(measured.visible(35.0, 45.0, 0), 1..3);
+    assert_eq!(measured.visible(35.0, 45.0, 1), 0..4);
+    assert_eq!(measured.visible(200.0, 300.0, 0), 5..5);
+
+    let grid = VirtualGrid {
+        item_count: 10,
+        cell_size: Vec2::splat(50.0),
+        gap: 10.0,
+        ..Default::default()
+    };
+    assert_eq!(grid.column_count(170.0), 3);
+    assert_eq!(grid.column_count(10.0), 1);
+    assert_eq!(grid.rows(3).count(), 4);
+
+    let items = VirtualItems::new(|_, _| {});
+    assert!(items == items.clone());
+    assert!(items != VirtualItems::new(|_, _| {}));
+    assert!(items != items.clone().with_key(|index| index.to_string()));
+}